@@ -0,0 +1,84 @@
+//! PhysicsValidator 배치 검증 벤치마크 (스칼라 vs SoA 배치)
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::Rng;
+use sap_core::types::{Acceleration, Position, Velocity};
+use sap_physics::{BatchResults, CommandBatch, MotionCommand, PhysicsValidator};
+
+const ROBOT_COUNT: usize = 1_000;
+
+fn random_position(rng: &mut impl Rng, max: f32) -> Position {
+    Position::new(rng.gen_range(0.0..max), rng.gen_range(0.0..max), 0.0)
+}
+
+fn random_commands(rng: &mut impl Rng) -> Vec<MotionCommand> {
+    (0..ROBOT_COUNT as u64)
+        .map(|robot_id| {
+            MotionCommand::new(robot_id)
+                .with_position(random_position(rng, 500.0))
+                .with_velocity(Velocity::new(
+                    rng.gen_range(-6.0..6.0),
+                    rng.gen_range(-6.0..6.0),
+                    0.0,
+                ))
+                .with_acceleration(Acceleration::new(rng.gen_range(-2.0..2.0), 0.0, 0.0))
+                .with_angular(rng.gen_range(-1.0..1.0), 0.0)
+                .with_ticket(1)
+        })
+        .collect()
+}
+
+fn bench_validate_tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PhysicsValidator::validate_1000_commands");
+    group.throughput(Throughput::Elements(ROBOT_COUNT as u64));
+
+    let mut rng = rand::thread_rng();
+    let commands = random_commands(&mut rng);
+
+    for obstacle_count in [0usize, 100, 1_000].iter() {
+        let obstacles: Vec<Position> = (0..*obstacle_count)
+            .map(|_| random_position(&mut rng, 500.0))
+            .collect();
+
+        let mut validator = PhysicsValidator::with_default_config();
+        group.bench_with_input(
+            BenchmarkId::new("scalar", obstacle_count),
+            &obstacles,
+            |b, obstacles| {
+                let mut timestamp_ns = 0;
+                b.iter(|| {
+                    timestamp_ns += 20_000_000;
+                    commands
+                        .iter()
+                        .filter(|cmd| validator.validate(cmd, obstacles, timestamp_ns).is_ok())
+                        .count()
+                })
+            },
+        );
+
+        let mut validator = PhysicsValidator::with_default_config();
+        let mut batch = CommandBatch::with_capacity(ROBOT_COUNT);
+        let mut results = BatchResults::new();
+        group.bench_with_input(
+            BenchmarkId::new("batch", obstacle_count),
+            &obstacles,
+            |b, obstacles| {
+                let mut timestamp_ns = 0;
+                b.iter(|| {
+                    timestamp_ns += 20_000_000;
+                    batch.clear();
+                    for cmd in &commands {
+                        batch.push(cmd);
+                    }
+                    batch.set_obstacles(obstacles);
+                    validator.validate_batch(&batch, timestamp_ns, &mut results);
+                    results.counts().0
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_validate_tick);
+criterion_main!(benches);
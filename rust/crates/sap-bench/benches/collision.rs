@@ -0,0 +1,62 @@
+//! CollisionPredictor 벤치마크 (선형 탐색 vs 공간 인덱스)
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::Rng;
+use sap_core::types::{Position, Velocity};
+use sap_physics::{CollisionPredictor, SpatialIndex};
+
+const ROBOT_COUNT: usize = 1_000;
+
+fn random_position(rng: &mut impl Rng, max: f32) -> Position {
+    Position::new(rng.gen_range(0.0..max), rng.gen_range(0.0..max), 0.0)
+}
+
+fn bench_predict(c: &mut Criterion) {
+    let mut group = c.benchmark_group("CollisionPredictor::predict_1000_robots");
+    group.throughput(Throughput::Elements(ROBOT_COUNT as u64));
+
+    let predictor = CollisionPredictor::new(1.0, 1.0);
+    let mut rng = rand::thread_rng();
+    let robots: Vec<(Position, Velocity)> = (0..ROBOT_COUNT)
+        .map(|_| {
+            let velocity = Velocity::new(rng.gen_range(-2.0..2.0), rng.gen_range(-2.0..2.0), 0.0);
+            (random_position(&mut rng, 500.0), velocity)
+        })
+        .collect();
+
+    for obstacle_count in [1_000, 20_000].iter() {
+        let obstacles: Vec<Position> = (0..*obstacle_count)
+            .map(|_| random_position(&mut rng, 500.0))
+            .collect();
+        let index = SpatialIndex::build(&obstacles, 1.0, 1);
+
+        group.bench_with_input(
+            BenchmarkId::new("linear", obstacle_count),
+            &obstacles,
+            |b, obstacles| {
+                b.iter(|| {
+                    robots
+                        .iter()
+                        .filter(|(p, v)| predictor.predict(p, v, obstacles).will_collide)
+                        .count()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("indexed", obstacle_count),
+            &index,
+            |b, index| {
+                b.iter(|| {
+                    robots
+                        .iter()
+                        .filter(|(p, v)| predictor.predict_indexed(p, v, index).will_collide)
+                        .count()
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_predict);
+criterion_main!(benches);
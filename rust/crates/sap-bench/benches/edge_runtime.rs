@@ -2,7 +2,6 @@
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use sap_core::types::{Acceleration, Position, Velocity};
-use sap_edge::runtime::CommandResult;
 use sap_edge::EdgeRuntime;
 use sap_physics::command::MotionCommand;
 
@@ -12,6 +11,8 @@ fn create_command(robot_id: u64) -> MotionCommand {
         current_position: Position::ORIGIN,
         target_velocity: Velocity::new(1.0, 0.0, 0.0),
         target_acceleration: Acceleration::new(0.5, 0.0, 0.0),
+        target_angular_velocity: 0.0,
+        target_angular_acceleration: 0.0,
         ticket_id: 1,
     }
 }
@@ -2,13 +2,16 @@
 //!
 //! PPR 매핑: AI_make_VtsAllocator
 
-use sap_core::ticket::VoxelTimeSlot;
+use sap_core::ticket::{VoxelGrid, VoxelTimeSlot};
+use sap_core::types::Trajectory;
 use std::collections::HashMap;
 
 /// VTS 할당자
 pub struct VtsAllocator {
     allocated: HashMap<u64, VtsAllocation>,
     pending: Vec<VtsRequest>,
+    next_request_id: u64,
+    next_path_id: u64,
     next_vts_id: u64,
     zone_limits: HashMap<u32, usize>,
 }
@@ -18,6 +21,8 @@ pub struct VtsAllocation {
     pub vts: VoxelTimeSlot,
     pub robot_id: u64,
     pub allocated_at_ns: u64,
+    /// 요청 궤적 ID (같은 궤적의 창끼리만 겹침 허용)
+    pub path_id: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +34,8 @@ pub struct VtsRequest {
     pub t_start_ns: u64,
     pub t_end_ns: u64,
     pub priority: u8,
+    /// 요청 궤적 ID (`request_trajectory`가 부여, 단일 요청은 None)
+    pub path_id: Option<u64>,
 }
 
 impl VtsAllocator {
@@ -36,6 +43,8 @@ impl VtsAllocator {
         Self {
             allocated: HashMap::new(),
             pending: Vec::new(),
+            next_request_id: 1,
+            next_path_id: 1,
             next_vts_id: 1,
             zone_limits: HashMap::new(),
         }
@@ -46,13 +55,46 @@ impl VtsAllocator {
     }
 
     pub fn request(&mut self, req: VtsRequest) -> u64 {
-        let request_id = self.pending.len() as u64 + 1;
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
         let mut req = req;
         req.request_id = request_id;
         self.pending.push(req);
         request_id
     }
 
+    /// 궤적 시각 기반 VTS 요청
+    ///
+    /// 궤적이 지나는 복셀마다 실제 통과 시각(± margin) 창을 요청한다.
+    /// 한 번의 호출로 요청한 창들은 같은 궤적 ID를 공유하여 서로 충돌하지 않는다.
+    /// 반환된 request_id는 시간순.
+    pub fn request_trajectory(
+        &mut self,
+        zone_id: u32,
+        trajectory: &Trajectory,
+        grid: &VoxelGrid,
+        margin_ns: u64,
+        priority: u8,
+    ) -> Vec<u64> {
+        let path_id = self.next_path_id;
+        self.next_path_id += 1;
+        grid.occupancy_windows(trajectory, margin_ns)
+            .into_iter()
+            .map(|vts| {
+                self.request(VtsRequest {
+                    request_id: 0,
+                    robot_id: trajectory.robot_id,
+                    zone_id,
+                    voxel_id: vts.voxel_id,
+                    t_start_ns: vts.t_start_ns,
+                    t_end_ns: vts.t_end_ns,
+                    priority,
+                    path_id: Some(path_id),
+                })
+            })
+            .collect()
+    }
+
     pub fn allocate(&mut self, request_id: u64, current_time_ns: u64) -> Option<VtsAllocation> {
         let idx = self
             .pending
@@ -80,6 +122,7 @@ impl VtsAllocator {
             vts,
             robot_id: req.robot_id,
             allocated_at_ns: current_time_ns,
+            path_id: req.path_id,
         };
 
         self.allocated.insert(vts_id, allocation.clone());
@@ -87,8 +130,10 @@ impl VtsAllocator {
     }
 
     fn has_conflict(&self, req: &VtsRequest) -> bool {
+        // 같은 궤적의 창끼리는 충돌하지 않음 (동일 복셀 재방문)
         self.allocated.values().any(|alloc| {
-            alloc.vts.voxel_id == req.voxel_id
+            !(req.path_id.is_some() && alloc.path_id == req.path_id)
+                && alloc.vts.voxel_id == req.voxel_id
                 && alloc.vts.t_start_ns < req.t_end_ns
                 && alloc.vts.t_end_ns > req.t_start_ns
         })
@@ -156,6 +201,7 @@ mod tests {
             t_start_ns: t_start,
             t_end_ns: t_end,
             priority: 0,
+            path_id: None,
         }
     }
 
@@ -199,6 +245,54 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_request_trajectory_windows() {
+        use sap_core::types::{Position, TrajectoryPoint};
+
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [10, 1, 1]);
+        let mut trajectory = Trajectory::new(7);
+        for i in 0..=6u64 {
+            let x = i as f32 * 0.5;
+            trajectory.push(TrajectoryPoint::new(i * 1000, Position::new(x, 0.5, 0.5)));
+        }
+
+        let mut alloc = VtsAllocator::new();
+        let ids = alloc.request_trajectory(1, &trajectory, &grid, 100, 0);
+        assert_eq!(ids.len(), 4); // 복셀 0, 1, 2, 3
+
+        let allocations: Vec<_> = ids
+            .iter()
+            .map(|&id| alloc.allocate(id, 0).expect("own windows never conflict"))
+            .collect();
+        assert_eq!(allocations[1].vts.voxel_id, 1);
+        assert_eq!(allocations[1].vts.t_start_ns, 900);
+        assert_eq!(allocations[1].vts.t_end_ns, 4100);
+        assert!(allocations.iter().all(|a| a.robot_id == 7));
+
+        // 다른 로봇이 같은 시간대에 복셀 2 요청 → 충돌
+        let r = alloc.request(create_request(8, 2, 4000, 4500));
+        assert!(alloc.allocate(r, 0).is_none());
+
+        // 같은 로봇이라도 별도 요청이면 자기 창과 충돌
+        let r = alloc.request(create_request(7, 2, 4000, 4500));
+        assert!(alloc.allocate(r, 0).is_none());
+        let again = alloc.request_trajectory(1, &trajectory, &grid, 100, 0);
+        assert!(alloc.allocate(again[0], 0).is_none());
+    }
+
+    #[test]
+    fn test_request_ids_unique_after_allocation() {
+        let mut alloc = VtsAllocator::new();
+        let r1 = alloc.request(create_request(1, 100, 1000, 2000));
+        let r2 = alloc.request(create_request(2, 200, 1000, 2000));
+        alloc.allocate(r1, 0);
+        // 대기열이 줄어도 이전 ID를 재사용하지 않음
+        let r3 = alloc.request(create_request(3, 300, 1000, 2000));
+        assert_ne!(r3, r2);
+        assert_eq!(alloc.allocate(r2, 0).unwrap().robot_id, 2);
+        assert_eq!(alloc.allocate(r3, 0).unwrap().robot_id, 3);
+    }
+
     #[test]
     fn test_release() {
         let mut alloc = VtsAllocator::new();
@@ -27,4 +27,4 @@ mod replay;
 mod signature;
 
 pub use replay::{NonceGenerator, ReplayGuard, SequenceTracker};
-pub use signature::{PublicKey, SecretKey, Signature, SignatureError, SignedMessage};
+pub use signature::{PublicKey, SecretKey, Signature, SignatureError, SignedMessage, Signer};
@@ -181,6 +181,17 @@ impl std::fmt::Display for SignatureError {
 
 impl std::error::Error for SignatureError {}
 
+/// 서명자 인터페이스
+///
+/// 실제 Ed25519 서명은 외부 크레이트 구현체가 제공한다.
+pub trait Signer {
+    /// 서명자 공개키
+    fn public_key(&self) -> PublicKey;
+
+    /// 메시지 서명
+    fn sign(&self, message: &[u8]) -> Signature;
+}
+
 /// 서명된 메시지 래퍼
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignedMessage<T> {
@@ -6,7 +6,8 @@
 //!
 //! - `types`: 기본 타입 (Position, Velocity, Acceleration, RobotState)
 //! - `validation`: 검증 타입 (ValidationResult, ValidationFrame)
-//! - `packet`: 네트워크 패킷 (DeltaTickPacket, RollbackFrame)
+//! - `packet`: 네트워크 패킷 (DeltaTickPacket, RollbackFrame, SelectiveAck)
+//! - `prediction`: 추측 항법 예측 모델 (MotionModel)
 //! - `ticket`: 티켓/경제 타입 (VoxelTimeSlot, TransitTicket)
 //! - `crypto`: 암호화/보안 (Signature, ReplayGuard)
 //! - `error`: 에러 타입
@@ -15,15 +16,17 @@
 pub mod crypto;
 pub mod error;
 pub mod packet;
+pub mod prediction;
 pub mod ticket;
 pub mod types;
 pub mod util;
 pub mod validation;
 
 // 자주 사용되는 타입 re-export
-pub use crypto::{PublicKey, ReplayGuard, Signature, SignedMessage};
+pub use crypto::{PublicKey, ReplayGuard, Signature, SignedMessage, Signer};
 pub use error::{Result, SapError};
 pub use packet::{DeltaTickPacket, PacketHeader, RollbackFrame};
+pub use prediction::{KinematicState, MotionModel};
 pub use ticket::{Bid, TransitTicket, VoxelTimeSlot, VtsId};
-pub use types::{Acceleration, Position, RobotState, Velocity, WorldState};
+pub use types::{Acceleration, Position, RobotState, Trajectory, Velocity, WorldState};
 pub use validation::{ProofDigest, ValidationFrame, ValidationResult};
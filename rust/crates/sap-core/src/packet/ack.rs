@@ -0,0 +1,73 @@
+//! 선택적 확인 응답 (SACK)
+//!
+//! 신뢰 전송 계층의 순번 메시지 수신 현황. DeltaTick에 실어(piggyback) 보내며,
+//! 보낼 DeltaTick이 없으면 단독 Ack 메시지로 보낸다.
+
+use serde::{Deserialize, Serialize};
+
+/// 선택적 확인 응답
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectiveAck {
+    /// 빠짐없이 수신한 마지막 순번 (0이면 없음, 순번은 1부터)
+    pub cumulative: u64,
+
+    /// `cumulative` 이후 수신 비트맵 - bit i ↔ 순번 `cumulative + 1 + i`
+    pub bitmap: u64,
+}
+
+impl SelectiveAck {
+    /// 비트맵이 표현하는 순번 범위
+    pub const WINDOW: u64 = 64;
+
+    /// 새 SelectiveAck 생성
+    pub fn new(cumulative: u64, bitmap: u64) -> Self {
+        Self { cumulative, bitmap }
+    }
+
+    /// 순번 수신 확인 여부
+    pub fn contains(&self, seq: u64) -> bool {
+        if seq == 0 {
+            return false;
+        }
+        if seq <= self.cumulative {
+            return true;
+        }
+        let offset = seq - self.cumulative - 1;
+        offset < Self::WINDOW && self.bitmap & (1 << offset) != 0
+    }
+
+    /// 확인한 순번이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.cumulative == 0 && self.bitmap == 0
+    }
+
+    /// 확인한 가장 큰 순번
+    pub fn highest(&self) -> u64 {
+        match self.bitmap {
+            0 => self.cumulative,
+            bits => self
+                .cumulative
+                .saturating_add(u64::from(64 - bits.leading_zeros())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selective_ack_contains() {
+        // 1..=3 연속, 5와 8 수신
+        let ack = SelectiveAck::new(3, 0b10010);
+        assert!(!ack.contains(0));
+        assert!(ack.contains(1) && ack.contains(3));
+        assert!(!ack.contains(4));
+        assert!(ack.contains(5));
+        assert!(!ack.contains(6));
+        assert!(ack.contains(8));
+        assert!(!ack.contains(3 + 1 + 64));
+        assert_eq!(ack.highest(), 8);
+        assert!(SelectiveAck::default().is_empty());
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! PPR 매핑: AI_make_DeltaTick
 
+use super::SelectiveAck;
+use crate::prediction::MotionModel;
 use crate::types::{Acceleration, Position, Velocity};
 use serde::{Deserialize, Serialize};
 
@@ -20,7 +22,7 @@ pub struct DeltaTickPacket {
     /// 논리적 틱 번호
     pub tick: u64,
 
-    /// 사용 중인 월드 모델 해시 (16바이트)
+    /// 사용 중인 예측 모델 해시 (16바이트, `MotionModel::model_hash`)
     pub model_hash: [u8; 16],
 
     /// PTP 동기화 타임스탬프 (나노초)
@@ -38,12 +40,18 @@ pub struct DeltaTickPacket {
     /// 방향각 (라디안)
     pub theta: f32,
 
+    /// 각속도 (rad/s)
+    pub omega: f32,
+
     /// 예측 대비 위치 오차 (actual - predicted)
     pub delta_position: Position,
 
     /// 예측 대비 방향 오차
     pub delta_theta: f32,
 
+    /// 델타 계산 기준이 된 직전 보고의 타임스탬프 (나노초, 첫 보고면 None)
+    pub baseline_ns: Option<u64>,
+
     /// 목표 세그먼트/레인 ID
     pub target_segment: u32,
 
@@ -58,6 +66,9 @@ pub struct DeltaTickPacket {
 
     /// 배터리 잔량 (밀리 퍼센트)
     pub battery_soc_milli: u16,
+
+    /// 신뢰 메시지 수신 확인 (piggyback)
+    pub ack: SelectiveAck,
 }
 
 impl DeltaTickPacket {
@@ -73,13 +84,16 @@ impl DeltaTickPacket {
             velocity: Velocity::ZERO,
             acceleration: Acceleration::ZERO,
             theta: 0.0,
+            omega: 0.0,
             delta_position: Position::ORIGIN,
             delta_theta: 0.0,
+            baseline_ns: None,
             target_segment: 0,
             ticket_id: 0,
             eta_ms: 0,
             controller_temp_c: 25,
             battery_soc_milli: 65535, // Max for u16 (100% = 65535)
+            ack: SelectiveAck::default(),
         }
     }
 
@@ -98,6 +112,23 @@ impl DeltaTickPacket {
         self
     }
 
+    /// 각속도 설정
+    pub fn with_angular_rate(mut self, omega: f32) -> Self {
+        self.omega = omega;
+        self
+    }
+
+    /// 예측 모델 설정
+    pub fn with_model(mut self, model: MotionModel) -> Self {
+        self.model_hash = model.model_hash();
+        self
+    }
+
+    /// 예측 모델 조회 (알 수 없는 해시면 None)
+    pub fn motion_model(&self) -> Option<MotionModel> {
+        MotionModel::from_model_hash(&self.model_hash)
+    }
+
     /// 델타 (예측 오차) 설정
     pub fn with_delta(mut self, delta_position: Position, delta_theta: f32) -> Self {
         self.delta_position = delta_position;
@@ -105,12 +136,24 @@ impl DeltaTickPacket {
         self
     }
 
+    /// 델타 기준 보고 타임스탬프 설정
+    pub fn with_baseline(mut self, baseline_ns: u64) -> Self {
+        self.baseline_ns = Some(baseline_ns);
+        self
+    }
+
     /// 타임스탬프 설정
     pub fn with_timestamp(mut self, timestamp_ns: u64) -> Self {
         self.timestamp_ns = timestamp_ns;
         self
     }
 
+    /// 수신 확인 설정
+    pub fn with_ack(mut self, ack: SelectiveAck) -> Self {
+        self.ack = ack;
+        self
+    }
+
     /// 예측 오차 크기 (위치)
     ///
     /// PPR: AI_process_StateComparison
@@ -0,0 +1,41 @@
+//! 비상 정지 패킷
+//!
+//! Edge가 로봇(또는 Zone 전체)에 즉시 정지를 지시한다. 반드시 도달해야 하는 메시지.
+
+use serde::{Deserialize, Serialize};
+
+/// 비상 정지 패킷
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmergencyStopPacket {
+    /// Zone ID
+    pub zone_id: u32,
+
+    /// 대상 로봇 ID (`ALL_ROBOTS`면 Zone 전체)
+    pub robot_id: u64,
+
+    /// 정지 사유 코드
+    pub reason_code: u32,
+
+    /// 발행 타임스탬프 (나노초)
+    pub issued_at_ns: u64,
+}
+
+impl EmergencyStopPacket {
+    /// Zone 전체 대상 로봇 ID
+    pub const ALL_ROBOTS: u64 = u64::MAX;
+
+    /// 새 EmergencyStopPacket 생성
+    pub fn new(zone_id: u32, robot_id: u64, reason_code: u32, issued_at_ns: u64) -> Self {
+        Self {
+            zone_id,
+            robot_id,
+            reason_code,
+            issued_at_ns,
+        }
+    }
+
+    /// 특정 로봇 대상 여부 (Zone 전체 정지 포함)
+    pub fn targets(&self, robot_id: u64) -> bool {
+        self.robot_id == Self::ALL_ROBOTS || self.robot_id == robot_id
+    }
+}
@@ -29,6 +29,18 @@ pub enum PacketType {
     /// 검증 OK
     VerificationOk = 0x13,
 
+    /// 비상 정지
+    EmergencyStop = 0x14,
+
+    /// 선택적 확인 응답
+    Ack = 0x15,
+
+    /// 순번 부여된 신뢰 메시지
+    Sequenced = 0x16,
+
+    /// 포기한 순번 건너뛰기
+    ForwardSeq = 0x17,
+
     /// 가격 견적 요청
     QuoteRequest = 0x20,
 
@@ -132,6 +144,10 @@ impl PacketHeader {
             0x11 => Some(PacketType::RollbackFrame),
             0x12 => Some(PacketType::ViolationAlert),
             0x13 => Some(PacketType::VerificationOk),
+            0x14 => Some(PacketType::EmergencyStop),
+            0x15 => Some(PacketType::Ack),
+            0x16 => Some(PacketType::Sequenced),
+            0x17 => Some(PacketType::ForwardSeq),
             0x20 => Some(PacketType::QuoteRequest),
             0x21 => Some(PacketType::QuoteResponse),
             0x22 => Some(PacketType::BidCommit),
@@ -1,9 +1,13 @@
 //! 네트워크 패킷 정의 모듈
 
+mod ack;
 mod delta_tick;
+mod emergency_stop;
 mod header;
 mod rollback_frame;
 
+pub use ack::SelectiveAck;
 pub use delta_tick::DeltaTickPacket;
-pub use header::PacketHeader;
-pub use rollback_frame::{RollbackFrame, RollbackReason};
+pub use emergency_stop::EmergencyStopPacket;
+pub use header::{PacketHeader, PacketType};
+pub use rollback_frame::{PredictedState, RollbackFrame, RollbackReason};
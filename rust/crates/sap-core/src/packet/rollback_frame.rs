@@ -73,6 +73,9 @@ pub enum RollbackReason {
 
     /// Edge 다운 복구
     EdgeRecovery,
+
+    /// 운영자 수동 요청
+    Manual,
 }
 
 impl RollbackFrame {
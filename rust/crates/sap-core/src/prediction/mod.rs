@@ -0,0 +1,5 @@
+//! 예측 모듈 - 로봇/Edge 공용 추측 항법 모델
+
+mod model;
+
+pub use model::{wrap_angle, KinematicState, MotionModel};
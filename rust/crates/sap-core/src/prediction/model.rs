@@ -0,0 +1,328 @@
+//! MotionModel - 추측 항법(dead reckoning) 예측 모델
+//!
+//! 로봇과 Edge가 같은 구현으로 예측해야 DeltaTick의 예측 오차를 검증할 수 있다.
+//! 모든 연산은 IEEE-754 기본 연산(+, -, ×, ÷)만 사용하며, 삼각함수도 자체 다항식으로
+//! 계산하므로 플랫폼 libm과 무관하게 비트 단위로 같은 결과를 낸다.
+//!
+//! PPR 매핑: AI_process_DeadReckoning
+
+use crate::packet::DeltaTickPacket;
+use crate::types::{Acceleration, Position, RobotState, Velocity};
+use serde::{Deserialize, Serialize};
+
+/// 예측 모델 식별자 접두사 (`model_hash` 앞 8바이트)
+const MODEL_TAG: [u8; 8] = *b"SAPDR\0\0\x01";
+
+/// 예측용 운동 상태
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct KinematicState {
+    /// 위치 (m)
+    pub position: Position,
+
+    /// 속도 (m/s)
+    pub velocity: Velocity,
+
+    /// 가속도 (m/s²)
+    pub acceleration: Acceleration,
+
+    /// 방향각 (rad)
+    pub theta: f32,
+
+    /// 각속도 (rad/s)
+    pub omega: f32,
+}
+
+impl KinematicState {
+    /// 로봇 상태에서 생성
+    pub fn from_robot(state: &RobotState) -> Self {
+        Self {
+            position: state.position,
+            velocity: state.velocity,
+            acceleration: state.acceleration,
+            theta: state.theta,
+            omega: state.omega,
+        }
+    }
+
+    /// DeltaTick 패킷에서 생성
+    pub fn from_packet(packet: &DeltaTickPacket) -> Self {
+        Self {
+            position: packet.position,
+            velocity: packet.velocity,
+            acceleration: packet.acceleration,
+            theta: packet.theta,
+            omega: packet.omega,
+        }
+    }
+}
+
+/// 추측 항법 예측 모델
+///
+/// PPR: AI_process_DeadReckoning(state, dt) -> KinematicState
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MotionModel {
+    /// 등속 (CV)
+    #[default]
+    ConstantVelocity,
+
+    /// 등가속 (CA)
+    ConstantAcceleration,
+
+    /// 등속·등회전율 (CTRV) - 차동 구동
+    ConstantTurnRateVelocity,
+}
+
+impl MotionModel {
+    /// 전체 모델 목록
+    pub const ALL: [Self; 3] = [
+        Self::ConstantVelocity,
+        Self::ConstantAcceleration,
+        Self::ConstantTurnRateVelocity,
+    ];
+
+    /// 모델 ID
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::ConstantVelocity => 1,
+            Self::ConstantAcceleration => 2,
+            Self::ConstantTurnRateVelocity => 3,
+        }
+    }
+
+    /// 모델 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ConstantVelocity => "CV",
+            Self::ConstantAcceleration => "CA",
+            Self::ConstantTurnRateVelocity => "CTRV",
+        }
+    }
+
+    /// DeltaTick `model_hash` 값 - [태그 8B][모델 ID 1B][0 7B]
+    pub fn model_hash(&self) -> [u8; 16] {
+        let mut hash = [0u8; 16];
+        hash[..8].copy_from_slice(&MODEL_TAG);
+        hash[8] = self.id();
+        hash
+    }
+
+    /// `model_hash`에서 모델 복원 (알 수 없는 값이면 None)
+    pub fn from_model_hash(hash: &[u8; 16]) -> Option<Self> {
+        if hash[..8] != MODEL_TAG || hash[9..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        Self::ALL.into_iter().find(|model| model.id() == hash[8])
+    }
+
+    /// `dt` 초 후 상태 예측
+    pub fn predict(&self, state: &KinematicState, dt: f32) -> KinematicState {
+        let mut next = *state;
+        match self {
+            Self::ConstantVelocity => {
+                next.position = advance(state.position, state.velocity, dt);
+                next.acceleration = Acceleration::ZERO;
+                next.theta = state.theta + state.omega * dt;
+            }
+            Self::ConstantAcceleration => {
+                let half_dt2 = 0.5 * dt * dt;
+                let a = state.acceleration;
+                next.position = Position::new(
+                    state.position.x + state.velocity.vx * dt + a.ax * half_dt2,
+                    state.position.y + state.velocity.vy * dt + a.ay * half_dt2,
+                    state.position.z + state.velocity.vz * dt + a.az * half_dt2,
+                );
+                next.velocity = Velocity::new(
+                    state.velocity.vx + a.ax * dt,
+                    state.velocity.vy + a.ay * dt,
+                    state.velocity.vz + a.az * dt,
+                );
+                next.theta = state.theta + state.omega * dt;
+            }
+            Self::ConstantTurnRateVelocity => {
+                let (sin0, cos0) = sin_cos(state.theta);
+                // 진행 방향 성분 (후진이면 음수)
+                let speed = state.velocity.vx * cos0 + state.velocity.vy * sin0;
+                let theta = state.theta + state.omega * dt;
+                let (sin1, cos1) = sin_cos(theta);
+
+                let (dx, dy) = if state.omega.abs() < 1e-4 {
+                    (speed * cos0 * dt, speed * sin0 * dt)
+                } else {
+                    let r = speed / state.omega;
+                    (r * (sin1 - sin0), r * (cos0 - cos1))
+                };
+                next.position = Position::new(
+                    state.position.x + dx,
+                    state.position.y + dy,
+                    state.position.z + state.velocity.vz * dt,
+                );
+                next.velocity = Velocity::new(speed * cos1, speed * sin1, state.velocity.vz);
+                next.acceleration = Acceleration::ZERO;
+                next.theta = theta;
+            }
+        }
+        next.theta = wrap_angle(next.theta);
+        next
+    }
+
+    /// 나노초 간격 예측 (로봇/Edge가 같은 dt 변환을 쓰도록 고정)
+    pub fn predict_ns(&self, state: &KinematicState, dt_ns: u64) -> KinematicState {
+        self.predict(state, ns_to_secs(dt_ns))
+    }
+
+    /// 예측 오차 (actual - predicted) - 위치 차이와 [-π, π) 방향 차이
+    pub fn prediction_delta(
+        &self,
+        previous: &KinematicState,
+        actual: &KinematicState,
+        dt_ns: u64,
+    ) -> (Position, f32) {
+        let predicted = self.predict_ns(previous, dt_ns);
+        (
+            Position::new(
+                actual.position.x - predicted.position.x,
+                actual.position.y - predicted.position.y,
+                actual.position.z - predicted.position.z,
+            ),
+            wrap_angle(actual.theta - predicted.theta),
+        )
+    }
+}
+
+#[inline]
+fn advance(position: Position, velocity: Velocity, dt: f32) -> Position {
+    Position::new(
+        position.x + velocity.vx * dt,
+        position.y + velocity.vy * dt,
+        position.z + velocity.vz * dt,
+    )
+}
+
+#[inline]
+fn ns_to_secs(dt_ns: u64) -> f32 {
+    (dt_ns as f64 / 1_000_000_000.0) as f32
+}
+
+/// 각도를 [-π, π)로 정규화
+pub fn wrap_angle(theta: f32) -> f32 {
+    use std::f32::consts::{PI, TAU};
+    (theta + PI).rem_euclid(TAU) - PI
+}
+
+/// 결정적 sin/cos - f64 다항식 (|오차| < 1e-9, f32 반올림 전)
+fn sin_cos(x: f32) -> (f32, f32) {
+    use std::f64::consts::{FRAC_PI_2, TAU};
+
+    let x = x as f64;
+    // [-π, π] → 사분면 + [-π/4, π/4]
+    let r = x - (x / TAU).round() * TAU;
+    let quadrant = (r / FRAC_PI_2).round();
+    let y = r - quadrant * FRAC_PI_2;
+    let y2 = y * y;
+
+    let sin = y
+        * (1.0
+            + y2 * (-1.0 / 6.0
+                + y2 * (1.0 / 120.0
+                    + y2 * (-1.0 / 5_040.0
+                        + y2 * (1.0 / 362_880.0 + y2 * (-1.0 / 39_916_800.0))))));
+    let cos = 1.0
+        + y2 * (-0.5
+            + y2 * (1.0 / 24.0
+                + y2 * (-1.0 / 720.0
+                    + y2 * (1.0 / 40_320.0 + y2 * (-1.0 / 3_628_800.0 + y2 / 479_001_600.0)))));
+
+    let (s, c) = match quadrant as i32 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        -1 => (-cos, sin),
+        _ => (-sin, -cos), // ±2
+    };
+    (s as f32, c as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    fn moving(vx: f32, vy: f32, theta: f32, omega: f32) -> KinematicState {
+        KinematicState {
+            velocity: Velocity::new(vx, vy, 0.0),
+            theta,
+            omega,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_model_hash_roundtrip() {
+        for model in MotionModel::ALL {
+            assert_eq!(
+                MotionModel::from_model_hash(&model.model_hash()),
+                Some(model)
+            );
+        }
+        assert_eq!(MotionModel::from_model_hash(&[0u8; 16]), None);
+        let mut unknown = MotionModel::ConstantVelocity.model_hash();
+        unknown[8] = 99;
+        assert_eq!(MotionModel::from_model_hash(&unknown), None);
+    }
+
+    #[test]
+    fn test_constant_velocity_and_acceleration() {
+        let mut state = moving(1.0, 0.0, 0.0, 0.0);
+        state.acceleration = Acceleration::new(2.0, 0.0, 0.0);
+
+        let cv = MotionModel::ConstantVelocity.predict(&state, 0.5);
+        assert!((cv.position.x - 0.5).abs() < 1e-6);
+        assert_eq!(cv.velocity, state.velocity);
+
+        let ca = MotionModel::ConstantAcceleration.predict(&state, 0.5);
+        assert!((ca.position.x - 0.75).abs() < 1e-6);
+        assert!((ca.velocity.vx - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ctrv_quarter_circle() {
+        // 1 m/s, π/2 rad/s → 1초 후 반경 2/π 사분원
+        let state = moving(1.0, 0.0, 0.0, FRAC_PI_2);
+        let next = MotionModel::ConstantTurnRateVelocity.predict(&state, 1.0);
+        let radius = 2.0 / PI;
+        assert!((next.position.x - radius).abs() < 1e-5);
+        assert!((next.position.y - radius).abs() < 1e-5);
+        assert!((next.theta - FRAC_PI_2).abs() < 1e-6);
+        assert!(next.velocity.vx.abs() < 1e-6);
+        assert!((next.velocity.vy - 1.0).abs() < 1e-6);
+
+        // 회전율 0이면 직진, 후진도 진행 방향 성분 유지
+        let straight =
+            MotionModel::ConstantTurnRateVelocity.predict(&moving(-1.0, 0.0, 0.0, 0.0), 1.0);
+        assert!((straight.position.x + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sin_cos_accuracy() {
+        for i in -100..=100 {
+            let x = i as f32 * 0.137;
+            let (s, c) = sin_cos(x);
+            assert!((s - x.sin()).abs() < 1e-6, "sin({x})");
+            assert!((c - x.cos()).abs() < 1e-6, "cos({x})");
+        }
+    }
+
+    #[test]
+    fn test_prediction_delta() {
+        let previous = moving(1.0, 0.0, 0.0, 0.0);
+        let mut actual = MotionModel::ConstantVelocity.predict_ns(&previous, 50_000_000);
+        actual.position.y += 0.02;
+        actual.theta += 0.1;
+
+        let (delta, delta_theta) =
+            MotionModel::ConstantVelocity.prediction_delta(&previous, &actual, 50_000_000);
+        assert!(delta.x.abs() < 1e-6);
+        assert!((delta.y - 0.02).abs() < 1e-6);
+        assert!((delta_theta - 0.1).abs() < 1e-6);
+    }
+}
@@ -1,11 +1,15 @@
 //! 티켓/경제 타입 정의 모듈
 
 mod bid;
+mod ticket_store;
 mod transit_ticket;
+mod voxel_grid;
 mod voxel_time_slot;
 mod vts_id;
 
 pub use bid::Bid;
+pub use ticket_store::TicketStore;
 pub use transit_ticket::TransitTicket;
+pub use voxel_grid::VoxelGrid;
 pub use voxel_time_slot::{VoxelTimeSlot, VoxelTimeSlotMeta};
 pub use vts_id::VtsId;
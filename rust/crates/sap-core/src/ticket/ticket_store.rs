@@ -0,0 +1,43 @@
+//! TicketStore - 티켓 조회 인터페이스
+//!
+//! 물리 커널이 경제 계층 구현에 의존하지 않고 티켓을 조회하기 위한 추상화
+//!
+//! PPR 매핑: AI_perceive_TicketStore
+
+use super::TransitTicket;
+use std::collections::HashMap;
+
+/// 티켓 저장소
+pub trait TicketStore {
+    /// 티켓 ID로 조회
+    fn ticket(&self, ticket_id: u128) -> Option<&TransitTicket>;
+}
+
+impl TicketStore for HashMap<u128, TransitTicket> {
+    fn ticket(&self, ticket_id: u128) -> Option<&TransitTicket> {
+        self.get(&ticket_id)
+    }
+}
+
+impl TicketStore for [TransitTicket] {
+    fn ticket(&self, ticket_id: u128) -> Option<&TransitTicket> {
+        self.iter().find(|t| t.ticket_id == ticket_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticket_store_lookup() {
+        let tickets = vec![TransitTicket::new(1, 10, 1), TransitTicket::new(2, 20, 1)];
+        assert_eq!(tickets.as_slice().ticket(2).unwrap().robot_id, 20);
+        assert!(tickets.as_slice().ticket(3).is_none());
+
+        let map: HashMap<u128, TransitTicket> =
+            tickets.into_iter().map(|t| (t.ticket_id, t)).collect();
+        assert_eq!(map.ticket(1).unwrap().robot_id, 10);
+        assert!(map.ticket(3).is_none());
+    }
+}
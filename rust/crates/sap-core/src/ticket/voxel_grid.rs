@@ -0,0 +1,158 @@
+//! VoxelGrid - 위치 ↔ 복셀 ID 매핑
+//!
+//! Zone을 균일한 정육면체 복셀로 분할한다.
+//! voxel_id = ix + iy·nx + iz·nx·ny
+//!
+//! PPR 매핑: AI_make_VoxelGrid
+
+use super::VoxelTimeSlot;
+use crate::types::{Position, Trajectory};
+use serde::{Deserialize, Serialize};
+
+/// 균일 복셀 격자
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct VoxelGrid {
+    /// 격자 원점 (최소 모서리, m)
+    pub origin: Position,
+
+    /// 복셀 한 변 길이 (m)
+    pub voxel_size: f32,
+
+    /// 축별 복셀 수 [nx, ny, nz]
+    pub dims: [u32; 3],
+}
+
+impl VoxelGrid {
+    /// 새 VoxelGrid 생성
+    pub fn new(origin: Position, voxel_size: f32, dims: [u32; 3]) -> Self {
+        Self {
+            origin,
+            voxel_size,
+            dims,
+        }
+    }
+
+    /// 총 복셀 수
+    pub fn voxel_count(&self) -> u64 {
+        self.dims.iter().map(|&d| d as u64).product()
+    }
+
+    /// 위치가 속한 복셀 ID (격자 밖이면 None)
+    pub fn voxel_id(&self, position: &Position) -> Option<u64> {
+        let [ix, iy, iz] = self.voxel_index(position)?;
+        let [nx, ny, _] = self.dims;
+        Some(ix as u64 + iy as u64 * nx as u64 + iz as u64 * nx as u64 * ny as u64)
+    }
+
+    /// 위치가 속한 복셀 인덱스 [ix, iy, iz]
+    pub fn voxel_index(&self, position: &Position) -> Option<[u32; 3]> {
+        let rel = *position - self.origin;
+        let coords = [rel.x, rel.y, rel.z];
+        let mut index = [0u32; 3];
+        for axis in 0..3 {
+            let cell = (coords[axis] / self.voxel_size).floor();
+            if cell < 0.0 || cell >= self.dims[axis] as f32 {
+                return None;
+            }
+            index[axis] = cell as u32;
+        }
+        Some(index)
+    }
+
+    /// 복셀 중심 위치
+    pub fn voxel_center(&self, voxel_id: u64) -> Option<Position> {
+        if voxel_id >= self.voxel_count() {
+            return None;
+        }
+        let [nx, ny, _] = self.dims;
+        let nx = nx as u64;
+        let ny = ny as u64;
+        let ix = voxel_id % nx;
+        let iy = (voxel_id / nx) % ny;
+        let iz = voxel_id / (nx * ny);
+        let half = self.voxel_size * 0.5;
+        Some(Position::new(
+            self.origin.x + ix as f32 * self.voxel_size + half,
+            self.origin.y + iy as f32 * self.voxel_size + half,
+            self.origin.z + iz as f32 * self.voxel_size + half,
+        ))
+    }
+
+    /// 궤적이 점유하는 복셀-시간 창 계산
+    ///
+    /// 복셀 경계를 넘는 샘플 구간은 양쪽 창에 모두 포함(보수적)하고,
+    /// 각 창을 `margin_ns`만큼 앞뒤로 확장한다. 격자 밖 샘플은 제외.
+    pub fn occupancy_windows(&self, trajectory: &Trajectory, margin_ns: u64) -> Vec<VoxelTimeSlot> {
+        let mut windows = Vec::new();
+        let mut current: Option<(u64, u64)> = None; // (voxel_id, 진입 시각)
+        let mut prev_ns = 0;
+
+        for point in trajectory.points() {
+            let voxel = self.voxel_id(&point.position);
+            match (current, voxel) {
+                (Some((id, _)), Some(v)) if id == v => {}
+                (Some((id, enter)), _) => {
+                    windows.push(Self::window(id, enter, point.timestamp_ns, margin_ns));
+                    current = voxel.map(|v| (v, prev_ns));
+                }
+                (None, Some(v)) => current = Some((v, point.timestamp_ns)),
+                (None, None) => {}
+            }
+            prev_ns = point.timestamp_ns;
+        }
+
+        if let Some((id, enter)) = current {
+            // 종료 시각은 배타적이므로 도착 시각을 포함하도록 +1
+            windows.push(Self::window(id, enter, prev_ns + 1, margin_ns));
+        }
+        windows
+    }
+
+    fn window(voxel_id: u64, enter_ns: u64, exit_ns: u64, margin_ns: u64) -> VoxelTimeSlot {
+        VoxelTimeSlot::new(
+            voxel_id,
+            enter_ns.saturating_sub(margin_ns),
+            exit_ns.saturating_add(margin_ns),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voxel_grid_roundtrip() {
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [10, 10, 2]);
+        assert_eq!(grid.voxel_count(), 200);
+
+        let id = grid.voxel_id(&Position::new(3.5, 2.2, 1.1)).unwrap();
+        assert_eq!(id, 3 + 2 * 10 + 100);
+        assert_eq!(grid.voxel_center(id), Some(Position::new(3.5, 2.5, 1.5)));
+
+        assert!(grid.voxel_id(&Position::new(-0.1, 0.0, 0.0)).is_none());
+        assert!(grid.voxel_id(&Position::new(10.0, 0.0, 0.0)).is_none());
+        assert!(grid.voxel_center(200).is_none());
+    }
+
+    #[test]
+    fn test_occupancy_windows() {
+        use crate::types::TrajectoryPoint;
+
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [4, 1, 1]);
+        let mut trajectory = Trajectory::new(1);
+        for (t, x) in [(0, 0.2), (100, 0.6), (200, 1.1), (300, 1.5), (400, 2.4)] {
+            trajectory.push(TrajectoryPoint::new(t, Position::new(x, 0.5, 0.5)));
+        }
+
+        let windows = grid.occupancy_windows(&trajectory, 10);
+        assert_eq!(
+            windows,
+            vec![
+                VoxelTimeSlot::new(0, 0, 210),
+                VoxelTimeSlot::new(1, 90, 410),
+                VoxelTimeSlot::new(2, 290, 411),
+            ]
+        );
+    }
+}
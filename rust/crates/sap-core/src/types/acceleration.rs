@@ -43,6 +43,17 @@ impl Acceleration {
         self.magnitude() <= max_accel
     }
 
+    /// 가속도 제한 적용 (클램핑)
+    #[inline]
+    pub fn clamp(&self, max_accel: f32) -> Self {
+        let mag = self.magnitude();
+        if mag <= max_accel || mag == 0.0 {
+            *self
+        } else {
+            self.scale(max_accel / mag)
+        }
+    }
+
     /// 저크(Jerk) 계산 - 가속도 변화율 (m/s³)
     ///
     /// PPR: AI_process_MaxJerk
@@ -85,6 +96,14 @@ mod tests {
         assert!(!accel.within_limit(4.0));
     }
 
+    #[test]
+    fn test_acceleration_clamp() {
+        let accel = Acceleration::new(6.0, 8.0, 0.0); // magnitude = 10.0
+        let clamped = accel.clamp(5.0);
+        assert!((clamped.magnitude() - 5.0).abs() < 1e-6);
+        assert_eq!(accel.clamp(20.0), accel);
+    }
+
     #[test]
     fn test_acceleration_jerk() {
         let prev = Acceleration::new(0.0, 0.0, 0.0);
@@ -0,0 +1,147 @@
+//! 3x3 공분산 타입
+//!
+//! 위치/속도 추정 불확실성 (GPS+IMU 등)을 표현한다.
+//!
+//! PPR 매핑: AI_perceive_RobotState, AI_process_UncertainCollision
+
+use serde::{Deserialize, Serialize};
+
+/// 대칭 3x3 공분산 행렬 (위치: m², 속도: (m/s)²)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub struct Covariance3 {
+    /// XX 성분
+    pub xx: f32,
+    /// XY 성분
+    pub xy: f32,
+    /// XZ 성분
+    pub xz: f32,
+    /// YY 성분
+    pub yy: f32,
+    /// YZ 성분
+    pub yz: f32,
+    /// ZZ 성분
+    pub zz: f32,
+}
+
+impl Covariance3 {
+    /// 불확실성 없음
+    pub const ZERO: Self = Self::diagonal(0.0, 0.0, 0.0);
+
+    /// 대각 공분산 (분산 단위)
+    #[inline]
+    pub const fn diagonal(xx: f32, yy: f32, zz: f32) -> Self {
+        Self {
+            xx,
+            xy: 0.0,
+            xz: 0.0,
+            yy,
+            yz: 0.0,
+            zz,
+        }
+    }
+
+    /// 모든 축 표준편차가 같은 등방성 공분산
+    #[inline]
+    pub fn isotropic(std_dev: f32) -> Self {
+        let variance = std_dev * std_dev;
+        Self::diagonal(variance, variance, variance)
+    }
+
+    /// 축별 표준편차로 생성
+    #[inline]
+    pub fn from_std_devs(sx: f32, sy: f32, sz: f32) -> Self {
+        Self::diagonal(sx * sx, sy * sy, sz * sz)
+    }
+
+    /// 스칼라 배
+    #[inline]
+    pub fn scale(&self, factor: f32) -> Self {
+        Self {
+            xx: self.xx * factor,
+            xy: self.xy * factor,
+            xz: self.xz * factor,
+            yy: self.yy * factor,
+            yz: self.yz * factor,
+            zz: self.zz * factor,
+        }
+    }
+
+    /// 대각합 (총 분산)
+    #[inline]
+    pub fn trace(&self) -> f32 {
+        self.xx + self.yy + self.zz
+    }
+
+    /// 전체 표준편차 상한 sqrt(trace) - 어느 방향 표준편차보다도 크거나 같다
+    #[inline]
+    pub fn std_dev_bound(&self) -> f32 {
+        self.trace().max(0.0).sqrt()
+    }
+
+    /// 지면(XY) 표준편차 상한 sqrt(xx + yy) - 수평 방향 표준편차보다 크거나 같다
+    ///
+    /// 지면 주행 로봇의 여유 거리 판단용 (Z 불확실성 제외)
+    #[inline]
+    pub fn planar_std_dev_bound(&self) -> f32 {
+        (self.xx + self.yy).max(0.0).sqrt()
+    }
+
+    /// 방향 (dx, dy, dz) 으로의 분산 dᵀΣd / |d|²
+    ///
+    /// 방향 벡터가 0이면 최대 대각 성분 반환
+    pub fn variance_along(&self, dx: f32, dy: f32, dz: f32) -> f32 {
+        let norm_sq = dx * dx + dy * dy + dz * dz;
+        if norm_sq < f32::EPSILON {
+            return self.xx.max(self.yy).max(self.zz);
+        }
+        let quad = self.xx * dx * dx
+            + self.yy * dy * dy
+            + self.zz * dz * dz
+            + 2.0 * (self.xy * dx * dy + self.xz * dx * dz + self.yz * dy * dz);
+        (quad / norm_sq).max(0.0)
+    }
+}
+
+impl std::ops::Add for Covariance3 {
+    type Output = Self;
+
+    /// 독립 오차의 합
+    fn add(self, other: Self) -> Self {
+        Self {
+            xx: self.xx + other.xx,
+            xy: self.xy + other.xy,
+            xz: self.xz + other.xz,
+            yy: self.yy + other.yy,
+            yz: self.yz + other.yz,
+            zz: self.zz + other.zz,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_covariance_isotropic() {
+        let cov = Covariance3::isotropic(0.5);
+        assert!((cov.trace() - 0.75).abs() < 1e-6);
+        assert!((cov.variance_along(1.0, 1.0, 0.0) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_covariance_planar_bound_ignores_z() {
+        let cov = Covariance3::from_std_devs(0.3, 0.4, 2.0);
+        assert!((cov.planar_std_dev_bound() - 0.5).abs() < 1e-6);
+        assert!(cov.std_dev_bound() > 2.0);
+    }
+
+    #[test]
+    fn test_covariance_variance_along_axis() {
+        let cov = Covariance3::from_std_devs(0.1, 0.5, 0.0) + Covariance3::ZERO;
+        assert!((cov.variance_along(2.0, 0.0, 0.0) - 0.01).abs() < 1e-6);
+        assert!((cov.variance_along(0.0, 3.0, 0.0) - 0.25).abs() < 1e-6);
+        // 0 방향은 최대 대각 성분
+        assert!((cov.variance_along(0.0, 0.0, 0.0) - 0.25).abs() < 1e-6);
+    }
+}
@@ -1,13 +1,17 @@
 //! 기본 타입 정의 모듈
 
 mod acceleration;
+mod covariance;
 mod position;
 mod robot_state;
+mod trajectory;
 mod velocity;
 mod world_state;
 
 pub use acceleration::Acceleration;
+pub use covariance::Covariance3;
 pub use position::Position;
 pub use robot_state::RobotState;
+pub use trajectory::{Trajectory, TrajectoryPoint};
 pub use velocity::Velocity;
 pub use world_state::{DynamicObstacle, VtsAllocationInfo, WorldState};
@@ -2,7 +2,7 @@
 //!
 //! PPR 매핑: AI_perceive_RobotState, AI_perceive_CurrentState
 
-use super::{Acceleration, Position, Velocity};
+use super::{Acceleration, Covariance3, Position, Velocity};
 use serde::{Deserialize, Serialize};
 
 /// 로봇의 전체 상태
@@ -43,6 +43,12 @@ pub struct RobotState {
 
     /// 컨트롤러 온도 (섭씨)
     pub controller_temp_c: i16,
+
+    /// 위치 추정 공분산 (m², 없으면 정확한 위치로 간주)
+    pub position_covariance: Option<Covariance3>,
+
+    /// 속도 추정 공분산 ((m/s)², 없으면 정확한 속도로 간주)
+    pub velocity_covariance: Option<Covariance3>,
 }
 
 impl RobotState {
@@ -61,6 +67,8 @@ impl RobotState {
             ticket_phase: 0.0,
             battery_soc_milli: 100_000, // 100%
             controller_temp_c: 25,
+            position_covariance: None,
+            velocity_covariance: None,
         }
     }
 
@@ -84,6 +92,13 @@ impl RobotState {
         self
     }
 
+    /// 위치/속도 추정 공분산 설정
+    pub fn with_covariance(mut self, position: Covariance3, velocity: Covariance3) -> Self {
+        self.position_covariance = Some(position);
+        self.velocity_covariance = Some(velocity);
+        self
+    }
+
     /// 속력(speed) 반환
     #[inline]
     pub fn speed(&self) -> f32 {
@@ -147,4 +162,19 @@ mod tests {
         assert_eq!(state.robot_id, decoded.robot_id);
         assert_eq!(state.position, decoded.position);
     }
+
+    #[test]
+    fn test_robot_state_covariance_roundtrip() {
+        let state = RobotState::new(7)
+            .with_covariance(Covariance3::isotropic(0.3), Covariance3::isotropic(0.1));
+
+        let encoded = bincode::serialize(&state).unwrap();
+        let decoded: RobotState = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(
+            decoded.position_covariance,
+            Some(Covariance3::isotropic(0.3))
+        );
+        assert_eq!(RobotState::new(7).position_covariance, None);
+    }
 }
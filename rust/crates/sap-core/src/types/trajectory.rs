@@ -0,0 +1,193 @@
+//! 궤적 타입
+//!
+//! 플래너가 제출하는 시간 매개변수화된 경로
+//!
+//! PPR 매핑: AI_perceive_Trajectory
+
+use super::{Acceleration, Position, Velocity};
+use serde::{Deserialize, Serialize};
+
+/// 궤적 샘플 (시각별 자세/속도/가속도)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrajectoryPoint {
+    /// PTP 동기화된 타임스탬프 (나노초)
+    pub timestamp_ns: u64,
+
+    /// 위치
+    pub position: Position,
+
+    /// 방향각 (라디안)
+    pub theta: f32,
+
+    /// 속도
+    pub velocity: Velocity,
+
+    /// 가속도
+    pub acceleration: Acceleration,
+}
+
+impl TrajectoryPoint {
+    /// 새 TrajectoryPoint 생성
+    pub fn new(timestamp_ns: u64, position: Position) -> Self {
+        Self {
+            timestamp_ns,
+            position,
+            theta: 0.0,
+            velocity: Velocity::ZERO,
+            acceleration: Acceleration::ZERO,
+        }
+    }
+
+    /// 속도 설정
+    pub fn with_velocity(mut self, velocity: Velocity) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// 가속도 설정
+    pub fn with_acceleration(mut self, acceleration: Acceleration) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// 방향각 설정
+    pub fn with_theta(mut self, theta: f32) -> Self {
+        self.theta = theta;
+        self
+    }
+}
+
+/// 궤적 (시간순 샘플 목록)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trajectory {
+    /// 로봇 ID
+    pub robot_id: u64,
+
+    /// 시간순 샘플 (타임스탬프 엄격 증가)
+    points: Vec<TrajectoryPoint>,
+}
+
+impl Trajectory {
+    /// 빈 궤적 생성
+    pub fn new(robot_id: u64) -> Self {
+        Self {
+            robot_id,
+            points: Vec::new(),
+        }
+    }
+
+    /// 샘플 목록으로 생성 (시간순이 아니면 None)
+    pub fn from_points(robot_id: u64, points: Vec<TrajectoryPoint>) -> Option<Self> {
+        let ordered = points
+            .windows(2)
+            .all(|w| w[0].timestamp_ns < w[1].timestamp_ns);
+        ordered.then_some(Self { robot_id, points })
+    }
+
+    /// 샘플 추가 (마지막 샘플보다 늦어야 함)
+    pub fn push(&mut self, point: TrajectoryPoint) -> bool {
+        if let Some(last) = self.points.last() {
+            if point.timestamp_ns <= last.timestamp_ns {
+                return false;
+            }
+        }
+        self.points.push(point);
+        true
+    }
+
+    /// 샘플 목록
+    #[inline]
+    pub fn points(&self) -> &[TrajectoryPoint] {
+        &self.points
+    }
+
+    /// 샘플 수
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 비어있는지 확인
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// 시작 시각 (나노초)
+    pub fn start_ns(&self) -> Option<u64> {
+        self.points.first().map(|p| p.timestamp_ns)
+    }
+
+    /// 종료(도착) 시각 (나노초)
+    pub fn end_ns(&self) -> Option<u64> {
+        self.points.last().map(|p| p.timestamp_ns)
+    }
+
+    /// 총 소요 시간 (나노초)
+    pub fn duration_ns(&self) -> u64 {
+        match (self.start_ns(), self.end_ns()) {
+            (Some(start), Some(end)) => end - start,
+            _ => 0,
+        }
+    }
+
+    /// 특정 시각의 위치 (구간 선형 보간, 범위 밖이면 None)
+    pub fn position_at(&self, timestamp_ns: u64) -> Option<Position> {
+        let idx = self
+            .points
+            .partition_point(|p| p.timestamp_ns <= timestamp_ns);
+        if idx == 0 {
+            return None;
+        }
+        let prev = &self.points[idx - 1];
+        if prev.timestamp_ns == timestamp_ns {
+            return Some(prev.position);
+        }
+        let next = self.points.get(idx)?;
+        let ratio = (timestamp_ns - prev.timestamp_ns) as f32
+            / (next.timestamp_ns - prev.timestamp_ns) as f32;
+        Some(prev.position + (next.position - prev.position).scale(ratio))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_trajectory() -> Trajectory {
+        let mut trajectory = Trajectory::new(7);
+        assert!(trajectory.push(TrajectoryPoint::new(1_000, Position::ORIGIN)));
+        assert!(trajectory.push(TrajectoryPoint::new(2_000, Position::new(1.0, 0.0, 0.0))));
+        assert!(trajectory.push(TrajectoryPoint::new(4_000, Position::new(1.0, 2.0, 0.0))));
+        trajectory
+    }
+
+    #[test]
+    fn test_trajectory_push_requires_increasing_time() {
+        let mut trajectory = sample_trajectory();
+        assert!(!trajectory.push(TrajectoryPoint::new(4_000, Position::ORIGIN)));
+        assert_eq!(trajectory.len(), 3);
+        assert_eq!(trajectory.duration_ns(), 3_000);
+
+        let unordered = vec![
+            TrajectoryPoint::new(2_000, Position::ORIGIN),
+            TrajectoryPoint::new(1_000, Position::ORIGIN),
+        ];
+        assert!(Trajectory::from_points(1, unordered).is_none());
+    }
+
+    #[test]
+    fn test_trajectory_position_at() {
+        let trajectory = sample_trajectory();
+        assert!(trajectory.position_at(500).is_none());
+        assert!(trajectory.position_at(5_000).is_none());
+        assert_eq!(
+            trajectory.position_at(2_000),
+            Some(Position::new(1.0, 0.0, 0.0))
+        );
+
+        let mid = trajectory.position_at(3_000).unwrap();
+        assert!((mid.x - 1.0).abs() < 1e-6);
+        assert!((mid.y - 1.0).abs() < 1e-6);
+    }
+}
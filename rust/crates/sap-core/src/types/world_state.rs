@@ -6,7 +6,7 @@ use super::RobotState;
 use serde::{Deserialize, Serialize};
 
 /// 전체 월드 상태 (Zone 내 모든 로봇 + 장애물)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorldState {
     /// Zone ID
     pub zone_id: u32,
@@ -31,7 +31,7 @@ pub struct WorldState {
 }
 
 /// VTS 할당 정보
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VtsAllocationInfo {
     /// VTS ID
     pub vts_id: u128,
@@ -46,7 +46,7 @@ pub struct VtsAllocationInfo {
 }
 
 /// 동적 장애물
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DynamicObstacle {
     /// 장애물 ID
     pub id: u64,
@@ -59,6 +59,42 @@ pub struct DynamicObstacle {
 
     /// 바운딩 박스 반경 (미터)
     pub radius: f32,
+
+    /// 위치 추정 공분산 (m², 없으면 정확한 위치로 간주)
+    pub position_covariance: Option<crate::types::Covariance3>,
+
+    /// 속도 추정 공분산 ((m/s)², 없으면 정확한 속도로 간주)
+    pub velocity_covariance: Option<crate::types::Covariance3>,
+}
+
+impl DynamicObstacle {
+    /// 새 DynamicObstacle 생성 (공분산 없음)
+    pub fn new(
+        id: u64,
+        position: crate::types::Position,
+        velocity: crate::types::Velocity,
+        radius: f32,
+    ) -> Self {
+        Self {
+            id,
+            position,
+            velocity,
+            radius,
+            position_covariance: None,
+            velocity_covariance: None,
+        }
+    }
+
+    /// 위치/속도 추정 공분산 설정
+    pub fn with_covariance(
+        mut self,
+        position: crate::types::Covariance3,
+        velocity: crate::types::Covariance3,
+    ) -> Self {
+        self.position_covariance = Some(position);
+        self.velocity_covariance = Some(velocity);
+        self
+    }
 }
 
 impl WorldState {
@@ -110,6 +146,26 @@ impl WorldState {
         self.robots.len()
     }
 
+    /// 메모리 사용량 추정 (바이트, 스냅샷 예산 계산용)
+    pub fn estimated_size_bytes(&self) -> usize {
+        use std::mem::size_of;
+
+        let vts: usize = self
+            .vts_allocations
+            .values()
+            .map(|allocations| {
+                size_of::<u64>()
+                    + size_of::<Vec<VtsAllocationInfo>>()
+                    + allocations.len() * size_of::<VtsAllocationInfo>()
+            })
+            .sum();
+        size_of::<Self>()
+            + self.robots.len() * size_of::<RobotState>()
+            + self.static_obstacles.len() * size_of::<crate::types::Position>()
+            + self.dynamic_obstacles.len() * size_of::<DynamicObstacle>()
+            + vts
+    }
+
     /// 월드 상태 해시 (롤백용)
     pub fn compute_hash(&self) -> [u8; 32] {
         use std::collections::hash_map::DefaultHasher;
@@ -117,10 +173,17 @@ impl WorldState {
 
         let mut hasher = DefaultHasher::new();
 
-        // 간소화된 해시: zone_id + tick + 로봇 수
+        // zone_id + tick + 로봇별 ID/위치/방향 (f32 비트 패턴)
         self.zone_id.hash(&mut hasher);
         self.tick.hash(&mut hasher);
         self.robots.len().hash(&mut hasher);
+        for robot in &self.robots {
+            robot.robot_id.hash(&mut hasher);
+            robot.position.x.to_bits().hash(&mut hasher);
+            robot.position.y.to_bits().hash(&mut hasher);
+            robot.position.z.to_bits().hash(&mut hasher);
+            robot.theta.to_bits().hash(&mut hasher);
+        }
 
         let hash = hasher.finish();
         let mut result = [0u8; 32];
@@ -168,6 +231,17 @@ mod tests {
         assert_eq!(robot.position.x, 10.0);
     }
 
+    #[test]
+    fn test_world_state_estimated_size() {
+        let mut world = WorldState::new(1);
+        let empty = world.estimated_size_bytes();
+        world.add_robot(RobotState::new(42));
+        assert_eq!(
+            world.estimated_size_bytes(),
+            empty + std::mem::size_of::<RobotState>()
+        );
+    }
+
     #[test]
     fn test_world_state_hash() {
         let world1 = WorldState::new(1).with_tick(100, 0);
@@ -176,5 +250,14 @@ mod tests {
 
         assert_eq!(world1.compute_hash(), world2.compute_hash());
         assert_ne!(world1.compute_hash(), world3.compute_hash());
+
+        // 로봇 위치가 다르면 해시도 달라야 함
+        let mut moved = world1.clone();
+        moved.add_robot(RobotState::new(42));
+        let before = moved.compute_hash();
+        moved.update_robot(
+            RobotState::new(42).with_motion(Position::new(0.1, 0.0, 0.0), Velocity::ZERO),
+        );
+        assert_ne!(before, moved.compute_hash());
     }
 }
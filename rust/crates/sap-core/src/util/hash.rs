@@ -59,6 +59,60 @@ pub fn compute_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
     level[0]
 }
 
+/// Merkle 포함 증명 계산
+///
+/// `index`번째 잎에서 루트까지의 형제 해시 목록 (잎 쪽부터).
+/// 홀수 노드는 `compute_merkle_root`와 같이 자기 자신을 형제로 쓴다.
+pub fn compute_merkle_proof(leaves: &[[u8; 32]], index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut proof = Vec::new();
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    let mut position = index;
+
+    while level.len() > 1 {
+        let sibling = position ^ 1;
+        proof.push(*level.get(sibling).unwrap_or(&level[position]));
+
+        level = level
+            .chunks(2)
+            .map(|chunk| hash_pair(&chunk[0], chunk.get(1).unwrap_or(&chunk[0])))
+            .collect();
+        position /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Merkle 포함 증명 검증
+pub fn verify_merkle_proof(
+    leaf: &[u8; 32],
+    index: usize,
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+) -> bool {
+    let mut hash = *leaf;
+    let mut position = index;
+    for sibling in proof {
+        hash = if position & 1 == 0 {
+            hash_pair(&hash, sibling)
+        } else {
+            hash_pair(sibling, &hash)
+        };
+        position /= 2;
+    }
+    position == 0 && hash == *root
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut combined = [0u8; 64];
+    combined[0..32].copy_from_slice(left);
+    combined[32..64].copy_from_slice(right);
+    compute_hash_bytes(&combined)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +146,24 @@ mod tests {
         assert_ne!(root, leaf2);
     }
 
+    #[test]
+    fn test_merkle_proof_roundtrip() {
+        let leaves: Vec<[u8; 32]> = (0..7u8).map(|i| compute_hash(&i)).collect();
+        let root = compute_merkle_root(&leaves);
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = compute_merkle_proof(&leaves, index).unwrap();
+            assert!(verify_merkle_proof(leaf, index, &proof, &root));
+            assert!(!verify_merkle_proof(&[9u8; 32], index, &proof, &root));
+        }
+        assert!(compute_merkle_proof(&leaves, 7).is_none());
+
+        // 단일 잎: 빈 증명
+        let single = compute_merkle_proof(&leaves[..1], 0).unwrap();
+        assert!(single.is_empty());
+        assert!(verify_merkle_proof(&leaves[0], 0, &single, &leaves[0]));
+    }
+
     #[test]
     fn test_merkle_root_empty() {
         let root = compute_merkle_root(&[]);
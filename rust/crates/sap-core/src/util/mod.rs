@@ -3,5 +3,8 @@
 mod hash;
 mod time;
 
-pub use hash::{compute_hash, compute_merkle_root};
+pub use hash::{
+    compute_hash, compute_hash_bytes, compute_merkle_proof, compute_merkle_root,
+    verify_merkle_proof,
+};
 pub use time::{current_time_ns, ms_to_ns, ns_to_ms};
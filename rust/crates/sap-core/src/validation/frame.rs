@@ -2,6 +2,7 @@
 //!
 //! PPR 매핑: AI_make_ValidationFrame
 
+use crate::util::compute_hash_bytes;
 use serde::{Deserialize, Serialize};
 
 /// 단일 검증 프레임 (zk-Physics 준비용)
@@ -27,9 +28,18 @@ pub struct ValidationFrame {
     /// bit 3: 지오펜스
     /// bit 4: 충돌 예측
     /// bit 5: 티켓 유효성
-    /// bit 6-63: 예약
+    /// bit 6: VTS 준수
+    /// bit 7: 속도-분리 감시
+    /// bit 8: 각속도 제한
+    /// bit 9: 각가속도 제한
+    /// bit 10-63: 예약
     pub constraints_passed_bitmap: u64,
 
+    /// 실패한 제약조건 비트맵 (비트 배치는 위와 동일)
+    ///
+    /// 두 비트맵 모두 0인 비트는 검사하지 않은 제약조건
+    pub constraints_failed_bitmap: u64,
+
     /// 검증 시각 (PTP 나노초)
     pub timestamp_ns: u64,
 
@@ -45,6 +55,7 @@ impl ValidationFrame {
             robot_id,
             cmd_hash: [0u8; 32],
             constraints_passed_bitmap: 0,
+            constraints_failed_bitmap: 0,
             timestamp_ns: 0,
             zone_id,
         }
@@ -62,13 +73,22 @@ impl ValidationFrame {
         self
     }
 
-    /// 제약조건 통과 설정
+    /// 제약조건 비트맵 설정 (통과/실패)
+    pub fn with_bitmaps(mut self, passed: u64, failed: u64) -> Self {
+        self.constraints_passed_bitmap = passed;
+        self.constraints_failed_bitmap = failed & !passed;
+        self
+    }
+
+    /// 제약조건 통과/실패 설정
     pub fn set_constraint(&mut self, constraint_id: u8, passed: bool) {
         if constraint_id < 64 {
             if passed {
                 self.constraints_passed_bitmap |= 1 << constraint_id;
+                self.constraints_failed_bitmap &= !(1 << constraint_id);
             } else {
                 self.constraints_passed_bitmap &= !(1 << constraint_id);
+                self.constraints_failed_bitmap |= 1 << constraint_id;
             }
         }
     }
@@ -82,6 +102,15 @@ impl ValidationFrame {
         }
     }
 
+    /// 제약조건 실패 여부 확인
+    pub fn check_failed(&self, constraint_id: u8) -> bool {
+        if constraint_id < 64 {
+            (self.constraints_failed_bitmap & (1 << constraint_id)) != 0
+        } else {
+            false
+        }
+    }
+
     /// 모든 제약조건 통과 여부
     pub fn all_passed(&self, required_mask: u64) -> bool {
         (self.constraints_passed_bitmap & required_mask) == required_mask
@@ -91,6 +120,19 @@ impl ValidationFrame {
     pub fn passed_count(&self) -> u32 {
         self.constraints_passed_bitmap.count_ones()
     }
+
+    /// Merkle 잎 해시 (모든 필드 포함)
+    pub fn leaf_hash(&self) -> [u8; 32] {
+        let mut bytes = Vec::with_capacity(76);
+        bytes.extend_from_slice(&self.tick.to_le_bytes());
+        bytes.extend_from_slice(&self.robot_id.to_le_bytes());
+        bytes.extend_from_slice(&self.cmd_hash);
+        bytes.extend_from_slice(&self.constraints_passed_bitmap.to_le_bytes());
+        bytes.extend_from_slice(&self.constraints_failed_bitmap.to_le_bytes());
+        bytes.extend_from_slice(&self.timestamp_ns.to_le_bytes());
+        bytes.extend_from_slice(&self.zone_id.to_le_bytes());
+        compute_hash_bytes(&bytes)
+    }
 }
 
 /// 제약조건 ID 상수
@@ -102,6 +144,10 @@ pub mod constraint_ids {
     pub const COLLISION_PREDICTION: u8 = 4;
     pub const TICKET_VALIDITY: u8 = 5;
     pub const VTS_COMPLIANCE: u8 = 6;
+    pub const SPEED_SEPARATION: u8 = 7;
+    pub const ANGULAR_VELOCITY_LIMIT: u8 = 8;
+    pub const ANGULAR_ACCELERATION_LIMIT: u8 = 9;
+    pub const POSITION_UNCERTAINTY: u8 = 10;
 }
 
 #[cfg(test)]
@@ -127,6 +173,11 @@ mod tests {
         assert!(frame.check_constraint(constraint_ids::GEOFENCE));
 
         assert!(!frame.check_constraint(constraint_ids::COLLISION_PREDICTION));
+        assert!(!frame.check_failed(constraint_ids::COLLISION_PREDICTION));
+
+        frame.set_constraint(constraint_ids::COLLISION_PREDICTION, false);
+        assert!(frame.check_failed(constraint_ids::COLLISION_PREDICTION));
+        assert!(!frame.check_constraint(constraint_ids::COLLISION_PREDICTION));
     }
 
     #[test]
@@ -158,4 +209,14 @@ mod tests {
 
         assert_eq!(frame.passed_count(), 3);
     }
+
+    #[test]
+    fn test_validation_frame_leaf_hash() {
+        let frame = ValidationFrame::new(1, 1, 1).with_bitmaps(0b11, 0b100);
+        assert_eq!(frame.leaf_hash(), frame.clone().leaf_hash());
+
+        let mut tampered = frame.clone();
+        tampered.set_constraint(2, true);
+        assert_ne!(frame.leaf_hash(), tampered.leaf_hash());
+    }
 }
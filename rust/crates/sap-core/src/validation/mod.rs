@@ -6,4 +6,4 @@ mod result;
 
 pub use frame::{constraint_ids, ValidationFrame};
 pub use proof::ProofDigest;
-pub use result::{AdjustedCommand, ValidationResult, ValidationResultDetail};
+pub use result::{AdjustedCommand, ValidationReason, ValidationResult, ValidationResultDetail};
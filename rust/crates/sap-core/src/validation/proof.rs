@@ -63,6 +63,18 @@ impl ProofDigest {
         self
     }
 
+    /// 서명 대상 바이트 (서명 필드 제외)
+    pub fn signing_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64);
+        bytes.extend_from_slice(&self.zone_id.to_le_bytes());
+        bytes.extend_from_slice(&self.from_tick.to_le_bytes());
+        bytes.extend_from_slice(&self.to_tick.to_le_bytes());
+        bytes.extend_from_slice(&self.frame_count.to_le_bytes());
+        bytes.extend_from_slice(&self.created_at_ns.to_le_bytes());
+        bytes.extend_from_slice(&self.merkle_root);
+        bytes
+    }
+
     /// 틱 범위 확인
     pub fn tick_range(&self) -> u64 {
         self.to_tick.saturating_sub(self.from_tick)
@@ -68,15 +68,25 @@ impl std::fmt::Display for ValidationResult {
 }
 
 /// 상세한 검증 결과 (이유 포함)
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationResultDetail {
     /// 기본 결과
     pub result: ValidationResult,
 
-    /// 거부/조정 이유 (있으면)
+    /// 거부/조정 대표 이유 (있으면)
     pub reason: Option<ValidationReason>,
 
+    /// 실패한 모든 제약조건의 이유 (검사 순서)
+    pub violations: Vec<ValidationReason>,
+
+    /// 통과한 제약조건 비트맵 (constraint_ids 기준)
+    pub constraints_passed_bitmap: u64,
+
+    /// 실패한 제약조건 비트맵 (constraint_ids 기준)
+    ///
+    /// 두 비트맵 모두 0인 비트는 검사하지 않은 제약조건
+    pub constraints_failed_bitmap: u64,
+
     /// 조정된 명령 (ADJUST 시 사용)
     pub adjusted_command: Option<AdjustedCommand>,
 
@@ -97,7 +107,7 @@ pub struct ValidationResultDetail {
 pub struct AdjustedCommand {
     /// 조정된 선속도 (m/s)
     pub adjusted_velocity: f32,
-    /// 조정된 각속도 (rad/s)  
+    /// 조정된 각속도 (rad/s)
     pub adjusted_angular_velocity: f32,
     /// 조정된 가속도 (m/s²)
     pub adjusted_acceleration: f32,
@@ -132,7 +142,6 @@ impl AdjustedCommand {
 }
 
 /// 검증 실패 이유
-#[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ValidationReason {
     /// 최대 속도 초과
@@ -144,6 +153,12 @@ pub enum ValidationReason {
     /// 최대 저크 초과
     JerkExceeded { actual: f32, limit: f32 },
 
+    /// 최대 각속도 초과
+    AngularVelocityExceeded { actual: f32, limit: f32 },
+
+    /// 최대 각가속도 초과
+    AngularAccelerationExceeded { actual: f32, limit: f32 },
+
     /// 충돌 예측
     CollisionPredicted {
         time_to_collision: f32,
@@ -158,15 +173,34 @@ pub enum ValidationReason {
 
     /// VoxelTimeSlot 위반
     VTSViolation { vts_id: u64 },
+
+    /// 분리 거리 대비 속도 초과 (속도-분리 감시)
+    SeparationSpeedExceeded {
+        actual: f32,
+        limit: f32,
+        separation: f32,
+    },
+
+    /// 위치 불확실성 기반 충돌 확률 초과
+    CollisionProbabilityExceeded {
+        probability: f32,
+        threshold: f32,
+        obstacle_id: u64,
+    },
+
+    /// 위치 추정 불확실성 과다 (감속)
+    PositionUncertaintyHigh { std_dev: f32, limit: f32 },
 }
 
-#[allow(dead_code)]
 impl ValidationResultDetail {
     /// 모두 통과한 결과 생성
     pub fn ok() -> Self {
         Self {
             result: ValidationResult::OK,
             reason: None,
+            violations: Vec::new(),
+            constraints_passed_bitmap: 0,
+            constraints_failed_bitmap: 0,
             adjusted_command: None,
             kinematics_ok: true,
             collision_ok: true,
@@ -178,7 +212,10 @@ impl ValidationResultDetail {
     pub fn reject(reason: ValidationReason) -> Self {
         Self {
             result: ValidationResult::REJECT,
-            reason: Some(reason),
+            reason: Some(reason.clone()),
+            violations: vec![reason],
+            constraints_passed_bitmap: 0,
+            constraints_failed_bitmap: 0,
             adjusted_command: None,
             kinematics_ok: false,
             collision_ok: false,
@@ -190,7 +227,10 @@ impl ValidationResultDetail {
     pub fn adjust(reason: ValidationReason, adjusted: AdjustedCommand) -> Self {
         Self {
             result: ValidationResult::ADJUST,
-            reason: Some(reason),
+            reason: Some(reason.clone()),
+            violations: vec![reason],
+            constraints_passed_bitmap: 0,
+            constraints_failed_bitmap: 0,
             adjusted_command: Some(adjusted),
             kinematics_ok: false,
             collision_ok: true,
@@ -202,13 +242,48 @@ impl ValidationResultDetail {
     pub fn adjust_without_command(reason: ValidationReason) -> Self {
         Self {
             result: ValidationResult::ADJUST,
-            reason: Some(reason),
+            reason: Some(reason.clone()),
+            violations: vec![reason],
+            constraints_passed_bitmap: 0,
+            constraints_failed_bitmap: 0,
             adjusted_command: None,
             kinematics_ok: false,
             collision_ok: true,
             constraint_ok: true,
         }
     }
+
+    /// 제약조건 통과 기록
+    pub fn record_pass(&mut self, constraint_id: u8) {
+        if constraint_id < 64 {
+            self.constraints_passed_bitmap |= 1 << constraint_id;
+            self.constraints_failed_bitmap &= !(1 << constraint_id);
+        }
+    }
+
+    /// 제약조건 위반 기록 (비트맵 + 이유)
+    pub fn record_violation(&mut self, constraint_id: u8, reason: ValidationReason) {
+        if constraint_id < 64 {
+            self.constraints_failed_bitmap |= 1 << constraint_id;
+            self.constraints_passed_bitmap &= !(1 << constraint_id);
+        }
+        self.violations.push(reason);
+    }
+
+    /// 제약조건 통과 여부
+    pub fn is_passed(&self, constraint_id: u8) -> bool {
+        constraint_id < 64 && (self.constraints_passed_bitmap & (1 << constraint_id)) != 0
+    }
+
+    /// 제약조건 실패 여부
+    pub fn is_failed(&self, constraint_id: u8) -> bool {
+        constraint_id < 64 && (self.constraints_failed_bitmap & (1 << constraint_id)) != 0
+    }
+
+    /// 실패한 제약조건 수
+    pub fn failed_count(&self) -> u32 {
+        self.constraints_failed_bitmap.count_ones()
+    }
 }
 
 #[cfg(test)]
@@ -246,5 +321,28 @@ mod tests {
         });
         assert!(detail.result.is_rejected());
         assert!(detail.reason.is_some());
+        assert_eq!(detail.violations.len(), 1);
+    }
+
+    #[test]
+    fn test_validation_result_detail_bitmaps() {
+        let mut detail = ValidationResultDetail::ok();
+        detail.record_pass(0);
+        detail.record_violation(
+            1,
+            ValidationReason::AccelerationExceeded {
+                actual: 12.0,
+                limit: 9.8,
+            },
+        );
+
+        assert!(detail.is_passed(0));
+        assert!(!detail.is_failed(0));
+        assert!(detail.is_failed(1));
+        assert!(!detail.is_passed(1));
+        // 검사하지 않은 제약조건은 양쪽 모두 false
+        assert!(!detail.is_passed(4) && !detail.is_failed(4));
+        assert_eq!(detail.failed_count(), 1);
+        assert_eq!(detail.violations.len(), 1);
     }
 }
@@ -3,10 +3,12 @@
 //! PPR 매핑: AI_make_VickreyAuction
 
 use sap_core::ticket::Bid;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// 경매 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct AuctionConfig {
     /// 최소 입찰 금액 (milli 단위) - 이보다 낮은 입찰은 거부
     pub min_bid: u64,
@@ -108,7 +110,7 @@ impl VickreyAuction {
             return None;
         }
 
-        entries.sort_by(|a, b| b.bid_amount.cmp(&a.bid_amount));
+        entries.sort_by_key(|a| std::cmp::Reverse(a.bid_amount));
 
         let winner = &entries[0];
 
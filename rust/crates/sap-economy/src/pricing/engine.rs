@@ -2,10 +2,12 @@
 //!
 //! PPR 매핑: AI_make_PricingEngine
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// 가격 결정 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct PricingConfig {
     /// 기본 가격
     pub base_price: u64,
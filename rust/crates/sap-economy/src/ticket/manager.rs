@@ -2,7 +2,7 @@
 //!
 //! PPR 매핑: AI_make_TransitTicketManager
 
-use sap_core::ticket::TransitTicket;
+use sap_core::ticket::{TicketStore, TransitTicket, VoxelTimeSlot};
 use std::collections::HashMap;
 
 /// TransitTicket 관리자
@@ -27,11 +27,11 @@ impl TicketManager {
         }
     }
 
-    /// 티켓 발행
+    /// 티켓 발행 - 낙찰된 VTS(복셀 ID)를 유효기간 동안 점유하는 슬롯으로 첨부
     pub fn issue_ticket(
         &mut self,
         robot_id: u64,
-        _vts_id: u64,
+        vts_id: u64,
         valid_from_ns: u64,
         valid_to_ns: u64,
     ) -> TransitTicket {
@@ -39,7 +39,7 @@ impl TicketManager {
         let ticket_id = self.issue_counter;
 
         let ticket = TransitTicket::new(ticket_id, robot_id, self.zone_id)
-            .with_validity(valid_from_ns, valid_to_ns);
+            .with_vts(vec![VoxelTimeSlot::new(vts_id, valid_from_ns, valid_to_ns)]);
 
         self.active_tickets.insert(ticket_id, ticket.clone());
         ticket
@@ -117,6 +117,12 @@ impl TicketManager {
     }
 }
 
+impl TicketStore for TicketManager {
+    fn ticket(&self, ticket_id: u128) -> Option<&TransitTicket> {
+        self.get_ticket(ticket_id)
+    }
+}
+
 /// 티켓 검증 결과
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TicketValidation {
@@ -137,6 +143,7 @@ mod tests {
         let ticket = manager.issue_ticket(42, 100, 1000, 5000);
 
         assert_eq!(ticket.robot_id, 42);
+        assert_eq!(ticket.vts_list, vec![VoxelTimeSlot::new(100, 1000, 5000)]);
         assert_eq!(manager.active_count(), 1);
         assert_eq!(manager.total_issued(), 1);
     }
@@ -19,9 +19,12 @@
 //! ## 주요 컴포넌트
 //!
 //! - `EdgeRuntime`: 통합 런타임 (통합 테스트용)
+//! - `DomainProfile`: 도메인별 TOML 설정 (WAREHOUSE/FAB/HOSPITAL 프리셋)
 
+pub mod profile;
 pub mod runtime;
 
+pub use profile::{DomainKind, DomainProfile, ProfileError, VtsSettings};
 pub use runtime::EdgeRuntime;
 
 // 핵심 크레이트 re-export
@@ -0,0 +1,601 @@
+//! DomainProfile - 도메인별 파라미터 세트 (TOML)
+//!
+//! 물리 검증, 동기화, 경제 설정을 하나의 TOML 파일로 묶어 로드한다.
+//! 로드 시 값 범위를 검증하며, 문서화된 세 도메인(WAREHOUSE/FAB/HOSPITAL)은
+//! 번들 프리셋으로 제공된다. (docs/profiles/DomainProfiles.md)
+
+use sap_economy::auction::AuctionConfig;
+use sap_economy::pricing::PricingConfig;
+use sap_network::failsafe::FailsafeConfig;
+use sap_network::rollback::{RollbackConfig, SnapshotStrategy};
+use sap_physics::constraint::DynamicHorizonConfig;
+use sap_physics::kinematics::VehicleProfile;
+use sap_physics::validator::PhysicsValidatorConfig;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// 문서화된 도메인
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DomainKind {
+    /// 물류 창고
+    Warehouse,
+    /// 반도체 공장
+    Fab,
+    /// 병원
+    Hospital,
+}
+
+impl DomainKind {
+    /// 전체 도메인 목록
+    pub const ALL: [DomainKind; 3] = [Self::Warehouse, Self::Fab, Self::Hospital];
+
+    /// 프로파일 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Warehouse => "warehouse",
+            Self::Fab => "fab",
+            Self::Hospital => "hospital",
+        }
+    }
+
+    /// 이름으로 도메인 조회 (대소문자 무시)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+
+    /// 번들 프리셋 TOML 원문
+    pub fn preset_toml(&self) -> &'static str {
+        match self {
+            Self::Warehouse => include_str!("../profiles/warehouse.toml"),
+            Self::Fab => include_str!("../profiles/fab.toml"),
+            Self::Hospital => include_str!("../profiles/hospital.toml"),
+        }
+    }
+}
+
+/// VTS(복셀-시간 슬롯) 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VtsSettings {
+    /// 복셀 한 변 길이 (m)
+    pub voxel_size_m: f32,
+
+    /// 시간 슬롯 길이 (밀리초)
+    pub time_slot_ms: u64,
+
+    /// 로봇당 최대 VTS 수
+    pub max_vts_per_robot: u32,
+}
+
+impl Default for VtsSettings {
+    fn default() -> Self {
+        Self {
+            voxel_size_m: 1.0,
+            time_slot_ms: 100,
+            max_vts_per_robot: 50,
+        }
+    }
+}
+
+/// 프로파일 로드/검증 에러
+#[derive(Error, Debug)]
+pub enum ProfileError {
+    #[error("Failed to read profile {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse profile: {0}")]
+    Parse(#[from] toml::de::Error),
+
+    #[error("Failed to serialize profile: {0}")]
+    Serialize(#[from] toml::ser::Error),
+
+    #[error("{field} out of range: {value} (expected {expected})")]
+    OutOfRange {
+        field: String,
+        value: f64,
+        expected: &'static str,
+    },
+}
+
+/// 도메인 프로파일
+///
+/// 누락된 섹션/필드는 각 설정의 기본값을 사용한다.
+/// `horizon` 섹션이 있으면 동적 충돌 예측 지평을 사용한다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DomainProfile {
+    /// 프로파일 이름
+    pub name: String,
+
+    /// 설명
+    #[serde(default)]
+    pub description: String,
+
+    /// VTS 설정
+    #[serde(default)]
+    pub vts: VtsSettings,
+
+    /// 물리 검증 설정
+    #[serde(default)]
+    pub physics: PhysicsValidatorConfig,
+
+    /// 동적 예측 지평 (None이면 고정 지평)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub horizon: Option<DynamicHorizonConfig>,
+
+    /// 차량 프로파일 (이름 → 프로파일)
+    #[serde(default)]
+    pub vehicles: BTreeMap<String, VehicleProfile>,
+
+    /// 롤백 설정
+    #[serde(default)]
+    pub rollback: RollbackConfig,
+
+    /// Failsafe 설정
+    #[serde(default)]
+    pub failsafe: FailsafeConfig,
+
+    /// 경매 설정
+    #[serde(default)]
+    pub auction: AuctionConfig,
+
+    /// 가격 결정 설정
+    #[serde(default)]
+    pub pricing: PricingConfig,
+}
+
+impl DomainProfile {
+    /// 번들 프리셋 로드
+    pub fn preset(kind: DomainKind) -> Self {
+        Self::from_toml_str(kind.preset_toml()).expect("bundled domain profile must be valid")
+    }
+
+    /// WAREHOUSE 프리셋
+    pub fn warehouse() -> Self {
+        Self::preset(DomainKind::Warehouse)
+    }
+
+    /// FAB 프리셋
+    pub fn fab() -> Self {
+        Self::preset(DomainKind::Fab)
+    }
+
+    /// HOSPITAL 프리셋
+    pub fn hospital() -> Self {
+        Self::preset(DomainKind::Hospital)
+    }
+
+    /// TOML 문자열에서 로드 (범위 검증 포함)
+    pub fn from_toml_str(source: &str) -> Result<Self, ProfileError> {
+        let profile: Self = toml::from_str(source)?;
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    /// TOML 파일에서 로드 (범위 검증 포함)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProfileError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|source| ProfileError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_toml_str(&source)
+    }
+
+    /// TOML 문자열로 직렬화
+    pub fn to_toml_string(&self) -> Result<String, ProfileError> {
+        Ok(toml::to_string(self)?)
+    }
+
+    /// 이름으로 차량 프로파일 조회
+    pub fn vehicle(&self, name: &str) -> Option<&VehicleProfile> {
+        self.vehicles.get(name)
+    }
+
+    /// 값 범위 검증
+    pub fn validate(&self) -> Result<(), ProfileError> {
+        let vts = &self.vts;
+        check(
+            "vts.voxel_size_m",
+            vts.voxel_size_m as f64,
+            vts.voxel_size_m > 0.0 && vts.voxel_size_m <= 100.0,
+            "0 < x <= 100",
+        )?;
+        check(
+            "vts.time_slot_ms",
+            vts.time_slot_ms as f64,
+            (1..=60_000).contains(&vts.time_slot_ms),
+            "1 <= x <= 60000",
+        )?;
+        check(
+            "vts.max_vts_per_robot",
+            vts.max_vts_per_robot as f64,
+            vts.max_vts_per_robot >= 1,
+            "x >= 1",
+        )?;
+
+        let physics = &self.physics;
+        positive("physics.max_velocity", physics.max_velocity)?;
+        positive("physics.max_acceleration", physics.max_acceleration)?;
+        positive("physics.max_jerk", physics.max_jerk)?;
+        positive("physics.max_angular_velocity", physics.max_angular_velocity)?;
+        positive(
+            "physics.max_angular_acceleration",
+            physics.max_angular_acceleration,
+        )?;
+        non_negative(
+            "physics.collision_safety_distance",
+            physics.collision_safety_distance,
+        )?;
+        positive(
+            "physics.collision_horizon_secs",
+            physics.collision_horizon_secs,
+        )?;
+        positive(
+            "physics.rollback_delta_threshold",
+            physics.rollback_delta_threshold,
+        )?;
+        positive("physics.control_period_secs", physics.control_period_secs)?;
+
+        if let Some(horizon) = &self.horizon {
+            positive("horizon.min_horizon_secs", horizon.min_horizon_secs)?;
+            check(
+                "horizon.max_horizon_secs",
+                horizon.max_horizon_secs as f64,
+                horizon.max_horizon_secs >= horizon.min_horizon_secs,
+                "x >= min_horizon_secs",
+            )?;
+            positive(
+                "horizon.stopping_distance_multiplier",
+                horizon.stopping_distance_multiplier,
+            )?;
+            positive("horizon.max_deceleration", horizon.max_deceleration)?;
+            non_negative("horizon.reaction_time_secs", horizon.reaction_time_secs)?;
+        }
+
+        for (name, vehicle) in &self.vehicles {
+            let field = |key: &str| format!("vehicles.{name}.{key}");
+            let kinematics = &vehicle.kinematics;
+            positive(&field("kinematics.max_velocity"), kinematics.max_velocity)?;
+            positive(
+                &field("kinematics.max_acceleration"),
+                kinematics.max_acceleration,
+            )?;
+            positive(
+                &field("kinematics.max_deceleration"),
+                kinematics.max_deceleration,
+            )?;
+            positive(
+                &field("kinematics.max_angular_velocity"),
+                kinematics.max_angular_velocity,
+            )?;
+            positive(
+                &field("kinematics.max_angular_acceleration"),
+                kinematics.max_angular_acceleration,
+            )?;
+            positive(&field("kinematics.max_jerk"), kinematics.max_jerk)?;
+            non_negative(
+                &field("kinematics.min_turning_radius"),
+                kinematics.min_turning_radius,
+            )?;
+            positive(&field("width"), vehicle.width)?;
+            positive(&field("length"), vehicle.length)?;
+            positive(&field("height"), vehicle.height)?;
+            non_negative(&field("safety_margin"), vehicle.safety_margin)?;
+        }
+
+        let rollback = &self.rollback;
+        check(
+            "rollback.max_snapshots",
+            rollback.max_snapshots as f64,
+            rollback.max_snapshots >= 1,
+            "x >= 1",
+        )?;
+        check(
+            "rollback.max_consecutive_rollbacks",
+            rollback.max_consecutive_rollbacks as f64,
+            rollback.max_consecutive_rollbacks >= 1,
+            "x >= 1",
+        )?;
+        check(
+            "rollback.keyframe_interval",
+            rollback.keyframe_interval as f64,
+            rollback.keyframe_interval >= 1,
+            "x >= 1",
+        )?;
+        check(
+            "rollback.tick_interval_ms",
+            rollback.tick_interval_ms as f64,
+            rollback.tick_interval_ms >= 1,
+            "x >= 1",
+        )?;
+        match rollback.strategy {
+            SnapshotStrategy::TickBased { interval } => {
+                check(
+                    "rollback.strategy.interval",
+                    interval as f64,
+                    interval >= 1,
+                    "x >= 1",
+                )?;
+            }
+            SnapshotStrategy::MemoryBudget {
+                max_bytes,
+                estimated_size_per_snapshot,
+            } => {
+                check(
+                    "rollback.strategy.estimated_size_per_snapshot",
+                    estimated_size_per_snapshot as f64,
+                    estimated_size_per_snapshot >= 1,
+                    "x >= 1",
+                )?;
+                check(
+                    "rollback.strategy.max_bytes",
+                    max_bytes as f64,
+                    max_bytes >= estimated_size_per_snapshot,
+                    "x >= estimated_size_per_snapshot",
+                )?;
+            }
+            SnapshotStrategy::Adaptive {
+                base_interval,
+                reduction_factor,
+                min_interval,
+            } => {
+                check(
+                    "rollback.strategy.min_interval",
+                    min_interval as f64,
+                    min_interval >= 1,
+                    "x >= 1",
+                )?;
+                check(
+                    "rollback.strategy.base_interval",
+                    base_interval as f64,
+                    base_interval >= min_interval,
+                    "x >= min_interval",
+                )?;
+                check(
+                    "rollback.strategy.reduction_factor",
+                    reduction_factor as f64,
+                    reduction_factor > 0.0 && reduction_factor <= 1.0,
+                    "0 < x <= 1",
+                )?;
+            }
+        }
+
+        let failsafe = &self.failsafe;
+        check(
+            "failsafe.heartbeat_timeout_ms",
+            failsafe.heartbeat_timeout_ms as f64,
+            failsafe.heartbeat_timeout_ms >= 1,
+            "x >= 1",
+        )?;
+        check(
+            "failsafe.degraded_speed_factor",
+            failsafe.degraded_speed_factor as f64,
+            failsafe.degraded_speed_factor > 0.0 && failsafe.degraded_speed_factor <= 1.0,
+            "0 < x <= 1",
+        )?;
+        non_negative(
+            "failsafe.emergency_stop_distance",
+            failsafe.emergency_stop_distance,
+        )?;
+
+        check(
+            "auction.max_bids",
+            self.auction.max_bids as f64,
+            self.auction.max_bids >= 1,
+            "x >= 1",
+        )?;
+
+        let pricing = &self.pricing;
+        check(
+            "pricing.base_price",
+            pricing.base_price as f64,
+            pricing.min_price <= pricing.base_price && pricing.base_price <= pricing.max_price,
+            "min_price <= x <= max_price",
+        )?;
+        unit_interval("pricing.demand_sensitivity", pricing.demand_sensitivity)?;
+        unit_interval("pricing.time_sensitivity", pricing.time_sensitivity)?;
+
+        Ok(())
+    }
+}
+
+fn check(field: &str, value: f64, ok: bool, expected: &'static str) -> Result<(), ProfileError> {
+    if ok {
+        Ok(())
+    } else {
+        Err(ProfileError::OutOfRange {
+            field: field.to_string(),
+            value,
+            expected,
+        })
+    }
+}
+
+/// 유한한 양수 (NaN/무한대 거부)
+fn positive(field: &str, value: f32) -> Result<(), ProfileError> {
+    check(
+        field,
+        value as f64,
+        value.is_finite() && value > 0.0,
+        "finite x > 0",
+    )
+}
+
+fn non_negative(field: &str, value: f32) -> Result<(), ProfileError> {
+    check(
+        field,
+        value as f64,
+        value.is_finite() && value >= 0.0,
+        "finite x >= 0",
+    )
+}
+
+fn unit_interval(field: &str, value: f32) -> Result<(), ProfileError> {
+    check(
+        field,
+        value as f64,
+        (0.0..=1.0).contains(&value),
+        "0 <= x <= 1",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_presets_load() {
+        for kind in DomainKind::ALL {
+            let profile = DomainProfile::preset(kind);
+            assert_eq!(profile.name, kind.name());
+            assert!(!profile.vehicles.is_empty());
+            assert_eq!(DomainKind::from_name(&profile.name), Some(kind));
+        }
+
+        let warehouse = DomainProfile::warehouse();
+        assert_eq!(warehouse.vts.voxel_size_m, 1.0);
+        assert_eq!(warehouse.physics.max_velocity, 2.5);
+        assert!(warehouse.horizon.is_some());
+        assert_eq!(
+            warehouse
+                .vehicle("amr")
+                .unwrap()
+                .kinematics
+                .max_angular_velocity,
+            2.0
+        );
+
+        let fab = DomainProfile::fab();
+        assert_eq!(fab.vts.time_slot_ms, 50);
+        assert_eq!(fab.physics.max_jerk, 2.0);
+        assert_eq!(fab.physics.rollback_delta_threshold, 0.03);
+        assert!(fab.horizon.is_none());
+
+        let hospital = DomainProfile::hospital();
+        assert_eq!(hospital.physics.collision_safety_distance, 0.2);
+        assert_eq!(hospital.vehicle("service_amr").unwrap().safety_margin, 0.5);
+    }
+
+    #[test]
+    fn test_partial_profile_uses_defaults() {
+        let profile = DomainProfile::from_toml_str(
+            r#"
+            name = "lab"
+
+            [physics]
+            max_velocity = 1.2
+
+            [rollback.strategy]
+            kind = "adaptive"
+            base_interval = 10
+            reduction_factor = 0.5
+            min_interval = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(profile.physics.max_velocity, 1.2);
+        assert_eq!(profile.physics.max_jerk, 50.0); // 기본값
+        assert_eq!(profile.failsafe.heartbeat_timeout_ms, 100);
+        assert!(matches!(
+            profile.rollback.strategy,
+            SnapshotStrategy::Adaptive {
+                min_interval: 2,
+                ..
+            }
+        ));
+        assert!(DomainKind::from_name("lab").is_none());
+    }
+
+    #[test]
+    fn test_out_of_range_rejected() {
+        let err = DomainProfile::from_toml_str(
+            r#"
+            name = "bad"
+            [failsafe]
+            degraded_speed_factor = 1.5
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ProfileError::OutOfRange { ref field, .. } if field == "failsafe.degraded_speed_factor"
+        ));
+
+        let mut profile = DomainProfile::warehouse();
+        profile
+            .vehicles
+            .get_mut("amr")
+            .unwrap()
+            .kinematics
+            .max_velocity = -1.0;
+        assert!(matches!(
+            profile.validate(),
+            Err(ProfileError::OutOfRange { ref field, .. })
+                if field == "vehicles.amr.kinematics.max_velocity"
+        ));
+
+        let mut profile = DomainProfile::fab();
+        profile.pricing.base_price = profile.pricing.max_price + 1;
+        assert!(profile.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // 알 수 없는 섹션 (오타)
+        assert!(matches!(
+            DomainProfile::from_toml_str("name = \"x\"\n[phisics]\nmax_velocity = 1.0\n"),
+            Err(ProfileError::Parse(_))
+        ));
+        // 중첩 설정의 알 수 없는 필드 (오타)
+        for source in [
+            "name = \"x\"\n[physics]\nmax_velocty = 1.0\n",
+            "name = \"x\"\n[vehicles.amr]\nwidht = 0.5\n",
+            "name = \"x\"\n[vehicles.amr.kinematics]\nmax_speed = 1.0\n",
+            "name = \"x\"\n[horizon]\nmin_horizon = 1.0\n",
+            "name = \"x\"\n[rollback]\nmax_snapshot = 10\n",
+            "name = \"x\"\n[rollback.strategy]\nkind = \"tick_based\"\ninterval = 5\nintervall = 5\n",
+            "name = \"x\"\n[failsafe]\nheartbeat_ms = 100\n",
+            "name = \"x\"\n[auction]\nreserve = 1\n",
+            "name = \"x\"\n[pricing]\nbase = 1\n",
+        ] {
+            assert!(
+                matches!(
+                    DomainProfile::from_toml_str(source),
+                    Err(ProfileError::Parse(_))
+                ),
+                "accepted unknown field: {source}"
+            );
+        }
+        // 이름 누락
+        assert!(DomainProfile::from_toml_str("[vts]\nvoxel_size_m = 1.0\n").is_err());
+        assert!(matches!(
+            DomainProfile::load("/nonexistent/profile.toml"),
+            Err(ProfileError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let original = DomainProfile::hospital();
+        let text = original.to_toml_string().unwrap();
+        let loaded = DomainProfile::from_toml_str(&text).unwrap();
+
+        assert_eq!(loaded.name, original.name);
+        assert_eq!(loaded.vts, original.vts);
+        assert_eq!(loaded.rollback.strategy, original.rollback.strategy);
+        assert_eq!(
+            loaded.vehicles.keys().collect::<Vec<_>>(),
+            original.vehicles.keys().collect::<Vec<_>>()
+        );
+    }
+}
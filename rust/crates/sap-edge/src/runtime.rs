@@ -2,7 +2,9 @@
 //!
 //! 물리 검증, 동기화, 경제 시스템을 통합
 
-use sap_core::types::WorldState;
+use crate::profile::{DomainProfile, VtsSettings};
+use sap_core::ticket::{TransitTicket, VoxelGrid};
+use sap_core::types::{Position, RobotState, WorldState};
 use sap_core::validation::ValidationResult;
 use sap_economy::auction::{AuctionResult, BidEntry, VickreyAuction};
 use sap_economy::pricing::PricingEngine;
@@ -11,7 +13,10 @@ use sap_network::failsafe::{FailsafeAction, FailsafeManager};
 use sap_network::rollback::{RollbackManager, RollbackReason};
 use sap_network::sync::{StateComparator, SyncResult};
 use sap_physics::command::MotionCommand;
+use sap_physics::constraint::{TicketConstraint, TicketConstraintConfig};
+use sap_physics::kinematics::VehicleProfile;
 use sap_physics::validator::{PhysicsValidator, PhysicsValidatorConfig};
+use std::collections::BTreeMap;
 
 /// Edge Runtime - 전체 L2+L3+L4 통합
 pub struct EdgeRuntime {
@@ -23,6 +28,10 @@ pub struct EdgeRuntime {
     auction: VickreyAuction,
     pricing_engine: PricingEngine,
     ticket_manager: TicketManager,
+    vts: VtsSettings,
+    vehicles: BTreeMap<String, VehicleProfile>,
+    /// Zone 상태 (텔레메트리로 갱신, 틱마다 스냅샷)
+    world: WorldState,
     current_tick: u64,
     stats: RuntimeStats,
 }
@@ -49,6 +58,9 @@ impl EdgeRuntime {
             auction: VickreyAuction::with_default_config(),
             pricing_engine: PricingEngine::with_default_config(),
             ticket_manager: TicketManager::new(zone_id),
+            vts: VtsSettings::default(),
+            vehicles: BTreeMap::new(),
+            world: WorldState::new(zone_id),
             current_tick: 0,
             stats: RuntimeStats::default(),
         }
@@ -65,26 +77,110 @@ impl EdgeRuntime {
             auction: VickreyAuction::with_default_config(),
             pricing_engine: PricingEngine::with_default_config(),
             ticket_manager: TicketManager::new(zone_id),
+            vts: VtsSettings::default(),
+            vehicles: BTreeMap::new(),
+            world: WorldState::new(zone_id),
             current_tick: 0,
             stats: RuntimeStats::default(),
         }
     }
 
+    /// 도메인 프로파일로 모든 컴포넌트 구성
+    ///
+    /// 차량 프로파일은 `assign_vehicle`로 로봇에 배정해야 적용되고,
+    /// VTS 설정은 입찰 한도와 `with_voxel_grid`의 복셀/슬롯 크기로 쓰인다.
+    pub fn with_profile(zone_id: u32, profile: &DomainProfile) -> Self {
+        let mut physics_validator = PhysicsValidator::new(profile.physics.clone());
+        if let Some(horizon) = &profile.horizon {
+            physics_validator = physics_validator.with_dynamic_horizon(horizon.clone());
+        }
+        Self {
+            zone_id,
+            physics_validator,
+            state_comparator: StateComparator::with_default_config(),
+            rollback_manager: RollbackManager::new(zone_id, profile.rollback.clone()),
+            failsafe_manager: FailsafeManager::new(zone_id, profile.failsafe.clone()),
+            auction: VickreyAuction::new(profile.auction.clone()),
+            pricing_engine: PricingEngine::new(profile.pricing.clone()),
+            ticket_manager: TicketManager::new(zone_id),
+            vts: profile.vts.clone(),
+            vehicles: profile.vehicles.clone(),
+            world: WorldState::new(zone_id),
+            current_tick: 0,
+            stats: RuntimeStats::default(),
+        }
+    }
+
+    /// Zone 복셀 격자를 설정하여 명령마다 티켓/VTS 준수를 검사
+    ///
+    /// 복셀 크기와 예측 구간은 VTS 설정(`voxel_size_m`, `time_slot_ms`)을 따른다.
+    pub fn with_voxel_grid(mut self, origin: Position, dims: [u32; 3]) -> Self {
+        let grid = VoxelGrid::new(origin, self.vts.voxel_size_m, dims);
+        let config = TicketConstraintConfig {
+            prediction_interval_ns: self.vts.time_slot_ms * 1_000_000,
+            ..Default::default()
+        };
+        self.physics_validator = self
+            .physics_validator
+            .with_ticket_constraint(TicketConstraint::new(grid, config));
+        self
+    }
+
+    /// 프로파일의 차량 프로파일을 로봇에 배정 (이름이 없으면 false)
+    pub fn assign_vehicle(&mut self, robot_id: u64, vehicle: &str) -> bool {
+        match self.vehicles.get(vehicle) {
+            Some(profile) => {
+                self.physics_validator
+                    .register_vehicle(robot_id, profile.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn tick(&mut self, timestamp_ns: u64) {
         self.current_tick += 1;
-        #[allow(clippy::manual_is_multiple_of)]
-        if self.current_tick % 10 == 0 {
-            let state = WorldState::new(self.zone_id).with_tick(self.current_tick, timestamp_ns);
+        self.world.tick = self.current_tick;
+        self.world.timestamp_ns = timestamp_ns;
+        if self.rollback_manager.should_snapshot(self.current_tick) {
             self.rollback_manager
-                .save_snapshot(self.current_tick, state);
+                .save_snapshot(self.current_tick, self.world.clone());
         }
         self.ticket_manager.cleanup_expired(timestamp_ns);
     }
 
+    /// 로봇 실측 상태 반영 - Zone 상태 갱신 및 재시뮬레이션 입력 기록
+    pub fn update_robot_state(&mut self, state: RobotState) {
+        self.rollback_manager
+            .record_telemetry(self.current_tick, state.clone());
+        self.world.update_robot(state);
+    }
+
     pub fn process_command(&mut self, cmd: &MotionCommand, timestamp_ns: u64) -> CommandResult {
         self.stats.total_commands += 1;
-        let validation = self.physics_validator.validate(cmd, &[], timestamp_ns);
-        match validation {
+        let report = self.physics_validator.validate_with_tickets(
+            cmd,
+            &[],
+            &self.ticket_manager,
+            timestamp_ns,
+        );
+        if report.result() != ValidationResult::REJECT {
+            // 재시뮬레이션 입력 - 실제 적용된 명령 기록
+            let applied = report.adjusted_command.as_ref().unwrap_or(cmd);
+            self.rollback_manager.record_command(
+                self.current_tick,
+                applied.robot_id,
+                applied.target_velocity,
+                applied.target_angular_velocity,
+            );
+        }
+        let reason = report
+            .detail
+            .reason
+            .as_ref()
+            .map(|r| format!("{:?}", r))
+            .unwrap_or_default();
+        match report.result() {
             ValidationResult::OK => {
                 self.stats.passed_commands += 1;
                 self.rollback_manager.reset_consecutive(cmd.robot_id);
@@ -93,14 +189,13 @@ impl EdgeRuntime {
             ValidationResult::ADJUST => {
                 self.stats.adjusted_commands += 1;
                 CommandResult::Adjusted {
-                    reason: "velocity/acceleration clamped".to_string(),
+                    reason,
+                    adjusted: report.adjusted_command.unwrap_or_else(|| cmd.clone()),
                 }
             }
             ValidationResult::REJECT => {
                 self.stats.rejected_commands += 1;
-                CommandResult::Rejected {
-                    reason: "collision or constraint violation".to_string(),
-                }
+                CommandResult::Rejected { reason }
             }
         }
     }
@@ -109,14 +204,20 @@ impl EdgeRuntime {
         &mut self,
         robot_id: u64,
         position_delta: f32,
+        theta_delta: f32,
         timestamp_ns: u64,
     ) -> SyncCheckResult {
-        let sync_result =
-            self.state_comparator
-                .compare_delta(robot_id, self.current_tick, position_delta, 0.0);
+        let sync_result = self.state_comparator.compare_delta(
+            robot_id,
+            self.current_tick,
+            position_delta,
+            theta_delta,
+        );
         match sync_result {
             SyncResult::InSync => SyncCheckResult::InSync,
             SyncResult::Warning => SyncCheckResult::Warning,
+            SyncResult::Glitch => SyncCheckResult::Glitch,
+            SyncResult::Drift => SyncCheckResult::Drift,
             SyncResult::NeedsRollback => {
                 match self.rollback_manager.execute_rollback(
                     robot_id,
@@ -158,6 +259,13 @@ impl EdgeRuntime {
         amount: u64,
         timestamp_ns: u64,
     ) -> Result<(), String> {
+        let held = self.ticket_manager.get_robot_tickets(robot_id).len();
+        if held >= self.vts.max_vts_per_robot as usize {
+            return Err(format!(
+                "robot {robot_id} already holds {held} VTS (max {})",
+                self.vts.max_vts_per_robot
+            ));
+        }
         let bid = BidEntry {
             robot_id,
             bid_amount: amount,
@@ -171,9 +279,14 @@ impl EdgeRuntime {
         Ok(())
     }
 
-    pub fn settle_auction(&mut self, vts_id: u64, timestamp_ns: u64) -> Option<AuctionResult> {
+    /// 경매 정산 - 낙찰자에게 해당 VTS(복셀 ID)를 포함한 티켓 발행
+    pub fn settle_auction(
+        &mut self,
+        vts_id: u64,
+        timestamp_ns: u64,
+    ) -> Option<(AuctionResult, TransitTicket)> {
         let result = self.auction.settle(vts_id, timestamp_ns)?;
-        let _ticket = self.ticket_manager.issue_ticket(
+        let ticket = self.ticket_manager.issue_ticket(
             result.winner_id,
             vts_id,
             timestamp_ns,
@@ -183,7 +296,7 @@ impl EdgeRuntime {
             .record_transaction(vts_id, result.winning_price);
         self.stats.auction_count += 1;
         self.stats.ticket_issued += 1;
-        Some(result)
+        Some((result, ticket))
     }
 
     pub fn quote_price(&mut self, vts_id: u64, timestamp_ns: u64) -> u64 {
@@ -199,19 +312,36 @@ impl EdgeRuntime {
     pub fn zone_id(&self) -> u32 {
         self.zone_id
     }
+    pub fn vts_settings(&self) -> &VtsSettings {
+        &self.vts
+    }
+    pub fn world_state(&self) -> &WorldState {
+        &self.world
+    }
+    pub fn rollback_manager(&self) -> &RollbackManager {
+        &self.rollback_manager
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum CommandResult {
     Passed,
-    Adjusted { reason: String },
-    Rejected { reason: String },
+    /// 조정됨 - 로봇은 `adjusted` 명령을 실행
+    Adjusted {
+        reason: String,
+        adjusted: MotionCommand,
+    },
+    Rejected {
+        reason: String,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub enum SyncCheckResult {
     InSync,
     Warning,
+    Glitch,
+    Drift,
     RolledBack { to_tick: u64 },
     RollbackFailed,
 }
@@ -227,6 +357,8 @@ mod tests {
             current_position: Position::ORIGIN,
             target_velocity: Velocity::new(vel_magnitude, 0.0, 0.0),
             target_acceleration: Acceleration::new(1.0, 0.0, 0.0),
+            target_angular_velocity: 0.0,
+            target_angular_acceleration: 0.0,
             ticket_id: 1,
         }
     }
@@ -238,6 +370,88 @@ mod tests {
         assert_eq!(runtime.current_tick(), 0);
     }
 
+    #[test]
+    fn test_runtime_with_profile() {
+        let mut runtime = EdgeRuntime::with_profile(1, &DomainProfile::fab());
+        // FAB 최대 속도 0.8 m/s 초과 → 조정
+        let cmd = create_test_command(42, 2.0);
+        match runtime.process_command(&cmd, 1_000_000_000) {
+            CommandResult::Adjusted { adjusted, .. } => {
+                assert!(adjusted.target_speed() <= 0.8 + 0.01);
+            }
+            other => panic!("expected Adjusted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_runtime_with_profile_vehicles_and_vts() {
+        let mut profile = DomainProfile::warehouse();
+        profile
+            .vehicles
+            .get_mut("amr")
+            .unwrap()
+            .kinematics
+            .max_velocity = 1.0;
+        profile.vts.max_vts_per_robot = 2;
+        let mut runtime = EdgeRuntime::with_profile(1, &profile);
+        assert_eq!(runtime.vts_settings(), &profile.vts);
+
+        // 차량 배정 전에는 Zone 한계, 배정 후에는 차량 한계로 조정
+        let cmd = create_test_command(42, 2.0);
+        assert!(matches!(
+            runtime.process_command(&cmd, 0),
+            CommandResult::Passed
+        ));
+        assert!(runtime.assign_vehicle(42, "amr"));
+        assert!(!runtime.assign_vehicle(42, "no_such_vehicle"));
+        match runtime.process_command(&cmd, 0) {
+            CommandResult::Adjusted { adjusted, .. } => {
+                assert!(adjusted.target_speed() <= 1.0 + 0.01);
+            }
+            other => panic!("expected Adjusted, got {:?}", other),
+        }
+
+        // 로봇당 VTS 한도 초과 입찰 거부
+        for vts_id in 0..2 {
+            runtime.submit_bid(7, vts_id, 500, 0).unwrap();
+            runtime.settle_auction(vts_id, 0).unwrap();
+        }
+        assert!(runtime.submit_bid(7, 2, 500, 0).is_err());
+    }
+
+    #[test]
+    fn test_voxel_grid_enforces_tickets() {
+        let mut runtime = EdgeRuntime::new(1).with_voxel_grid(Position::ORIGIN, [10, 10, 1]);
+        let mut cmd = create_test_command(42, 1.0);
+        cmd.current_position = Position::new(0.5, 0.5, 0.5);
+        assert!(matches!(
+            runtime.process_command(&cmd, 0),
+            CommandResult::Rejected { reason } if reason.contains("InvalidTicket")
+        ));
+    }
+
+    #[test]
+    fn test_won_ticket_passes_voxel_grid() {
+        let mut runtime = EdgeRuntime::new(1).with_voxel_grid(Position::ORIGIN, [10, 10, 1]);
+        // 복셀 3 = x 3~4m, y 0~1m
+        runtime.submit_bid(42, 3, 500, 0).unwrap();
+        let (_, ticket) = runtime.settle_auction(3, 0).unwrap();
+
+        let mut cmd = create_test_command(42, 1.0).with_ticket(ticket.ticket_id);
+        cmd.current_position = Position::new(3.2, 0.5, 0.5);
+        assert!(matches!(
+            runtime.process_command(&cmd, 1_000_000),
+            CommandResult::Passed
+        ));
+
+        // 낙찰받지 않은 복셀로는 진입 불가
+        cmd.current_position = Position::new(3.95, 0.5, 0.5);
+        assert!(matches!(
+            runtime.process_command(&cmd, 1_000_000),
+            CommandResult::Rejected { reason } if reason.contains("VTSViolation")
+        ));
+    }
+
     #[test]
     fn test_tick_advances() {
         let mut runtime = EdgeRuntime::new(1);
@@ -261,7 +475,17 @@ mod tests {
         let mut runtime = EdgeRuntime::new(1);
         let cmd = create_test_command(42, 10.0);
         let result = runtime.process_command(&cmd, 1_000_000_000);
-        assert!(matches!(result, CommandResult::Adjusted { .. }));
+        match result {
+            CommandResult::Adjusted { reason, adjusted } => {
+                assert!(reason.contains("VelocityExceeded"));
+                assert!(
+                    adjusted.target_speed()
+                        <= PhysicsValidatorConfig::default().max_velocity + 0.01
+                );
+                assert_eq!(adjusted.robot_id, 42);
+            }
+            other => panic!("expected Adjusted, got {:?}", other),
+        }
         assert_eq!(runtime.stats().adjusted_commands, 1);
     }
 
@@ -269,17 +493,64 @@ mod tests {
     fn test_sync_check_in_sync() {
         let mut runtime = EdgeRuntime::new(1);
         runtime.tick(1_000_000_000);
-        let result = runtime.check_sync(42, 0.05, 1_000_000_000);
+        let result = runtime.check_sync(42, 0.05, 0.0, 1_000_000_000);
         assert!(matches!(result, SyncCheckResult::InSync));
     }
 
+    #[test]
+    fn test_rollback_uses_recorded_zone_state() {
+        let mut runtime = EdgeRuntime::new(1);
+        for i in 1..=12 {
+            runtime.tick(i * 50_000_000);
+            runtime.update_robot_state(
+                RobotState::new(42)
+                    .with_motion(
+                        Position::new(i as f32 * 0.05, 0.0, 0.0),
+                        Velocity::new(1.0, 0.0, 0.0),
+                    )
+                    .with_timestamp(i * 50_000_000),
+            );
+        }
+        assert_eq!(runtime.world_state().robot_count(), 1);
+
+        // 틱 11 스냅샷에는 틱 10까지 반영된 실제 Zone 상태가 담김
+        let snapshot = runtime.rollback_manager().get_snapshot(11).unwrap();
+        let robot = snapshot.get_robot(42).unwrap();
+        assert!((robot.position.x - 0.5).abs() < 1e-4);
+
+        let result = runtime.check_sync(42, 0.5, 0.0, 600_000_000);
+        assert!(matches!(
+            result,
+            SyncCheckResult::RolledBack { to_tick: 11 }
+        ));
+        let resimulated = runtime
+            .rollback_manager()
+            .resimulated_state()
+            .unwrap()
+            .get_robot(42)
+            .unwrap();
+        // 마지막 텔레메트리(x = 0.6)에서 재시뮬레이션 종료
+        assert!((resimulated.position.x - 0.6).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_sync_check_heading_error_rolls_back() {
+        let mut runtime = EdgeRuntime::new(1);
+        for i in 1..=10 {
+            runtime.tick(i * 50_000_000);
+        }
+        // 위치는 맞지만 방향 오차가 임계값(0.2 rad)의 2.5배
+        let result = runtime.check_sync(42, 0.01, 0.5, 500_000_000);
+        assert!(matches!(result, SyncCheckResult::RolledBack { .. }));
+    }
+
     #[test]
     fn test_sync_check_needs_rollback() {
         let mut runtime = EdgeRuntime::new(1);
         for i in 1..=10 {
             runtime.tick(i * 50_000_000);
         }
-        let result = runtime.check_sync(42, 0.5, 500_000_000);
+        let result = runtime.check_sync(42, 0.5, 0.0, 500_000_000);
         assert!(matches!(result, SyncCheckResult::RolledBack { .. }));
         assert_eq!(runtime.stats().rollback_count, 1);
     }
@@ -290,7 +561,8 @@ mod tests {
         runtime.submit_bid(1, 100, 500, 1_000_000_000).unwrap();
         runtime.submit_bid(2, 100, 800, 2_000_000_000).unwrap();
         runtime.submit_bid(3, 100, 600, 3_000_000_000).unwrap();
-        let result = runtime.settle_auction(100, 5_000_000_000).unwrap();
+        let (result, ticket) = runtime.settle_auction(100, 5_000_000_000).unwrap();
+        assert_eq!(ticket.robot_id, 2);
         assert_eq!(result.winner_id, 2);
         assert_eq!(result.winning_price, 600);
         assert_eq!(runtime.stats().auction_count, 1);
@@ -323,7 +595,7 @@ mod tests {
         runtime.receive_heartbeat(1, 0);
         runtime.submit_bid(42, 100, 500, 100_000_000).unwrap();
         runtime.submit_bid(43, 100, 700, 200_000_000).unwrap();
-        let auction_result = runtime.settle_auction(100, 300_000_000).unwrap();
+        let (auction_result, _) = runtime.settle_auction(100, 300_000_000).unwrap();
         assert_eq!(auction_result.winner_id, 43);
         for i in 1..=10 {
             runtime.tick(i * 50_000_000);
@@ -332,7 +604,7 @@ mod tests {
             let result = runtime.process_command(&cmd, i * 50_000_000);
             assert!(matches!(result, CommandResult::Passed));
         }
-        let sync_result = runtime.check_sync(43, 0.03, 500_000_000);
+        let sync_result = runtime.check_sync(43, 0.03, 0.0, 500_000_000);
         assert!(matches!(sync_result, SyncCheckResult::InSync));
         let failsafe = runtime.check_failsafe(500_000_000);
         assert!(matches!(failsafe, FailsafeAction::None));
@@ -340,4 +612,52 @@ mod tests {
         assert_eq!(runtime.stats().passed_commands, 10);
         assert_eq!(runtime.stats().auction_count, 1);
     }
+
+    /// 에뮬레이션 링크로 연결된 로봇들의 DeltaTick 스트림 처리 → (수신 수, 롤백 수)
+    fn run_over_emulated_links(seed: u64) -> (usize, u64) {
+        use sap_core::packet::DeltaTickPacket;
+        use sap_network::transport::{EmulatedLink, LinkEmulatorConfig, MessageLink, SapMessage};
+
+        let mut runtime = EdgeRuntime::new(1);
+        let mut links: Vec<(EmulatedLink, EmulatedLink)> = (0..3)
+            .map(|i| EmulatedLink::symmetric(LinkEmulatorConfig::lossy_wifi(), seed + i))
+            .collect();
+
+        let mut received = 0;
+        for tick in 1..=200u64 {
+            let now_ns = tick * 50_000_000;
+            runtime.tick(now_ns);
+            for (robot_id, (robot, _)) in links.iter_mut().enumerate() {
+                // 40틱마다 예측 오차 급증
+                let error = if tick % 40 == 0 { 0.5 } else { 0.02 };
+                let packet = DeltaTickPacket::new(1, robot_id as u64, tick)
+                    .with_delta(Position::new(error, 0.0, 0.0), 0.0)
+                    .with_timestamp(now_ns);
+                robot.send(SapMessage::DeltaTick(packet), now_ns).unwrap();
+            }
+            for (_, edge) in links.iter_mut() {
+                for message in edge.poll(now_ns) {
+                    if let SapMessage::DeltaTick(packet) = message {
+                        received += 1;
+                        runtime.check_sync(
+                            packet.robot_id,
+                            packet.delta_magnitude(),
+                            packet.delta_theta,
+                            now_ns,
+                        );
+                    }
+                }
+            }
+        }
+        (received, runtime.stats().rollback_count)
+    }
+
+    #[test]
+    fn test_robots_over_emulated_link_are_reproducible() {
+        let (received, rollbacks) = run_over_emulated_links(7);
+        assert!(received < 600, "lossy link should drop some packets");
+        assert!(received > 500);
+        assert!(rollbacks > 0);
+        assert_eq!(run_over_emulated_links(7), (received, rollbacks));
+    }
 }
@@ -1,16 +1,25 @@
 //! FailsafeManager - 장애 대응 관리자
 //!
+//! Zone별 Primary/Standby Edge 배정을 관리하며, Primary 장애 시 정상 Standby로
+//! 핸드오버하고, 모든 Edge를 잃은 Zone만 격리한다. 정상 복귀는 일정 시간 연속으로
+//! 정상이 유지된 뒤에만 허용한다 (히스테리시스).
+//!
 //! PPR 매핑: AI_make_FailsafeManager
 
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// Failsafe 관리자 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct FailsafeConfig {
     pub heartbeat_timeout_ms: u64,
     pub max_retries: u32,
     pub degraded_speed_factor: f32,
     pub emergency_stop_distance: f32,
+
+    /// 정상 복귀 전 연속 정상 유지 시간 (밀리초)
+    pub recovery_hold_ms: u64,
 }
 
 impl Default for FailsafeConfig {
@@ -20,6 +29,7 @@ impl Default for FailsafeConfig {
             max_retries: 3,
             degraded_speed_factor: 0.5,
             emergency_stop_distance: 0.2,
+            recovery_hold_ms: 500, // 10틱
         }
     }
 }
@@ -33,6 +43,14 @@ pub enum EdgeStatus {
     Failed,
 }
 
+impl EdgeStatus {
+    /// 서비스 불가 상태 (Unresponsive/Failed)
+    #[inline]
+    pub fn is_unavailable(&self) -> bool {
+        matches!(self, Self::Unresponsive | Self::Failed)
+    }
+}
+
 /// 장애 대응 액션
 #[derive(Debug, Clone)]
 pub enum FailsafeAction {
@@ -49,7 +67,9 @@ pub struct FailsafeManager {
     config: FailsafeConfig,
     zone_id: u32,
     edge_status: HashMap<u32, EdgeStatusInfo>,
+    zones: BTreeMap<u32, ZoneAssignment>,
     current_mode: OperationMode,
+    healthy_since_ns: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -59,6 +79,21 @@ struct EdgeStatusInfo {
     consecutive_failures: u32,
 }
 
+/// Zone별 Edge 배정
+#[derive(Debug, Clone)]
+struct ZoneAssignment {
+    primary: u32,
+    standbys: Vec<u32>,
+    isolated: bool,
+    recovered_since_ns: Option<u64>,
+}
+
+impl ZoneAssignment {
+    fn edges(&self) -> impl Iterator<Item = u32> + '_ {
+        std::iter::once(self.primary).chain(self.standbys.iter().copied())
+    }
+}
+
 /// 운영 모드
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OperationMode {
@@ -73,7 +108,9 @@ impl FailsafeManager {
             config,
             zone_id,
             edge_status: HashMap::new(),
+            zones: BTreeMap::new(),
             current_mode: OperationMode::Normal,
+            healthy_since_ns: None,
         }
     }
 
@@ -92,6 +129,24 @@ impl FailsafeManager {
         );
     }
 
+    /// Zone의 Primary/Standby Edge 배정 (미등록 Edge는 자동 등록)
+    pub fn assign_zone(&mut self, zone_id: u32, primary: u32, standbys: &[u32]) {
+        for edge_id in std::iter::once(primary).chain(standbys.iter().copied()) {
+            if !self.edge_status.contains_key(&edge_id) {
+                self.register_edge(edge_id);
+            }
+        }
+        self.zones.insert(
+            zone_id,
+            ZoneAssignment {
+                primary,
+                standbys: standbys.iter().copied().filter(|&e| e != primary).collect(),
+                isolated: false,
+                recovered_since_ns: None,
+            },
+        );
+    }
+
     pub fn receive_heartbeat(&mut self, edge_id: u32, timestamp_ns: u64) {
         if let Some(info) = self.edge_status.get_mut(&edge_id) {
             info.last_heartbeat_ns = timestamp_ns;
@@ -100,43 +155,160 @@ impl FailsafeManager {
         }
     }
 
+    /// 상태 갱신 후 가장 우선순위가 높은 액션 1개 결정
+    ///
+    /// 우선순위: EmergencyStop > ZoneIsolation > EdgeHandover > 감속 모드 > None.
+    /// 반환한 액션만 상태에 반영하므로, 여러 Zone에 동시에 장애가 나면
+    /// 이어지는 호출에서 나머지 액션이 차례로 반환된다.
     pub fn check_and_decide(&mut self, current_time_ns: u64) -> FailsafeAction {
-        let timeout_ns = self.config.heartbeat_timeout_ms * 1_000_000;
-        let mut unhealthy_count = 0;
-        let mut failed_edges = Vec::new();
+        self.update_edge_status(current_time_ns);
+        self.update_zone_recovery(current_time_ns);
 
-        for (&edge_id, info) in self.edge_status.iter_mut() {
-            let elapsed_ns = current_time_ns.saturating_sub(info.last_heartbeat_ns);
+        if self.all_edges_lost() {
+            self.healthy_since_ns = None;
+            self.current_mode = OperationMode::Emergency;
+            return FailsafeAction::EmergencyStop;
+        }
 
-            if elapsed_ns > timeout_ns * 3 {
-                info.status = EdgeStatus::Failed;
-                failed_edges.push(edge_id);
-                unhealthy_count += 1;
-            } else if elapsed_ns > timeout_ns * 2 {
-                info.status = EdgeStatus::Unresponsive;
-                unhealthy_count += 1;
-            } else if elapsed_ns > timeout_ns {
-                info.status = EdgeStatus::Degraded;
-            } else {
-                info.status = EdgeStatus::Healthy;
+        if let Some(zone_id) = self.find_lost_zone() {
+            if let Some(zone) = self.zones.get_mut(&zone_id) {
+                zone.isolated = true;
+                zone.recovered_since_ns = None;
             }
+            self.healthy_since_ns = None;
+            self.current_mode = OperationMode::Degraded;
+            return FailsafeAction::ZoneIsolation { zone_id };
         }
 
-        if failed_edges.len() > 1 {
-            self.current_mode = OperationMode::Emergency;
-            FailsafeAction::EmergencyStop
-        } else if unhealthy_count > 0 {
+        if let Some((zone_id, to_edge)) = self.find_handover() {
+            let zone = self.zones.get_mut(&zone_id).expect("zone exists");
+            let from_edge = zone.primary;
+            zone.standbys.retain(|&e| e != to_edge);
+            zone.standbys.push(from_edge);
+            zone.primary = to_edge;
+            self.healthy_since_ns = None;
             self.current_mode = OperationMode::Degraded;
-            FailsafeAction::EnableDegradedMode {
+            return FailsafeAction::EdgeHandover { from_edge, to_edge };
+        }
+
+        if self.is_degraded() {
+            self.healthy_since_ns = None;
+            self.current_mode = OperationMode::Degraded;
+            return FailsafeAction::EnableDegradedMode {
                 speed_factor: self.config.degraded_speed_factor,
+            };
+        }
+
+        // 히스테리시스: 연속 정상 유지 후에만 Normal 복귀
+        if self.current_mode != OperationMode::Normal {
+            let since = *self.healthy_since_ns.get_or_insert(current_time_ns);
+            let hold_ns = self.config.recovery_hold_ms * 1_000_000;
+            if current_time_ns.saturating_sub(since) < hold_ns {
+                self.current_mode = OperationMode::Degraded;
+                return FailsafeAction::EnableDegradedMode {
+                    speed_factor: self.config.degraded_speed_factor,
+                };
             }
+        }
+        self.healthy_since_ns = None;
+        self.current_mode = OperationMode::Normal;
+        FailsafeAction::None
+    }
+
+    fn update_edge_status(&mut self, current_time_ns: u64) {
+        let timeout_ns = self.config.heartbeat_timeout_ms * 1_000_000;
+        for info in self.edge_status.values_mut() {
+            let elapsed_ns = current_time_ns.saturating_sub(info.last_heartbeat_ns);
+            info.status = if info.consecutive_failures >= self.config.max_retries
+                || elapsed_ns > timeout_ns * 3
+            {
+                EdgeStatus::Failed
+            } else if elapsed_ns > timeout_ns * 2 {
+                EdgeStatus::Unresponsive
+            } else if elapsed_ns > timeout_ns {
+                EdgeStatus::Degraded
+            } else {
+                EdgeStatus::Healthy
+            };
+        }
+    }
+
+    /// 격리 Zone에 정상 Edge가 복귀 유지 시간 동안 있으면 격리 해제
+    fn update_zone_recovery(&mut self, current_time_ns: u64) {
+        let hold_ns = self.config.recovery_hold_ms * 1_000_000;
+        for zone in self.zones.values_mut().filter(|z| z.isolated) {
+            let has_healthy = zone
+                .edges()
+                .any(|e| status_of(&self.edge_status, e) == EdgeStatus::Healthy);
+            if !has_healthy {
+                zone.recovered_since_ns = None;
+                continue;
+            }
+            let since = *zone.recovered_since_ns.get_or_insert(current_time_ns);
+            if current_time_ns.saturating_sub(since) >= hold_ns {
+                zone.isolated = false;
+                zone.recovered_since_ns = None;
+            }
+        }
+    }
+
+    /// 전체 Edge 상실 여부
+    ///
+    /// Zone 배정이 있으면 모든 Zone이 Edge를 잃었을 때, 없으면 Failed Edge가 2개 이상일 때.
+    fn all_edges_lost(&self) -> bool {
+        if self.zones.is_empty() {
+            let failed = self
+                .edge_status
+                .values()
+                .filter(|i| i.status == EdgeStatus::Failed)
+                .count();
+            failed > 1
         } else {
-            self.current_mode = OperationMode::Normal;
-            FailsafeAction::None
+            self.zones.values().all(|zone| self.zone_lost(zone))
         }
     }
 
-    #[allow(dead_code)]
+    fn zone_lost(&self, zone: &ZoneAssignment) -> bool {
+        zone.edges()
+            .all(|e| status_of(&self.edge_status, e) == EdgeStatus::Failed)
+    }
+
+    fn find_lost_zone(&self) -> Option<u32> {
+        self.zones
+            .iter()
+            .find(|(_, zone)| !zone.isolated && self.zone_lost(zone))
+            .map(|(&zone_id, _)| zone_id)
+    }
+
+    /// Primary 장애 + 정상 Standby가 있는 Zone
+    fn find_handover(&self) -> Option<(u32, u32)> {
+        self.zones.iter().find_map(|(&zone_id, zone)| {
+            if zone.isolated || !status_of(&self.edge_status, zone.primary).is_unavailable() {
+                return None;
+            }
+            zone.standbys
+                .iter()
+                .copied()
+                .find(|&e| status_of(&self.edge_status, e) == EdgeStatus::Healthy)
+                .map(|to_edge| (zone_id, to_edge))
+        })
+    }
+
+    /// 감속 운영 필요 여부
+    ///
+    /// 격리 Zone, 대체 불가한 Primary 장애, 또는 Zone 미배정 Edge 장애가 있을 때.
+    fn is_degraded(&self) -> bool {
+        let zone_degraded = self.zones.values().any(|zone| {
+            zone.isolated || status_of(&self.edge_status, zone.primary).is_unavailable()
+        });
+        let unassigned_unhealthy = self.edge_status.iter().any(|(&edge_id, info)| {
+            info.status.is_unavailable()
+                && !self.zones.values().any(|z| z.edges().any(|e| e == edge_id))
+        });
+        zone_degraded || unassigned_unhealthy
+    }
+
+    /// Edge 통신 실패 보고 - `max_retries`회 누적 시 다음 판단에서 Failed
     pub fn report_edge_failure(&mut self, edge_id: u32, _current_time_ns: u64) {
         if let Some(info) = self.edge_status.get_mut(&edge_id) {
             info.consecutive_failures += 1;
@@ -154,6 +326,24 @@ impl FailsafeManager {
         self.edge_status.get(&edge_id).map(|i| i.status)
     }
 
+    /// Zone의 현재 Primary Edge
+    pub fn primary_edge(&self, zone_id: u32) -> Option<u32> {
+        self.zones.get(&zone_id).map(|z| z.primary)
+    }
+
+    /// Zone의 Standby Edge 목록
+    pub fn standby_edges(&self, zone_id: u32) -> &[u32] {
+        self.zones
+            .get(&zone_id)
+            .map(|z| z.standbys.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Zone 격리 여부
+    pub fn is_zone_isolated(&self, zone_id: u32) -> bool {
+        self.zones.get(&zone_id).is_some_and(|z| z.isolated)
+    }
+
     pub fn healthy_edge_count(&self) -> usize {
         self.edge_status
             .values()
@@ -161,7 +351,6 @@ impl FailsafeManager {
             .count()
     }
 
-    #[allow(dead_code)]
     pub fn total_edge_count(&self) -> usize {
         self.edge_status.len()
     }
@@ -171,15 +360,24 @@ impl FailsafeManager {
     }
 
     pub fn emergency_stop(&mut self, _current_time_ns: u64) -> FailsafeAction {
+        self.healthy_since_ns = None;
         self.current_mode = OperationMode::Emergency;
         FailsafeAction::EmergencyStop
     }
 
     pub fn recover_to_normal(&mut self) {
+        self.healthy_since_ns = None;
         self.current_mode = OperationMode::Normal;
     }
 }
 
+fn status_of(edges: &HashMap<u32, EdgeStatusInfo>, edge_id: u32) -> EdgeStatus {
+    edges
+        .get(&edge_id)
+        .map(|i| i.status)
+        .unwrap_or(EdgeStatus::Failed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +438,126 @@ mod tests {
         manager.recover_to_normal();
         assert_eq!(manager.current_mode(), OperationMode::Normal);
     }
+
+    fn zoned_manager() -> FailsafeManager {
+        let mut manager = FailsafeManager::with_default_config(1);
+        manager.assign_zone(1, 10, &[11]);
+        manager.assign_zone(2, 20, &[]);
+        for edge in [10, 11, 20] {
+            manager.receive_heartbeat(edge, 0);
+        }
+        manager
+    }
+
+    #[test]
+    fn test_primary_failure_hands_over_to_standby() {
+        let mut manager = zoned_manager();
+        manager.receive_heartbeat(11, 240_000_000);
+        manager.receive_heartbeat(20, 240_000_000);
+
+        // Primary 10 무응답 (>200ms), Standby 11 정상
+        let action = manager.check_and_decide(250_000_000);
+        assert!(matches!(
+            action,
+            FailsafeAction::EdgeHandover {
+                from_edge: 10,
+                to_edge: 11
+            }
+        ));
+        assert_eq!(manager.primary_edge(1), Some(11));
+        assert_eq!(manager.standby_edges(1), &[10]);
+
+        // 핸드오버 후 Zone은 정상 운영, 히스테리시스 동안만 감속 유지
+        let action = manager.check_and_decide(260_000_000);
+        assert!(matches!(action, FailsafeAction::EnableDegradedMode { .. }));
+    }
+
+    #[test]
+    fn test_lost_zone_is_isolated_not_stopped() {
+        let mut manager = zoned_manager();
+        for edge in [10, 11] {
+            manager.receive_heartbeat(edge, 400_000_000);
+        }
+
+        // Zone 2의 유일한 Edge 20 상실 (>300ms)
+        let action = manager.check_and_decide(400_000_000);
+        assert!(matches!(
+            action,
+            FailsafeAction::ZoneIsolation { zone_id: 2 }
+        ));
+        assert!(manager.is_zone_isolated(2));
+        assert_eq!(manager.current_mode(), OperationMode::Degraded);
+
+        // 격리는 한 번만 보고
+        let action = manager.check_and_decide(410_000_000);
+        assert!(matches!(action, FailsafeAction::EnableDegradedMode { .. }));
+
+        // 모든 Zone 상실 시에만 비상 정지
+        let action = manager.check_and_decide(1_000_000_000);
+        assert!(matches!(action, FailsafeAction::EmergencyStop));
+    }
+
+    #[test]
+    fn test_reported_failures_trigger_handover() {
+        let mut manager = zoned_manager();
+        for _ in 0..3 {
+            manager.report_edge_failure(10, 10_000_000);
+        }
+        assert_eq!(manager.get_edge_status(10), Some(EdgeStatus::Failed));
+
+        let action = manager.check_and_decide(50_000_000);
+        assert!(matches!(
+            action,
+            FailsafeAction::EdgeHandover {
+                from_edge: 10,
+                to_edge: 11
+            }
+        ));
+    }
+
+    #[test]
+    fn test_recovery_hysteresis() {
+        let mut manager = FailsafeManager::with_default_config(1);
+        manager.register_edge(1);
+        manager.receive_heartbeat(1, 0);
+        let action = manager.check_and_decide(250_000_000);
+        assert!(matches!(action, FailsafeAction::EnableDegradedMode { .. }));
+
+        // 복귀 직후에는 감속 유지
+        manager.receive_heartbeat(1, 300_000_000);
+        let action = manager.check_and_decide(300_000_000);
+        assert!(matches!(action, FailsafeAction::EnableDegradedMode { .. }));
+
+        // 500ms 연속 정상 후 Normal
+        for t in (350..=800).step_by(50) {
+            manager.receive_heartbeat(1, t * 1_000_000);
+            let action = manager.check_and_decide(t * 1_000_000);
+            if t < 800 {
+                assert_eq!(manager.current_mode(), OperationMode::Degraded, "t={t}");
+            } else {
+                assert!(matches!(action, FailsafeAction::None));
+            }
+        }
+        assert_eq!(manager.current_mode(), OperationMode::Normal);
+    }
+
+    #[test]
+    fn test_isolated_zone_rejoins_after_hold() {
+        let mut manager = zoned_manager();
+        manager.receive_heartbeat(10, 400_000_000);
+        manager.check_and_decide(400_000_000);
+        assert!(manager.is_zone_isolated(2));
+
+        manager.receive_heartbeat(10, 500_000_000);
+        manager.receive_heartbeat(20, 500_000_000);
+        manager.check_and_decide(500_000_000);
+        assert!(manager.is_zone_isolated(2));
+
+        for t in [700, 900, 1_000] {
+            manager.receive_heartbeat(10, t * 1_000_000);
+            manager.receive_heartbeat(20, t * 1_000_000);
+            manager.check_and_decide(t * 1_000_000);
+        }
+        assert!(!manager.is_zone_isolated(2));
+    }
 }
@@ -2,4 +2,4 @@
 
 mod manager;
 
-pub use manager::{EdgeStatus, FailsafeAction, FailsafeConfig, FailsafeManager};
+pub use manager::{EdgeStatus, FailsafeAction, FailsafeConfig, FailsafeManager, OperationMode};
@@ -7,18 +7,33 @@
 //! - `sync`: 상태 동기화
 //! - `rollback`: 롤백 관리자
 //! - `failsafe`: 장애 대응 관리자
+//! - `transport`: Edge↔Robot QUIC 전송 계층
 //!
 //! ## PPR 매핑
 //!
 //! - `AI_make_RollbackManager` → `RollbackManager`
+//! - `AI_process_Resimulation` → `RollbackManager::execute_rollback()`
+//! - `AI_make_SnapshotStore` → `SnapshotStore`
 //! - `AI_make_FailsafeManager` → `FailsafeManager`
 //! - `AI_process_StateComparison` → `StateComparator`
+//! - `AI_process_DeltaVerification` → `DeltaVerifier`
+//! - `AI_make_TransportEndpoint` → `TransportEndpoint`
+//! - `AI_make_TransportSession` → `Session`
+//! - `AI_make_TransportFrame` → `SapMessage::encode()`
+//! - `AI_make_MessageLink` → `MessageLink`
+//! - `AI_make_LinkEmulator` → `LinkEmulator`
+//! - `AI_make_ReliableChannel` → `ReliableChannel`
 
 pub mod failsafe;
 pub mod rollback;
 pub mod sync;
+pub mod transport;
 
 // 주요 타입 re-export
 pub use failsafe::{FailsafeAction, FailsafeManager};
 pub use rollback::{RollbackEvent, RollbackManager};
-pub use sync::{StateComparator, SyncResult};
+pub use sync::{DeltaVerification, DeltaVerifier, StateComparator, SyncConfig, SyncResult};
+pub use transport::{
+    EmulatedLink, LinkEmulator, LinkEmulatorConfig, MessageLink, ReliableChannel, ReliableHub,
+    SapMessage, Session, SessionConfig, TransportEndpoint, TransportError,
+};
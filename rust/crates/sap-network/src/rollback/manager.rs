@@ -12,14 +12,21 @@
 //! 2. 물리적 로봇에게는 RollbackFrame을 통해 복구 궤적(safe_trajectory) 전달
 //! 3. 로봇은 safe_trajectory를 따라 물리적으로 복구 동작 수행
 //!
-//! PPR 매핑: AI_make_RollbackManager
+//! 롤백 시 스냅샷을 복원한 뒤 틱별 입력 로그(명령/텔레메트리)를 재생하여
+//! 현재 틱까지 재시뮬레이션하고, 그 결과로 safe_trajectory를 채운다.
+//!
+//! PPR 매핑: AI_make_RollbackManager, AI_process_Resimulation
 
-use sap_core::packet::{RollbackFrame, RollbackReason as CoreRollbackReason};
-use sap_core::types::WorldState;
-use std::collections::HashMap;
+use super::SnapshotStore;
+use sap_core::packet::{PredictedState, RollbackFrame, RollbackReason as CoreRollbackReason};
+use sap_core::prediction::{KinematicState, MotionModel};
+use sap_core::types::{RobotState, Velocity, WorldState};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// 스냅샷 저장 전략
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
 pub enum SnapshotStrategy {
     /// 고정 틱 간격으로 저장 (기본)
     /// - 장점: 예측 가능, 구현 간단
@@ -61,16 +68,29 @@ impl Default for SnapshotStrategy {
 impl SnapshotStrategy {
     /// 다음 스냅샷까지의 간격 계산
     pub fn compute_interval(&self, consecutive_rollbacks: u32) -> u64 {
+        self.interval_for(consecutive_rollbacks, None)
+    }
+
+    /// 실측 스냅샷 크기를 반영한 간격 계산
+    ///
+    /// MemoryBudget은 `measured_snapshot_bytes`가 있으면 예상 크기 대신 사용한다.
+    pub fn interval_for(
+        &self,
+        consecutive_rollbacks: u32,
+        measured_snapshot_bytes: Option<usize>,
+    ) -> u64 {
         match self {
-            Self::TickBased { interval } => *interval,
+            Self::TickBased { interval } => (*interval).max(1),
             Self::MemoryBudget {
                 max_bytes,
                 estimated_size_per_snapshot,
             } => {
                 // 예상 최대 스냅샷 수에서 간격 역산
-                let size = (*estimated_size_per_snapshot).max(1);
+                let size = measured_snapshot_bytes
+                    .unwrap_or(*estimated_size_per_snapshot)
+                    .max(1);
                 let max_snapshots = *max_bytes / size;
-                (100 / max_snapshots.max(1)) as u64 // 100틱당 max_snapshots개
+                ((100 / max_snapshots.max(1)) as u64).max(1) // 100틱당 max_snapshots개
             }
             Self::Adaptive {
                 base_interval,
@@ -80,11 +100,19 @@ impl SnapshotStrategy {
                 // 롤백 횟수에 따라 간격 감소
                 let factor = reduction_factor.powf(consecutive_rollbacks as f32);
                 let interval = (*base_interval as f32 * factor) as u64;
-                interval.max(*min_interval)
+                interval.max(*min_interval).max(1)
             }
         }
     }
 
+    /// 메모리 예산 (MemoryBudget 외에는 None)
+    pub fn memory_budget(&self) -> Option<usize> {
+        match self {
+            Self::MemoryBudget { max_bytes, .. } => Some(*max_bytes),
+            _ => None,
+        }
+    }
+
     /// 전략 이름 반환
     pub fn name(&self) -> &'static str {
         match self {
@@ -96,12 +124,13 @@ impl SnapshotStrategy {
 }
 
 /// 롤백 관리자 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct RollbackConfig {
     /// 최대 스냅샷 보관 개수
     pub max_snapshots: usize,
 
-    /// 스냅샷 간격 (틱) - 기본 전략용 (deprecated, use strategy)
+    /// 스냅샷 간격 (틱) - 사용하지 않음 (deprecated, use strategy)
     pub snapshot_interval: u64,
 
     /// 스냅샷 저장 전략
@@ -112,6 +141,18 @@ pub struct RollbackConfig {
 
     /// 롤백 쿨다운 (밀리초)
     pub rollback_cooldown_ms: u64,
+
+    /// 재시뮬레이션 틱 간격 (밀리초)
+    pub tick_interval_ms: u64,
+
+    /// 입력 로그 보관 틱 수
+    pub input_log_ticks: u64,
+
+    /// 키프레임 간격 (스냅샷 수, 사이는 차분 저장 / 1이면 전체 저장)
+    pub keyframe_interval: usize,
+
+    /// 재시뮬레이션 기본 예측 모델 (로봇별 모델이 없을 때)
+    pub motion_model: MotionModel,
 }
 
 impl Default for RollbackConfig {
@@ -122,6 +163,10 @@ impl Default for RollbackConfig {
             strategy: SnapshotStrategy::default(),
             max_consecutive_rollbacks: 3,
             rollback_cooldown_ms: 500,
+            tick_interval_ms: 50,
+            input_log_ticks: 1_000, // 50초 @ 50ms
+            keyframe_interval: 10,
+            motion_model: MotionModel::default(),
         }
     }
 }
@@ -130,11 +175,27 @@ impl Default for RollbackConfig {
 pub struct RollbackManager {
     config: RollbackConfig,
     zone_id: u32,
-    snapshots: HashMap<u64, WorldState>,
-    snapshot_ticks: Vec<u64>,
+    snapshots: SnapshotStore,
     rollback_history: Vec<RollbackEvent>,
     consecutive_rollbacks: HashMap<u64, u32>,
     last_rollback_time: HashMap<u64, u64>,
+    inputs: BTreeMap<u64, Vec<TickInput>>,
+    motion_models: HashMap<u64, MotionModel>,
+    resimulated: Option<WorldState>,
+}
+
+/// 틱 입력 (재시뮬레이션 시 재생)
+#[derive(Debug, Clone)]
+pub enum TickInput {
+    /// 모션 명령 - 해당 틱부터 적용되는 목표 속도/각속도
+    Command {
+        robot_id: u64,
+        velocity: Velocity,
+        omega: f32,
+    },
+
+    /// 텔레메트리 - 해당 틱의 실측 상태 (예측 상태를 덮어씀)
+    Telemetry(RobotState),
 }
 
 /// 롤백 이벤트
@@ -151,23 +212,52 @@ pub struct RollbackEvent {
 /// 롤백 이유
 #[derive(Debug, Clone)]
 pub enum RollbackReason {
-    PredictionError { delta: f32 },
-    CollisionPredicted,
-    TicketViolation,
+    PredictionError {
+        delta: f32,
+    },
+    CollisionPredicted {
+        obstacle_id: u64,
+        time_to_collision: f32,
+    },
+    TicketViolation {
+        ticket_id: u128,
+    },
     EdgeRecovery,
     Manual,
 }
 
+impl From<&RollbackReason> for CoreRollbackReason {
+    fn from(reason: &RollbackReason) -> Self {
+        match *reason {
+            RollbackReason::PredictionError { delta } => Self::PredictionError {
+                delta_magnitude: delta,
+            },
+            RollbackReason::CollisionPredicted {
+                obstacle_id,
+                time_to_collision,
+            } => Self::CollisionPredicted {
+                obstacle_id,
+                time_to_collision,
+            },
+            RollbackReason::TicketViolation { ticket_id } => Self::TicketViolation { ticket_id },
+            RollbackReason::EdgeRecovery => Self::EdgeRecovery,
+            RollbackReason::Manual => Self::Manual,
+        }
+    }
+}
+
 impl RollbackManager {
     pub fn new(zone_id: u32, config: RollbackConfig) -> Self {
         Self {
+            snapshots: SnapshotStore::new(config.keyframe_interval),
             config,
             zone_id,
-            snapshots: HashMap::new(),
-            snapshot_ticks: Vec::new(),
             rollback_history: Vec::new(),
             consecutive_rollbacks: HashMap::new(),
             last_rollback_time: HashMap::new(),
+            inputs: BTreeMap::new(),
+            motion_models: HashMap::new(),
+            resimulated: None,
         }
     }
 
@@ -175,35 +265,126 @@ impl RollbackManager {
         Self::new(zone_id, RollbackConfig::default())
     }
 
+    /// 현재 전략 기준 스냅샷 간격 (틱)
+    ///
+    /// Adaptive는 Zone 내 최대 연속 롤백 횟수, MemoryBudget은 실측 평균 크기를 사용한다.
+    pub fn snapshot_interval(&self) -> u64 {
+        let consecutive = self
+            .consecutive_rollbacks
+            .values()
+            .copied()
+            .max()
+            .unwrap_or(0);
+        let measured = (!self.snapshots.is_empty())
+            .then(|| self.snapshots.memory_bytes() / self.snapshots.len());
+        self.config.strategy.interval_for(consecutive, measured)
+    }
+
+    /// 해당 틱에 스냅샷을 저장해야 하는지 여부
+    pub fn should_snapshot(&self, tick: u64) -> bool {
+        match self.snapshots.last_tick() {
+            Some(last_tick) => tick.saturating_sub(last_tick) >= self.snapshot_interval(),
+            None => true,
+        }
+    }
+
+    /// 스냅샷 저장 (전략상 저장 시점이 아니면 무시)
     pub fn save_snapshot(&mut self, tick: u64, state: WorldState) {
-        if !self.snapshot_ticks.is_empty() {
-            let last_tick = *self.snapshot_ticks.last().unwrap();
-            if tick.saturating_sub(last_tick) < self.config.snapshot_interval {
-                return;
-            }
+        if !self.should_snapshot(tick) {
+            return;
         }
 
-        while self.snapshots.len() >= self.config.max_snapshots {
-            if let Some(oldest_tick) = self.snapshot_ticks.first().copied() {
-                self.snapshots.remove(&oldest_tick);
-                self.snapshot_ticks.remove(0);
+        self.snapshots.push(tick, state);
+
+        // 개수/메모리 한도 내로 오래된 스냅샷부터 제거 (최신 1개는 유지)
+        let budget = self.config.strategy.memory_budget();
+        while self.snapshots.len() > 1 {
+            let over_count = self.snapshots.len() > self.config.max_snapshots;
+            let over_budget = budget.is_some_and(|max| self.snapshots.memory_bytes() > max);
+            if !over_count && !over_budget {
+                break;
             }
+            self.snapshots.pop_front();
         }
+        self.prune_inputs();
+    }
+
+    /// 보관 중인 스냅샷 메모리 (바이트, 추정)
+    pub fn snapshot_memory_bytes(&self) -> usize {
+        self.snapshots.memory_bytes()
+    }
 
-        self.snapshots.insert(tick, state);
-        self.snapshot_ticks.push(tick);
+    /// 모션 명령 기록
+    pub fn record_command(&mut self, tick: u64, robot_id: u64, velocity: Velocity, omega: f32) {
+        self.record_input(
+            tick,
+            TickInput::Command {
+                robot_id,
+                velocity,
+                omega,
+            },
+        );
     }
 
-    pub fn get_snapshot(&self, tick: u64) -> Option<&WorldState> {
-        self.snapshots.get(&tick)
+    /// 텔레메트리(실측 상태) 기록
+    pub fn record_telemetry(&mut self, tick: u64, state: RobotState) {
+        self.record_input(tick, TickInput::Telemetry(state));
     }
 
-    pub fn find_nearest_snapshot(&self, tick: u64) -> Option<(&u64, &WorldState)> {
-        self.snapshot_ticks
-            .iter()
-            .rev()
-            .find(|&&t| t <= tick)
-            .and_then(|t| self.snapshots.get(t).map(|s| (t, s)))
+    /// 틱 입력 기록
+    pub fn record_input(&mut self, tick: u64, input: TickInput) {
+        self.inputs.entry(tick).or_default().push(input);
+        self.prune_inputs();
+    }
+
+    /// 특정 틱의 입력 조회
+    pub fn inputs_at(&self, tick: u64) -> &[TickInput] {
+        self.inputs.get(&tick).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// 기록된 입력 틱 수
+    pub fn input_tick_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// 로봇 예측 모델 설정 (DeltaTick `model_hash`로 보고된 모델)
+    pub fn set_motion_model(&mut self, robot_id: u64, model: MotionModel) {
+        self.motion_models.insert(robot_id, model);
+    }
+
+    /// 로봇 재시뮬레이션 예측 모델
+    pub fn motion_model(&self, robot_id: u64) -> MotionModel {
+        self.motion_models
+            .get(&robot_id)
+            .copied()
+            .unwrap_or(self.config.motion_model)
+    }
+
+    /// 마지막 롤백의 재시뮬레이션 결과 (현재 틱 상태)
+    pub fn resimulated_state(&self) -> Option<&WorldState> {
+        self.resimulated.as_ref()
+    }
+
+    /// 가장 오래된 스냅샷 이전, 보관 기간을 넘은 입력 제거
+    fn prune_inputs(&mut self) {
+        let Some(&latest) = self.inputs.keys().next_back() else {
+            return;
+        };
+        let mut keep_from = latest.saturating_sub(self.config.input_log_ticks);
+        if let Some(oldest_snapshot) = self.snapshots.first_tick() {
+            keep_from = keep_from.min(oldest_snapshot);
+        }
+        self.inputs = self.inputs.split_off(&keep_from);
+    }
+
+    /// 특정 틱 스냅샷 재구성
+    pub fn get_snapshot(&self, tick: u64) -> Option<WorldState> {
+        self.snapshots.get(tick)
+    }
+
+    /// `tick` 이하 가장 가까운 스냅샷 재구성
+    pub fn find_nearest_snapshot(&self, tick: u64) -> Option<(u64, WorldState)> {
+        self.snapshots.nearest(tick)
     }
 
     pub fn execute_rollback(
@@ -229,27 +410,25 @@ impl RollbackManager {
             return Err(RollbackError::TooManyConsecutive { count: consecutive });
         }
 
-        // 스냅샷 찾기
+        // 스냅샷 복원
         let (rollback_tick, snapshot) = self
             .find_nearest_snapshot(current_tick)
             .ok_or(RollbackError::NoSnapshotAvailable)?;
 
+        // 현재 틱까지 재시뮬레이션
+        let (state, trajectory) = self.resimulate(snapshot, rollback_tick, current_tick, robot_id);
+
         // RollbackFrame 생성
-        let frame = RollbackFrame::new(
-            self.zone_id,
-            robot_id,
-            *rollback_tick,
-            CoreRollbackReason::PredictionError {
-                delta_magnitude: 0.0,
-            },
-        )
-        .with_state_hash(snapshot.compute_hash())
-        .with_timestamp(timestamp_ns);
+        let frame = RollbackFrame::new(self.zone_id, robot_id, rollback_tick, (&reason).into())
+            .with_state_hash(state.compute_hash())
+            .with_trajectory(trajectory)
+            .with_timestamp(timestamp_ns);
+        self.resimulated = Some(state);
 
         // 히스토리 기록
         let event = RollbackEvent {
             robot_id,
-            rollback_tick: *rollback_tick,
+            rollback_tick,
             current_tick,
             reason,
             timestamp_ns,
@@ -263,6 +442,67 @@ impl RollbackManager {
         Ok(frame)
     }
 
+    /// 스냅샷 틱부터 현재 틱까지 입력을 재생
+    ///
+    /// 각 틱에서 텔레메트리로 상태를 보정하고 명령을 적용한 뒤,
+    /// 로봇별 `MotionModel`로 틱 간격만큼 예측한다 (로봇/Edge 추측 항법과 동일).
+    /// 대상 로봇의 틱별 상태가 안전 궤적이 된다.
+    fn resimulate(
+        &self,
+        mut state: WorldState,
+        rollback_tick: u64,
+        current_tick: u64,
+        robot_id: u64,
+    ) -> (WorldState, Vec<PredictedState>) {
+        let tick_ns = self.config.tick_interval_ms * 1_000_000;
+        let mut trajectory = Vec::with_capacity((current_tick - rollback_tick + 1) as usize);
+
+        for tick in rollback_tick..=current_tick {
+            for input in self.inputs_at(tick) {
+                match input {
+                    TickInput::Telemetry(robot) => state.update_robot(robot.clone()),
+                    TickInput::Command {
+                        robot_id,
+                        velocity,
+                        omega,
+                    } => {
+                        if let Some(robot) =
+                            state.robots.iter_mut().find(|r| r.robot_id == *robot_id)
+                        {
+                            robot.velocity = *velocity;
+                            robot.omega = *omega;
+                        }
+                    }
+                }
+            }
+
+            if let Some(robot) = state.get_robot(robot_id) {
+                trajectory.push(PredictedState::new(
+                    (tick - rollback_tick) as u32,
+                    robot.position,
+                    robot.theta,
+                    robot.speed(),
+                ));
+            }
+
+            if tick < current_tick {
+                for robot in &mut state.robots {
+                    let next = self
+                        .motion_model(robot.robot_id)
+                        .predict_ns(&KinematicState::from_robot(robot), tick_ns);
+                    robot.position = next.position;
+                    robot.velocity = next.velocity;
+                    robot.acceleration = next.acceleration;
+                    robot.theta = next.theta;
+                    robot.timestamp_ns += tick_ns;
+                }
+            }
+        }
+
+        let timestamp_ns = state.timestamp_ns + (current_tick - rollback_tick) * tick_ns;
+        (state.with_tick(current_tick, timestamp_ns), trajectory)
+    }
+
     pub fn reset_consecutive(&mut self, robot_id: u64) {
         self.consecutive_rollbacks.remove(&robot_id);
     }
@@ -309,6 +549,7 @@ pub enum RollbackError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use sap_core::types::Position;
 
     fn create_world_state(zone_id: u32, tick: u64) -> WorldState {
         WorldState::new(zone_id).with_tick(tick, tick * 50_000_000)
@@ -330,7 +571,7 @@ mod tests {
         manager.save_snapshot(30, create_world_state(1, 30));
         manager.save_snapshot(50, create_world_state(1, 50));
         let (tick, _) = manager.find_nearest_snapshot(45).unwrap();
-        assert_eq!(*tick, 30);
+        assert_eq!(tick, 30);
     }
 
     #[test]
@@ -392,4 +633,227 @@ mod tests {
         let result = manager.execute_rollback(42, 52, RollbackReason::Manual, 3_000_000_000);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_rollback_resimulates_inputs() {
+        let mut manager = RollbackManager::with_default_config(1);
+        let mut snapshot = create_world_state(1, 10);
+        snapshot.add_robot(RobotState::new(42));
+        manager.save_snapshot(10, snapshot);
+
+        // 틱 10: 1 m/s 전진, 틱 12: 텔레메트리로 y 보정, 틱 13: 정지
+        manager.record_command(10, 42, Velocity::new(1.0, 0.0, 0.0), 0.0);
+        manager.record_telemetry(
+            12,
+            RobotState::new(42)
+                .with_motion(Position::new(0.1, 0.2, 0.0), Velocity::new(1.0, 0.0, 0.0)),
+        );
+        manager.record_command(13, 42, Velocity::ZERO, 0.0);
+
+        let frame = manager
+            .execute_rollback(
+                42,
+                15,
+                RollbackReason::CollisionPredicted {
+                    obstacle_id: 7,
+                    time_to_collision: 0.4,
+                },
+                5_000_000_000,
+            )
+            .unwrap();
+
+        assert_eq!(frame.rollback_tick, 10);
+        assert_eq!(frame.trajectory_len(), 6);
+        assert!(matches!(
+            frame.reason,
+            CoreRollbackReason::CollisionPredicted { obstacle_id: 7, .. }
+        ));
+
+        let last = frame.safe_trajectory.last().unwrap();
+        assert_eq!(last.tick_offset, 5);
+        assert!((last.position.x - 0.15).abs() < 1e-5);
+        assert!((last.position.y - 0.2).abs() < 1e-5);
+        assert_eq!(last.speed, 0.0);
+
+        let state = manager.resimulated_state().unwrap();
+        assert_eq!(state.tick, 15);
+        assert_eq!(frame.world_state_hash, state.compute_hash());
+        assert_ne!(
+            frame.world_state_hash,
+            manager.get_snapshot(10).unwrap().compute_hash()
+        );
+    }
+
+    #[test]
+    fn test_resimulation_uses_shared_motion_model() {
+        let config = RollbackConfig {
+            motion_model: MotionModel::ConstantTurnRateVelocity,
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        let mut snapshot = create_world_state(1, 10);
+        let mut robot = RobotState::new(42)
+            .with_motion(Position::ORIGIN, Velocity::new(-1.0, 0.0, 0.0))
+            .with_timestamp(500_000_000);
+        robot.theta = 3.1;
+        robot.omega = 2.0;
+        snapshot.add_robot(robot.clone());
+        manager.save_snapshot(10, snapshot);
+
+        let frame = manager
+            .execute_rollback(42, 14, RollbackReason::Manual, 1_000_000_000)
+            .unwrap();
+
+        // Edge/로봇 추측 항법과 같은 결과, 방향각은 [-π, π)로 정규화
+        let model = MotionModel::ConstantTurnRateVelocity;
+        let mut expected = KinematicState::from_robot(&robot);
+        for _ in 0..4 {
+            expected = model.predict_ns(&expected, 50_000_000);
+        }
+        let last = frame.safe_trajectory.last().unwrap();
+        assert_eq!(last.position, expected.position);
+        assert_eq!(last.theta, expected.theta);
+        assert!((-std::f32::consts::PI..0.0).contains(&last.theta));
+        assert_eq!(manager.motion_model(42), model);
+
+        manager.set_motion_model(42, MotionModel::ConstantVelocity);
+        assert_eq!(manager.motion_model(42), MotionModel::ConstantVelocity);
+    }
+
+    #[test]
+    fn test_rollback_reason_is_preserved() {
+        let mut manager = RollbackManager::with_default_config(1);
+        manager.save_snapshot(10, create_world_state(1, 10));
+        let frame = manager
+            .execute_rollback(
+                42,
+                20,
+                RollbackReason::PredictionError { delta: 0.15 },
+                1_000_000_000,
+            )
+            .unwrap();
+        assert!(matches!(
+            frame.reason,
+            CoreRollbackReason::PredictionError { delta_magnitude } if delta_magnitude == 0.15
+        ));
+    }
+
+    #[test]
+    fn test_input_log_pruned_with_snapshots() {
+        let config = RollbackConfig {
+            max_snapshots: 2,
+            input_log_ticks: 5,
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        for tick in 0..40 {
+            manager.record_command(tick, 42, Velocity::new(0.5, 0.0, 0.0), 0.0);
+        }
+        assert_eq!(manager.input_tick_count(), 6);
+
+        // 스냅샷 이후 입력은 보관 기간과 무관하게 유지
+        manager.save_snapshot(30, create_world_state(1, 30));
+        for tick in 40..50 {
+            manager.record_command(tick, 42, Velocity::new(0.5, 0.0, 0.0), 0.0);
+        }
+        assert!(manager.inputs_at(33).is_empty());
+        assert!(!manager.inputs_at(34).is_empty());
+        assert_eq!(manager.input_tick_count(), 16);
+        assert_eq!(manager.inputs_at(49).len(), 1);
+    }
+
+    #[test]
+    fn test_snapshot_ring_eviction() {
+        let config = RollbackConfig {
+            max_snapshots: 3,
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        for tick in (0..=50).step_by(10) {
+            manager.save_snapshot(tick, create_world_state(1, tick));
+        }
+        assert_eq!(manager.snapshot_count(), 3);
+        assert!(manager.get_snapshot(20).is_none());
+        let (oldest, _) = manager.find_nearest_snapshot(35).unwrap();
+        assert_eq!(oldest, 30);
+        // 가장 오래된 스냅샷은 키프레임으로 승격
+        assert!(manager.snapshot_memory_bytes() >= create_world_state(1, 0).estimated_size_bytes());
+        assert_eq!(manager.get_snapshot(50).unwrap().tick, 50);
+    }
+
+    #[test]
+    fn test_tick_based_schedule() {
+        let config = RollbackConfig {
+            strategy: SnapshotStrategy::TickBased { interval: 5 },
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        for tick in 1..=20 {
+            manager.save_snapshot(tick, create_world_state(1, tick));
+        }
+        // 1, 6, 11, 16
+        assert_eq!(manager.snapshot_count(), 4);
+        assert!(manager.get_snapshot(16).is_some());
+        assert!(!manager.should_snapshot(20));
+        assert!(manager.should_snapshot(21));
+    }
+
+    #[test]
+    fn test_memory_budget_tracks_actual_size() {
+        let mut state = create_world_state(1, 0);
+        for id in 0..10 {
+            state.add_robot(RobotState::new(id));
+        }
+        let size = state.estimated_size_bytes();
+        let config = RollbackConfig {
+            strategy: SnapshotStrategy::MemoryBudget {
+                max_bytes: size * 4,
+                estimated_size_per_snapshot: 1,
+            },
+            keyframe_interval: 1,
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        assert_eq!(manager.snapshot_interval(), 1);
+
+        for tick in 0..100 {
+            manager.save_snapshot(tick, state.clone().with_tick(tick, 0));
+            assert!(manager.snapshot_memory_bytes() <= size * 4);
+        }
+        // 차분 기준 상태 복제분 포함 실측: 스냅샷 2개 + 기준 1개 → 100틱당 2개 → 50틱 간격
+        assert_eq!(manager.snapshot_interval(), 50);
+        assert_eq!(manager.snapshot_count(), 2);
+        assert!(manager.get_snapshot(50).is_some());
+    }
+
+    #[test]
+    fn test_adaptive_interval_follows_rollbacks() {
+        let config = RollbackConfig {
+            strategy: SnapshotStrategy::Adaptive {
+                base_interval: 20,
+                reduction_factor: 0.5,
+                min_interval: 4,
+            },
+            rollback_cooldown_ms: 0,
+            ..Default::default()
+        };
+        let mut manager = RollbackManager::new(1, config);
+        manager.save_snapshot(0, create_world_state(1, 0));
+        assert_eq!(manager.snapshot_interval(), 20);
+        assert!(!manager.should_snapshot(10));
+
+        manager
+            .execute_rollback(42, 10, RollbackReason::Manual, 1_000_000_000)
+            .unwrap();
+        assert_eq!(manager.snapshot_interval(), 10);
+        assert!(manager.should_snapshot(10));
+
+        manager
+            .execute_rollback(42, 11, RollbackReason::Manual, 2_000_000_000)
+            .unwrap();
+        assert_eq!(manager.snapshot_interval(), 5);
+
+        manager.reset_consecutive(42);
+        assert_eq!(manager.snapshot_interval(), 20);
+    }
 }
@@ -1,7 +1,10 @@
 //! rollback 모듈 - 롤백 관리
 
 mod manager;
+mod snapshot;
 
 pub use manager::{
-    RollbackConfig, RollbackEvent, RollbackManager, RollbackReason, SnapshotStrategy,
+    RollbackConfig, RollbackError, RollbackEvent, RollbackManager, RollbackReason,
+    SnapshotStrategy, TickInput,
 };
+pub use snapshot::{SnapshotStore, WorldDelta};
@@ -0,0 +1,539 @@
+//! SnapshotStore - 키프레임 + 틱별 차분 스냅샷 저장소
+//!
+//! 매 스냅샷마다 WorldState 전체를 복제하지 않고, `keyframe_interval`개마다
+//! 전체 상태(키프레임)를 저장하고 그 사이는 직전 스냅샷 대비 차분만 저장한다.
+//! 차분은 로봇/동적 장애물 변경·추가·삭제, 정적 장애물 교체, VTS 할당 변경으로 구성되며
+//! 재구성 결과는 원본과 정확히 같다 (해시 포함).
+//!
+//! PPR 매핑: AI_make_SnapshotStore
+
+use sap_core::types::{DynamicObstacle, Position, RobotState, VtsAllocationInfo, WorldState};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::mem::size_of;
+
+/// ID로 식별되는 리스트 항목
+trait Keyed: Clone + PartialEq {
+    fn key(&self) -> u64;
+}
+
+impl Keyed for RobotState {
+    fn key(&self) -> u64 {
+        self.robot_id
+    }
+}
+
+impl Keyed for DynamicObstacle {
+    fn key(&self) -> u64 {
+        self.id
+    }
+}
+
+/// ID 기반 리스트 차분
+#[derive(Debug, Clone, PartialEq)]
+enum ListDelta<T> {
+    /// 삭제 → 변경(제자리 교체) → 추가(끝에 붙임) 순서로 적용
+    Patch {
+        removed: Vec<u64>,
+        changed: Vec<T>,
+        added: Vec<T>,
+    },
+
+    /// 순서 변경/중복 ID 등 패치로 표현할 수 없는 경우 전체 교체
+    Replace(Vec<T>),
+}
+
+impl<T: Keyed> ListDelta<T> {
+    fn diff(base: &[T], target: &[T]) -> Self {
+        let base_index: HashMap<u64, usize> = base
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (item.key(), i))
+            .collect();
+        let target_keys: HashSet<u64> = target.iter().map(Keyed::key).collect();
+        if base_index.len() != base.len() || target_keys.len() != target.len() {
+            return Self::Replace(target.to_vec());
+        }
+
+        let mut changed = Vec::new();
+        let mut added = Vec::new();
+        for item in target {
+            match base_index.get(&item.key()) {
+                Some(&i) if base[i] == *item => {}
+                Some(_) => changed.push(item.clone()),
+                None => added.push(item.clone()),
+            }
+        }
+        let removed: Vec<u64> = base
+            .iter()
+            .map(Keyed::key)
+            .filter(|key| !target_keys.contains(key))
+            .collect();
+
+        // 패치 적용 후 순서가 원본과 같아야 정확히 재구성된다
+        let patched_order = base
+            .iter()
+            .map(Keyed::key)
+            .filter(|key| target_keys.contains(key))
+            .chain(added.iter().map(Keyed::key));
+        if !patched_order.eq(target.iter().map(Keyed::key)) {
+            return Self::Replace(target.to_vec());
+        }
+
+        Self::Patch {
+            removed,
+            changed,
+            added,
+        }
+    }
+
+    fn apply(&self, list: &mut Vec<T>) {
+        match self {
+            Self::Patch {
+                removed,
+                changed,
+                added,
+            } => {
+                if !removed.is_empty() {
+                    let removed: HashSet<u64> = removed.iter().copied().collect();
+                    list.retain(|item| !removed.contains(&item.key()));
+                }
+                if !changed.is_empty() {
+                    let index: HashMap<u64, usize> = list
+                        .iter()
+                        .enumerate()
+                        .map(|(i, item)| (item.key(), i))
+                        .collect();
+                    for item in changed {
+                        if let Some(&i) = index.get(&item.key()) {
+                            list[i] = item.clone();
+                        }
+                    }
+                }
+                list.extend(added.iter().cloned());
+            }
+            Self::Replace(items) => *list = items.clone(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Patch { removed, changed, added }
+            if removed.is_empty() && changed.is_empty() && added.is_empty())
+    }
+
+    fn estimated_size_bytes(&self) -> usize {
+        size_of::<Self>()
+            + match self {
+                Self::Patch {
+                    removed,
+                    changed,
+                    added,
+                } => {
+                    removed.len() * size_of::<u64>()
+                        + (changed.len() + added.len()) * size_of::<T>()
+                }
+                Self::Replace(items) => items.len() * size_of::<T>(),
+            }
+    }
+}
+
+/// 직전 스냅샷 대비 월드 상태 차분
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldDelta {
+    zone_id: u32,
+    tick: u64,
+    timestamp_ns: u64,
+    robots: ListDelta<RobotState>,
+    static_obstacles: Option<Vec<Position>>,
+    dynamic_obstacles: ListDelta<DynamicObstacle>,
+    vts_changed: Vec<(u64, Vec<VtsAllocationInfo>)>,
+    vts_removed: Vec<u64>,
+}
+
+impl WorldDelta {
+    /// `base` → `target` 차분 계산
+    pub fn diff(base: &WorldState, target: &WorldState) -> Self {
+        let vts_changed = target
+            .vts_allocations
+            .iter()
+            .filter(|(id, allocations)| base.vts_allocations.get(id) != Some(allocations))
+            .map(|(&id, allocations)| (id, allocations.clone()))
+            .collect();
+        let vts_removed = base
+            .vts_allocations
+            .keys()
+            .filter(|id| !target.vts_allocations.contains_key(id))
+            .copied()
+            .collect();
+
+        Self {
+            zone_id: target.zone_id,
+            tick: target.tick,
+            timestamp_ns: target.timestamp_ns,
+            robots: ListDelta::diff(&base.robots, &target.robots),
+            static_obstacles: (base.static_obstacles != target.static_obstacles)
+                .then(|| target.static_obstacles.clone()),
+            dynamic_obstacles: ListDelta::diff(&base.dynamic_obstacles, &target.dynamic_obstacles),
+            vts_changed,
+            vts_removed,
+        }
+    }
+
+    /// `state`에 차분 적용
+    pub fn apply(&self, state: &mut WorldState) {
+        state.zone_id = self.zone_id;
+        state.tick = self.tick;
+        state.timestamp_ns = self.timestamp_ns;
+        self.robots.apply(&mut state.robots);
+        if let Some(obstacles) = &self.static_obstacles {
+            state.static_obstacles = obstacles.clone();
+        }
+        self.dynamic_obstacles.apply(&mut state.dynamic_obstacles);
+        for id in &self.vts_removed {
+            state.vts_allocations.remove(id);
+        }
+        for (id, allocations) in &self.vts_changed {
+            state.vts_allocations.insert(*id, allocations.clone());
+        }
+    }
+
+    /// 틱/타임스탬프 외 변경 없음 여부
+    pub fn is_empty(&self) -> bool {
+        self.robots.is_empty()
+            && self.static_obstacles.is_none()
+            && self.dynamic_obstacles.is_empty()
+            && self.vts_changed.is_empty()
+            && self.vts_removed.is_empty()
+    }
+
+    /// 메모리 사용량 추정 (바이트)
+    pub fn estimated_size_bytes(&self) -> usize {
+        size_of::<Self>()
+            + self.robots.estimated_size_bytes()
+            + self
+                .static_obstacles
+                .as_ref()
+                .map_or(0, |obstacles| obstacles.len() * size_of::<Position>())
+            + self.dynamic_obstacles.estimated_size_bytes()
+            + self
+                .vts_changed
+                .iter()
+                .map(|(_, allocations)| {
+                    size_of::<(u64, Vec<VtsAllocationInfo>)>()
+                        + allocations.len() * size_of::<VtsAllocationInfo>()
+                })
+                .sum::<usize>()
+            + self.vts_removed.len() * size_of::<u64>()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum SnapshotData {
+    Keyframe(WorldState),
+    Delta(WorldDelta),
+}
+
+#[derive(Debug, Clone)]
+struct SnapshotEntry {
+    tick: u64,
+    data: SnapshotData,
+    size_bytes: usize,
+}
+
+impl SnapshotEntry {
+    fn keyframe(tick: u64, state: WorldState) -> Self {
+        Self {
+            tick,
+            size_bytes: state.estimated_size_bytes(),
+            data: SnapshotData::Keyframe(state),
+        }
+    }
+
+    fn delta(tick: u64, delta: WorldDelta) -> Self {
+        Self {
+            tick,
+            size_bytes: delta.estimated_size_bytes(),
+            data: SnapshotData::Delta(delta),
+        }
+    }
+}
+
+/// 키프레임 + 차분 스냅샷 저장소 (틱 오름차순 링)
+///
+/// PPR: AI_make_SnapshotStore(keyframe_interval) -> SnapshotStore
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    keyframe_interval: usize,
+    entries: VecDeque<SnapshotEntry>,
+    since_keyframe: usize,
+    memory_bytes: usize,
+    /// 마지막 스냅샷 전체 상태 (다음 차분 기준)
+    tip: Option<WorldState>,
+}
+
+impl SnapshotStore {
+    /// 새 저장소 생성 (`keyframe_interval`개마다 키프레임, 1이면 전체 저장)
+    pub fn new(keyframe_interval: usize) -> Self {
+        Self {
+            keyframe_interval: keyframe_interval.max(1),
+            entries: VecDeque::new(),
+            since_keyframe: 0,
+            memory_bytes: 0,
+            tip: None,
+        }
+    }
+
+    /// 스냅샷 추가 - `tick`은 마지막 스냅샷보다 커야 하며, 아니면 무시
+    pub fn push(&mut self, tick: u64, state: WorldState) {
+        if self.last_tick().is_some_and(|last| tick <= last) {
+            return;
+        }
+
+        let entry = match &self.tip {
+            Some(base) if self.since_keyframe < self.keyframe_interval => {
+                self.since_keyframe += 1;
+                SnapshotEntry::delta(tick, WorldDelta::diff(base, &state))
+            }
+            _ => {
+                self.since_keyframe = 1;
+                SnapshotEntry::keyframe(tick, state.clone())
+            }
+        };
+        self.memory_bytes += entry.size_bytes;
+        self.entries.push_back(entry);
+        self.tip = Some(state);
+    }
+
+    /// 가장 오래된 스냅샷 제거 - 다음 차분은 키프레임으로 승격
+    pub fn pop_front(&mut self) -> Option<u64> {
+        let front = self.entries.pop_front()?;
+        self.memory_bytes -= front.size_bytes;
+
+        let SnapshotData::Keyframe(mut state) = front.data else {
+            unreachable!("front snapshot is always a keyframe");
+        };
+        if let Some(next) = self.entries.front_mut() {
+            if let SnapshotData::Delta(delta) = &next.data {
+                delta.apply(&mut state);
+                self.memory_bytes -= next.size_bytes;
+                *next = SnapshotEntry::keyframe(next.tick, state);
+                self.memory_bytes += next.size_bytes;
+            }
+        } else {
+            self.tip = None;
+            self.since_keyframe = 0;
+        }
+        Some(front.tick)
+    }
+
+    /// 특정 틱 스냅샷 재구성
+    pub fn get(&self, tick: u64) -> Option<WorldState> {
+        let index = self
+            .entries
+            .binary_search_by_key(&tick, |entry| entry.tick)
+            .ok()?;
+        Some(self.reconstruct(index))
+    }
+
+    /// `tick` 이하 가장 가까운 스냅샷 재구성
+    pub fn nearest(&self, tick: u64) -> Option<(u64, WorldState)> {
+        let index = self
+            .entries
+            .partition_point(|entry| entry.tick <= tick)
+            .checked_sub(1)?;
+        Some((self.entries[index].tick, self.reconstruct(index)))
+    }
+
+    fn reconstruct(&self, index: usize) -> WorldState {
+        if index + 1 == self.entries.len() {
+            if let Some(tip) = &self.tip {
+                return tip.clone();
+            }
+        }
+
+        let keyframe = (0..=index)
+            .rev()
+            .find(|&i| matches!(self.entries[i].data, SnapshotData::Keyframe(_)))
+            .expect("front snapshot is always a keyframe");
+        let SnapshotData::Keyframe(base) = &self.entries[keyframe].data else {
+            unreachable!();
+        };
+        let mut state = base.clone();
+        for entry in self.entries.range(keyframe + 1..=index) {
+            if let SnapshotData::Delta(delta) = &entry.data {
+                delta.apply(&mut state);
+            }
+        }
+        state
+    }
+
+    /// 저장된 스냅샷 틱 (오름차순)
+    pub fn ticks(&self) -> impl DoubleEndedIterator<Item = u64> + '_ {
+        self.entries.iter().map(|entry| entry.tick)
+    }
+
+    /// 가장 오래된 스냅샷 틱
+    pub fn first_tick(&self) -> Option<u64> {
+        self.entries.front().map(|entry| entry.tick)
+    }
+
+    /// 가장 최근 스냅샷 틱
+    pub fn last_tick(&self) -> Option<u64> {
+        self.entries.back().map(|entry| entry.tick)
+    }
+
+    /// 스냅샷 수
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 비어 있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 키프레임 수
+    pub fn keyframe_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.data, SnapshotData::Keyframe(_)))
+            .count()
+    }
+
+    /// 저장된 키프레임/차분 및 차분 기준 상태 메모리 (바이트, 추정)
+    pub fn memory_bytes(&self) -> usize {
+        self.memory_bytes
+            + self
+                .tip
+                .as_ref()
+                .map_or(0, WorldState::estimated_size_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::Velocity;
+
+    fn world(tick: u64, robots: u64) -> WorldState {
+        let mut state = WorldState::new(1).with_tick(tick, tick * 50_000_000);
+        for id in 0..robots {
+            state.add_robot(
+                RobotState::new(id).with_motion(Position::new(id as f32, 0.0, 0.0), Velocity::ZERO),
+            );
+        }
+        state
+    }
+
+    /// 틱마다 로봇 하나 이동, 주기적으로 로봇/장애물/할당 추가·삭제
+    fn evolve(state: &WorldState, tick: u64) -> WorldState {
+        let mut next = state.clone().with_tick(tick, tick * 50_000_000);
+        let id = tick % next.robots.len() as u64;
+        if let Some(robot) = next.robots.iter_mut().find(|r| r.robot_id == id) {
+            robot.position.y += 0.05;
+        }
+        if tick.is_multiple_of(3) {
+            next.dynamic_obstacles.push(DynamicObstacle::new(
+                tick,
+                Position::new(tick as f32, 1.0, 0.0),
+                Velocity::new(0.1, 0.0, 0.0),
+                0.3,
+            ));
+        }
+        if tick.is_multiple_of(5) {
+            next.dynamic_obstacles.retain(|o| o.id + 6 > tick);
+            next.robots.retain(|r| r.robot_id != 1000 + tick - 5);
+            next.add_robot(RobotState::new(1000 + tick));
+            next.vts_allocations.insert(
+                tick % 4,
+                vec![VtsAllocationInfo {
+                    vts_id: tick as u128,
+                    voxel_id: tick,
+                    t_start_ns: 0,
+                    t_end_ns: 1,
+                    ticket_id: 0,
+                }],
+            );
+        }
+        if tick.is_multiple_of(7) {
+            next.vts_allocations.remove(&(tick % 4));
+            next.static_obstacles
+                .push(Position::new(0.0, tick as f32, 0.0));
+        }
+        next
+    }
+
+    #[test]
+    fn test_reconstruction_is_exact() {
+        let mut store = SnapshotStore::new(4);
+        let mut history = Vec::new();
+        let mut state = world(0, 20);
+        for tick in 0..30 {
+            state = evolve(&state, tick);
+            store.push(tick, state.clone());
+            history.push(state.clone());
+        }
+
+        assert_eq!(store.len(), 30);
+        assert_eq!(store.keyframe_count(), 8);
+        for (tick, original) in history.iter().enumerate() {
+            let restored = store.get(tick as u64).unwrap();
+            assert_eq!(&restored, original);
+            assert_eq!(restored.compute_hash(), original.compute_hash());
+        }
+    }
+
+    #[test]
+    fn test_eviction_promotes_delta_to_keyframe() {
+        let mut store = SnapshotStore::new(10);
+        let mut history = Vec::new();
+        let mut state = world(0, 5);
+        for tick in 0..12 {
+            state = evolve(&state, tick);
+            store.push(tick, state.clone());
+            history.push(state.clone());
+        }
+
+        for _ in 0..3 {
+            store.pop_front();
+        }
+        assert_eq!(store.first_tick(), Some(3));
+        for tick in 3..12 {
+            assert_eq!(store.get(tick).unwrap(), history[tick as usize]);
+        }
+        let (nearest, restored) = store.nearest(100).unwrap();
+        assert_eq!(nearest, 11);
+        assert_eq!(restored, history[11]);
+        assert!(store.nearest(2).is_none());
+    }
+
+    #[test]
+    fn test_delta_is_smaller_than_keyframe() {
+        let mut store = SnapshotStore::new(100);
+        let mut state = world(0, 1000);
+        store.push(0, state.clone());
+        let keyframe_bytes = store.memory_bytes();
+
+        for tick in 1..100 {
+            state = evolve(&state, tick);
+            store.push(tick, state.clone());
+        }
+        // 100개 전체 복제 대비 1/10 미만
+        assert!(store.memory_bytes() < keyframe_bytes * 10);
+
+        let unchanged = WorldDelta::diff(&state, &state.clone().with_tick(200, 0));
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_reordered_list_falls_back_to_replace() {
+        let base = world(0, 3);
+        let mut target = base.clone();
+        target.robots.reverse();
+
+        let delta = WorldDelta::diff(&base, &target);
+        assert!(matches!(delta.robots, ListDelta::Replace(_)));
+        let mut restored = base.clone();
+        delta.apply(&mut restored);
+        assert_eq!(restored, target);
+    }
+}
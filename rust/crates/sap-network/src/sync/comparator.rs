@@ -1,24 +1,102 @@
 //! StateComparator - 상태 비교 및 동기화
 //!
+//! 위치/방향 오차를 임계값으로 정규화한 점수(1.0 = 롤백 임계값)에 대해
+//! 로봇별 EWMA 평균·분산과 CUSUM 누적합을 유지하여
+//! 단발성 글리치, 지속적 드리프트, 롤백 필요 상황을 구분한다.
+//! 글리치 보류는 평균이 낮고 분산도 작은 안정된 기준선에서만 허용한다.
+//!
 //! PPR 매핑: AI_process_StateComparison
 
 use sap_core::packet::DeltaTickPacket;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::f32::consts::{PI, TAU};
+
+/// 상태 비교 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncConfig {
+    /// 위치 롤백 임계값 (m)
+    pub rollback_threshold: f32,
+
+    /// 방향 롤백 임계값 (rad)
+    pub heading_threshold: f32,
+
+    /// 경고 비율 (정규화 오차)
+    pub warning_ratio: f32,
+
+    /// 글리치로 볼 수 있는 최대 정규화 오차 - 이상이면 즉시 롤백
+    pub glitch_limit: f32,
+
+    /// 글리치 판정에 필요한 최소 샘플 수 (기준선 확보 전에는 롤백)
+    pub min_baseline_samples: u64,
+
+    /// 글리치 판정 기준선의 최대 EWMA 표준편차 (정규화 오차) - 초과하면 롤백
+    pub glitch_max_std_dev: f32,
+
+    /// EWMA 평활 계수 (0.0~1.0)
+    pub ewma_alpha: f32,
+
+    /// CUSUM 허용 오차 k (정규화 오차, 정상 상태 오차 수준)
+    pub cusum_slack: f32,
+
+    /// CUSUM 경보 임계값 h
+    pub cusum_threshold: f32,
+
+    /// 히스토리 최대 크기
+    pub history_capacity: usize,
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            rollback_threshold: 0.1, // 10cm
+            heading_threshold: 0.2,  // ~11°
+            warning_ratio: 0.7,
+            glitch_limit: 2.0,
+            min_baseline_samples: 10,
+            glitch_max_std_dev: 0.4,
+            ewma_alpha: 0.1,
+            cusum_slack: 0.5,
+            cusum_threshold: 4.0,
+            history_capacity: 1000,
+        }
+    }
+}
+
+/// 로봇별 예측 오차 통계 (정규화 오차 기준)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DriftStats {
+    /// 샘플 수 (마지막 롤백 이후)
+    pub samples: u64,
+
+    /// EWMA 평균
+    pub ewma: f32,
+
+    /// EWMA 분산
+    pub variance: f32,
+
+    /// CUSUM 누적합
+    pub cusum: f32,
+
+    /// 연속 임계값 초과 횟수
+    pub consecutive_exceeded: u32,
+
+    /// 마지막 정규화 오차
+    pub last_error: f32,
+}
 
 /// 상태 비교기
 ///
 /// 예측 상태와 실제 상태를 비교하여 롤백 필요 여부 결정
 pub struct StateComparator {
-    /// 롤백 임계값 (미터)
-    rollback_threshold: f32,
-
-    /// 경고 임계값 (롤백 임계값의 일정 비율)
-    warning_threshold: f32,
+    config: SyncConfig,
 
-    /// 최근 비교 결과 기록
-    history: Vec<ComparisonMetrics>,
+    /// 로봇별 오차 통계
+    stats: HashMap<u64, DriftStats>,
 
-    /// 히스토리 최대 크기
-    history_capacity: usize,
+    /// 최근 비교 결과 기록 (링)
+    history: VecDeque<ComparisonMetrics>,
 }
 
 /// 비교 메트릭스
@@ -46,9 +124,15 @@ pub enum SyncResult {
     /// 동기화 상태 양호
     InSync,
 
-    /// 경고 (드리프트 감지, 모니터링 필요)
+    /// 경고 (오차 증가, 모니터링 필요)
     Warning,
 
+    /// 단발성 글리치 - 안정된 기준선에서 1회 임계값 초과 (롤백 보류)
+    Glitch,
+
+    /// 지속적 드리프트 - 임계값 미만이지만 CUSUM 경보 (모델 재보정 필요)
+    Drift,
+
     /// 롤백 필요
     NeedsRollback,
 }
@@ -56,44 +140,37 @@ pub enum SyncResult {
 impl StateComparator {
     /// 새 StateComparator 생성
     pub fn new(rollback_threshold: f32) -> Self {
-        Self {
+        Self::from_config(SyncConfig {
             rollback_threshold,
-            warning_threshold: rollback_threshold * 0.7,
-            history: Vec::new(),
-            history_capacity: 1000,
+            ..Default::default()
+        })
+    }
+
+    /// 설정으로 생성
+    pub fn from_config(config: SyncConfig) -> Self {
+        Self {
+            history: VecDeque::with_capacity(config.history_capacity.min(1024)),
+            stats: HashMap::new(),
+            config,
         }
     }
 
     /// 기본 설정으로 생성 (10cm 임계값)
     pub fn with_default_config() -> Self {
-        Self::new(0.1)
+        Self::from_config(SyncConfig::default())
     }
 
     /// 상태 비교 (PPR: AI_process_StateComparison)
     ///
-    /// DeltaTickPacket의 delta 필드를 분석하여 동기화 상태 판단
+    /// DeltaTickPacket의 위치/방향 delta를 분석하여 동기화 상태 판단
     pub fn compare(&mut self, packet: &DeltaTickPacket) -> SyncResult {
-        let delta_mag = packet.delta_magnitude();
-
-        // 메트릭스 기록
-        let metrics = ComparisonMetrics {
+        self.evaluate(ComparisonMetrics {
             robot_id: packet.robot_id,
             tick: packet.tick,
-            position_delta: delta_mag,
-            theta_delta: packet.delta_theta.abs(),
+            position_delta: packet.delta_magnitude(),
+            theta_delta: wrap_angle(packet.delta_theta).abs(),
             timestamp_ns: packet.timestamp_ns,
-        };
-
-        self.record_metrics(metrics);
-
-        // 결과 결정
-        if delta_mag > self.rollback_threshold {
-            SyncResult::NeedsRollback
-        } else if delta_mag > self.warning_threshold {
-            SyncResult::Warning
-        } else {
-            SyncResult::InSync
-        }
+        })
     }
 
     /// 직접 델타 값으로 비교
@@ -104,31 +181,90 @@ impl StateComparator {
         position_delta: f32,
         theta_delta: f32,
     ) -> SyncResult {
-        let metrics = ComparisonMetrics {
+        self.evaluate(ComparisonMetrics {
             robot_id,
             tick,
             position_delta,
-            theta_delta,
+            theta_delta: wrap_angle(theta_delta).abs(),
             timestamp_ns: 0,
-        };
+        })
+    }
+
+    /// 정규화 오차 (위치/방향 중 큰 값, 1.0 = 롤백 임계값)
+    pub fn normalized_error(&self, position_delta: f32, theta_delta: f32) -> f32 {
+        let position = position_delta / self.config.rollback_threshold.max(f32::EPSILON);
+        let heading = theta_delta.abs() / self.config.heading_threshold.max(f32::EPSILON);
+        position.max(heading)
+    }
 
+    fn evaluate(&mut self, metrics: ComparisonMetrics) -> SyncResult {
+        let error = self.normalized_error(metrics.position_delta, metrics.theta_delta);
+        let robot_id = metrics.robot_id;
         self.record_metrics(metrics);
 
-        if position_delta > self.rollback_threshold {
-            SyncResult::NeedsRollback
-        } else if position_delta > self.warning_threshold {
-            SyncResult::Warning
+        let config = &self.config;
+        let stats = self.stats.entry(robot_id).or_default();
+        let baseline_ok = stats.samples >= config.min_baseline_samples
+            && stats.ewma < config.warning_ratio
+            && stats.variance.sqrt() <= config.glitch_max_std_dev
+            && stats.consecutive_exceeded == 0;
+
+        // EWMA 평균/분산
+        if stats.samples == 0 {
+            stats.ewma = error;
+            stats.variance = 0.0;
         } else {
-            SyncResult::InSync
+            let diff = error - stats.ewma;
+            stats.ewma += config.ewma_alpha * diff;
+            stats.variance =
+                (1.0 - config.ewma_alpha) * (stats.variance + config.ewma_alpha * diff * diff);
         }
+        // CUSUM (상방 단측)
+        stats.cusum = (stats.cusum + error - config.cusum_slack).max(0.0);
+        stats.samples += 1;
+        stats.last_error = error;
+
+        let result = if error > 1.0 {
+            stats.consecutive_exceeded += 1;
+            if baseline_ok && error < config.glitch_limit {
+                SyncResult::Glitch
+            } else {
+                SyncResult::NeedsRollback
+            }
+        } else {
+            stats.consecutive_exceeded = 0;
+            if stats.cusum > config.cusum_threshold {
+                SyncResult::Drift
+            } else if error > config.warning_ratio {
+                SyncResult::Warning
+            } else {
+                SyncResult::InSync
+            }
+        };
+
+        // 롤백 후 상태가 재조정되므로 통계 초기화
+        if result == SyncResult::NeedsRollback {
+            self.stats.remove(&robot_id);
+        }
+        result
     }
 
     /// 메트릭스 기록
     fn record_metrics(&mut self, metrics: ComparisonMetrics) {
-        if self.history.len() >= self.history_capacity {
-            self.history.remove(0);
+        if self.history.len() >= self.config.history_capacity {
+            self.history.pop_front();
         }
-        self.history.push(metrics);
+        self.history.push_back(metrics);
+    }
+
+    /// 로봇별 오차 통계 조회
+    pub fn drift_stats(&self, robot_id: u64) -> Option<&DriftStats> {
+        self.stats.get(&robot_id)
+    }
+
+    /// 로봇 통계 초기화 (외부 롤백/재보정 후 호출)
+    pub fn reset_robot(&mut self, robot_id: u64) {
+        self.stats.remove(&robot_id);
     }
 
     /// 특정 로봇의 최근 평균 델타
@@ -165,7 +301,7 @@ impl StateComparator {
 
         let rollback_count = recent
             .iter()
-            .filter(|m| m.position_delta > self.rollback_threshold)
+            .filter(|m| m.position_delta > self.config.rollback_threshold)
             .count();
 
         rollback_count as f32 / recent.len() as f32
@@ -173,15 +309,26 @@ impl StateComparator {
 
     /// 임계값 조회
     pub fn rollback_threshold(&self) -> f32 {
-        self.rollback_threshold
+        self.config.rollback_threshold
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &SyncConfig {
+        &self.config
     }
 
     /// 히스토리 클리어
     pub fn clear_history(&mut self) {
         self.history.clear();
+        self.stats.clear();
     }
 }
 
+/// 각도 차이를 [-π, π)로 정규화
+fn wrap_angle(theta: f32) -> f32 {
+    (theta + PI).rem_euclid(TAU) - PI
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +392,111 @@ mod tests {
         let freq = comparator.rollback_frequency(1, 4);
         assert!((freq - 0.5).abs() < 0.01);
     }
+
+    #[test]
+    fn test_heading_error_triggers_rollback() {
+        let mut comparator = StateComparator::new(0.1);
+        // 위치는 정상, 방향 0.5rad > 0.2rad
+        let result = comparator.compare_delta(1, 100, 0.01, 0.5);
+        assert_eq!(result, SyncResult::NeedsRollback);
+
+        // 2π 근처 방향 차이는 작은 오차
+        let result = comparator.compare_delta(2, 100, 0.01, TAU - 0.01);
+        assert_eq!(result, SyncResult::InSync);
+    }
+
+    #[test]
+    fn test_single_glitch_after_baseline() {
+        let mut comparator = StateComparator::new(0.1);
+        for tick in 0..20 {
+            assert_eq!(
+                comparator.compare_delta(1, tick, 0.02, 0.0),
+                SyncResult::InSync
+            );
+        }
+
+        assert_eq!(
+            comparator.compare_delta(1, 20, 0.15, 0.0),
+            SyncResult::Glitch
+        );
+        assert_eq!(
+            comparator.compare_delta(1, 21, 0.02, 0.0),
+            SyncResult::InSync
+        );
+
+        // 연속 초과는 글리치가 아님
+        assert_eq!(
+            comparator.compare_delta(1, 22, 0.15, 0.0),
+            SyncResult::Glitch
+        );
+        assert_eq!(
+            comparator.compare_delta(1, 23, 0.15, 0.0),
+            SyncResult::NeedsRollback
+        );
+        assert!(comparator.drift_stats(1).is_none());
+    }
+
+    #[test]
+    fn test_noisy_baseline_is_not_glitch() {
+        let mut comparator = StateComparator::new(0.1);
+        // 평균은 낮지만 0 ~ 임계값 사이를 오가는 불안정한 기준선
+        for tick in 0..40 {
+            let delta = if tick % 2 == 0 { 0.0 } else { 0.1 };
+            assert_ne!(
+                comparator.compare_delta(1, tick, delta, 0.0),
+                SyncResult::NeedsRollback
+            );
+        }
+        let stats = comparator.drift_stats(1).unwrap();
+        assert!(stats.ewma < comparator.config().warning_ratio);
+        assert!(stats.variance.sqrt() > comparator.config().glitch_max_std_dev);
+
+        assert_eq!(
+            comparator.compare_delta(1, 40, 0.15, 0.0),
+            SyncResult::NeedsRollback
+        );
+    }
+
+    #[test]
+    fn test_gross_error_always_rolls_back() {
+        let mut comparator = StateComparator::new(0.1);
+        for tick in 0..20 {
+            comparator.compare_delta(1, tick, 0.02, 0.0);
+        }
+        assert_eq!(
+            comparator.compare_delta(1, 20, 0.3, 0.0),
+            SyncResult::NeedsRollback
+        );
+    }
+
+    #[test]
+    fn test_sustained_drift_detected() {
+        let mut comparator = StateComparator::new(0.1);
+        for tick in 0..20 {
+            comparator.compare_delta(1, tick, 0.02, 0.0);
+        }
+
+        // 임계값 미만이지만 지속적으로 큰 방향 오차
+        let mut detected = None;
+        for tick in 20..80 {
+            let result = comparator.compare_delta(1, tick, 0.02, 0.13);
+            assert_ne!(result, SyncResult::NeedsRollback);
+            if result == SyncResult::Drift {
+                detected = Some(tick);
+                break;
+            }
+        }
+        assert!(detected.is_some());
+
+        let stats = comparator.drift_stats(1).unwrap();
+        assert!(stats.cusum > comparator.config().cusum_threshold);
+        assert!(stats.ewma > 0.3);
+        assert!(stats.variance > 0.0);
+
+        comparator.reset_robot(1);
+        assert_eq!(
+            comparator.compare_delta(1, 80, 0.02, 0.0),
+            SyncResult::InSync
+        );
+    }
 }
@@ -1,5 +1,7 @@
 //! sync 모듈 - 상태 동기화
 
 mod comparator;
+mod verifier;
 
-pub use comparator::{ComparisonMetrics, StateComparator, SyncResult};
+pub use comparator::{ComparisonMetrics, DriftStats, StateComparator, SyncConfig, SyncResult};
+pub use verifier::{DeltaVerification, DeltaVerifier, DeltaVerifierConfig};
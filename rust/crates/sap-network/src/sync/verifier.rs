@@ -0,0 +1,332 @@
+//! DeltaVerifier - Edge 측 추측 항법 예측 및 DeltaTick 오차 검증
+//!
+//! 로봇이 보고한 `delta_position`/`delta_theta`를 그대로 믿지 않고,
+//! 직전 패킷 상태를 같은 `MotionModel`로 예측하여 재계산한 값과 비교한다.
+//! 패킷의 `baseline_ns`가 Edge가 마지막으로 받은 패킷과 다르면(중간 유실)
+//! 재계산할 수 없으므로 `NoBaseline`으로 보고 기준만 다시 잡는다.
+//! 재기준 패킷은 검증되지 않은 값이므로 신뢰하지 않으며, 같은 로봇에서
+//! 연속으로 반복되면 `RepeatedRebase`로 격상한다 (기준 위조로 검증 회피 방지).
+//!
+//! PPR 매핑: AI_process_DeltaVerification
+
+use sap_core::packet::DeltaTickPacket;
+use sap_core::prediction::{wrap_angle, KinematicState, MotionModel};
+use sap_core::types::Position;
+use std::collections::HashMap;
+
+/// 검증 허용 오차 설정
+#[derive(Debug, Clone)]
+pub struct DeltaVerifierConfig {
+    /// 위치 오차 허용치 (m)
+    pub position_tolerance: f32,
+
+    /// 방향 오차 허용치 (rad)
+    pub heading_tolerance: f32,
+
+    /// 격상 전 허용되는 연속 재기준 횟수
+    pub max_consecutive_rebases: u32,
+}
+
+impl Default for DeltaVerifierConfig {
+    fn default() -> Self {
+        Self {
+            position_tolerance: 1e-4, // 0.1mm - 같은 구현이면 사실상 비트 일치
+            heading_tolerance: 1e-4,
+            max_consecutive_rebases: 3,
+        }
+    }
+}
+
+/// 검증 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeltaVerification {
+    /// 보고된 오차가 재계산 값과 일치
+    Verified,
+
+    /// 기준 패킷 없음 (첫 패킷 또는 기준 패킷 유실) - 기준으로만 저장
+    NoBaseline,
+
+    /// 연속 재기준 횟수 초과 - 기준으로만 저장하고 상위로 보고
+    RepeatedRebase {
+        /// 연속 재기준 횟수
+        consecutive: u32,
+    },
+
+    /// 알 수 없는 모델 해시
+    UnknownModel,
+
+    /// 직전 기준보다 오래된 패킷 - 무시
+    Stale,
+
+    /// 보고값과 재계산 값 불일치
+    Mismatch {
+        /// 재계산한 위치 오차
+        expected_position: Position,
+        /// 재계산한 방향 오차
+        expected_theta: f32,
+        /// 보고값과의 위치 차이 (m)
+        position_error: f32,
+        /// 보고값과의 방향 차이 (rad)
+        heading_error: f32,
+    },
+}
+
+impl DeltaVerification {
+    /// 보고값을 신뢰할 수 있는지 여부 (재계산으로 확인된 경우만)
+    #[inline]
+    pub fn is_trusted(&self) -> bool {
+        matches!(self, Self::Verified)
+    }
+
+    /// 상위 보고가 필요한 이상 여부
+    #[inline]
+    pub fn needs_escalation(&self) -> bool {
+        matches!(self, Self::RepeatedRebase { .. } | Self::Mismatch { .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Baseline {
+    state: KinematicState,
+    timestamp_ns: u64,
+    model: MotionModel,
+    /// 연속 재기준 횟수
+    rebases: u32,
+}
+
+/// DeltaTick 검증기
+///
+/// PPR: AI_process_DeltaVerification(packet) -> DeltaVerification
+#[derive(Debug, Clone, Default)]
+pub struct DeltaVerifier {
+    config: DeltaVerifierConfig,
+    baselines: HashMap<u64, Baseline>,
+}
+
+impl DeltaVerifier {
+    /// 새 DeltaVerifier 생성
+    pub fn new(config: DeltaVerifierConfig) -> Self {
+        Self {
+            config,
+            baselines: HashMap::new(),
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(DeltaVerifierConfig::default())
+    }
+
+    /// 패킷 검증 후 기준 상태 갱신
+    pub fn verify(&mut self, packet: &DeltaTickPacket) -> DeltaVerification {
+        let Some(model) = packet.motion_model() else {
+            return DeltaVerification::UnknownModel;
+        };
+        let actual = KinematicState::from_packet(packet);
+        let previous = self.baselines.get(&packet.robot_id).copied();
+
+        let result = match previous {
+            Some(baseline) if packet.timestamp_ns <= baseline.timestamp_ns => {
+                return DeltaVerification::Stale;
+            }
+            // 로봇이 계산에 쓴 기준 패킷을 받지 못함 (또는 기준 위조) - 재기준
+            Some(baseline) if packet.baseline_ns != Some(baseline.timestamp_ns) => {
+                let consecutive = baseline.rebases + 1;
+                if consecutive > self.config.max_consecutive_rebases {
+                    DeltaVerification::RepeatedRebase { consecutive }
+                } else {
+                    DeltaVerification::NoBaseline
+                }
+            }
+            None => DeltaVerification::NoBaseline,
+            Some(baseline) => {
+                let (expected_position, expected_theta) = model.prediction_delta(
+                    &baseline.state,
+                    &actual,
+                    packet.timestamp_ns - baseline.timestamp_ns,
+                );
+                let position_error = (packet.delta_position - expected_position).magnitude();
+                let heading_error = wrap_angle(packet.delta_theta - expected_theta).abs();
+                if position_error <= self.config.position_tolerance
+                    && heading_error <= self.config.heading_tolerance
+                {
+                    DeltaVerification::Verified
+                } else {
+                    DeltaVerification::Mismatch {
+                        expected_position,
+                        expected_theta,
+                        position_error,
+                        heading_error,
+                    }
+                }
+            }
+        };
+
+        self.baselines.insert(
+            packet.robot_id,
+            Baseline {
+                state: actual,
+                timestamp_ns: packet.timestamp_ns,
+                model,
+                rebases: match &result {
+                    DeltaVerification::NoBaseline => previous.map_or(1, |b| b.rebases + 1),
+                    DeltaVerification::RepeatedRebase { consecutive } => *consecutive,
+                    _ => 0,
+                },
+            },
+        );
+        result
+    }
+
+    /// 마지막 패킷 기준 Edge 측 예측 상태
+    pub fn predict(&self, robot_id: u64, timestamp_ns: u64) -> Option<KinematicState> {
+        let baseline = self.baselines.get(&robot_id)?;
+        let dt_ns = timestamp_ns.saturating_sub(baseline.timestamp_ns);
+        Some(baseline.model.predict_ns(&baseline.state, dt_ns))
+    }
+
+    /// 로봇 기준 상태 제거
+    pub fn remove_robot(&mut self, robot_id: u64) {
+        self.baselines.remove(&robot_id);
+    }
+
+    /// 추적 중인 로봇 수
+    pub fn robot_count(&self) -> usize {
+        self.baselines.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::{Acceleration, Velocity};
+
+    fn packet(tick: u64, x: f32, y: f32, theta: f32, model: MotionModel) -> DeltaTickPacket {
+        DeltaTickPacket::new(1, 42, tick)
+            .with_motion(
+                Position::new(x, y, 0.0),
+                Velocity::new(1.0, 0.0, 0.0),
+                Acceleration::ZERO,
+                theta,
+            )
+            .with_angular_rate(0.5)
+            .with_model(model)
+            .with_baseline((tick - 1) * 50_000_000)
+            .with_timestamp(tick * 50_000_000)
+    }
+
+    #[test]
+    fn test_verifies_recomputed_delta() {
+        let mut verifier = DeltaVerifier::with_default_config();
+        let model = MotionModel::ConstantTurnRateVelocity;
+        let first = packet(1, 0.0, 0.0, 0.0, model);
+        assert_eq!(verifier.verify(&first), DeltaVerification::NoBaseline);
+        assert!(!DeltaVerification::NoBaseline.is_trusted());
+
+        let second = packet(2, 0.06, 0.01, 0.02, model);
+        let (delta, delta_theta) = model.prediction_delta(
+            &KinematicState::from_packet(&first),
+            &KinematicState::from_packet(&second),
+            50_000_000,
+        );
+        let honest = second.clone().with_delta(delta, delta_theta);
+        assert_eq!(verifier.verify(&honest), DeltaVerification::Verified);
+        assert!(verifier.verify(&honest) == DeltaVerification::Stale);
+    }
+
+    #[test]
+    fn test_detects_misreported_delta() {
+        let mut verifier = DeltaVerifier::with_default_config();
+        let model = MotionModel::ConstantVelocity;
+        verifier.verify(&packet(1, 0.0, 0.0, 0.0, model));
+
+        // 실제 오차 (0.01, 0.01)인데 0으로 보고
+        let lying = packet(2, 0.06, 0.01, 0.025, model);
+        match verifier.verify(&lying) {
+            DeltaVerification::Mismatch {
+                expected_position,
+                position_error,
+                ..
+            } => {
+                assert!((expected_position.x - 0.01).abs() < 1e-5);
+                assert!((position_error - 0.01 * std::f32::consts::SQRT_2).abs() < 1e-5);
+            }
+            other => panic!("expected Mismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_lost_packet_rebases() {
+        let mut verifier = DeltaVerifier::with_default_config();
+        let model = MotionModel::ConstantVelocity;
+        verifier.verify(&packet(1, 0.0, 0.0, 0.0, model));
+
+        // 틱 2 유실 - 틱 3의 델타는 틱 2 기준이므로 재계산하지 않고 재기준
+        let third = packet(3, 0.12, 0.0, 0.0, model);
+        assert_eq!(verifier.verify(&third), DeltaVerification::NoBaseline);
+
+        // 틱 3 기준으로 정상 검증
+        let fourth = packet(4, 0.17, 0.0, 0.0, model);
+        let (delta, delta_theta) = model.prediction_delta(
+            &KinematicState::from_packet(&third),
+            &KinematicState::from_packet(&fourth),
+            50_000_000,
+        );
+        assert_eq!(
+            verifier.verify(&fourth.with_delta(delta, delta_theta)),
+            DeltaVerification::Verified
+        );
+    }
+
+    #[test]
+    fn test_falsified_baseline_not_trusted() {
+        let mut verifier = DeltaVerifier::with_default_config();
+        let model = MotionModel::ConstantVelocity;
+        verifier.verify(&packet(1, 0.0, 0.0, 0.0, model));
+
+        // 기준 타임스탬프를 위조해 재계산을 피하고 거짓 델타 보고
+        let forge = |tick: u64| {
+            packet(tick, 0.05 * tick as f32 + 0.3, 0.0, 0.0, model)
+                .with_baseline(1)
+                .with_delta(Position::ORIGIN, 0.0)
+        };
+        let result = verifier.verify(&forge(2));
+        assert_eq!(result, DeltaVerification::NoBaseline);
+        assert!(!result.is_trusted());
+        assert!(!result.needs_escalation());
+
+        // 첫 패킷 포함 연속 재기준이 한도(3)를 넘으면 격상
+        assert_eq!(verifier.verify(&forge(3)), DeltaVerification::NoBaseline);
+        let result = verifier.verify(&forge(4));
+        assert_eq!(result, DeltaVerification::RepeatedRebase { consecutive: 4 });
+        assert!(!result.is_trusted());
+        assert!(result.needs_escalation());
+
+        // 정상 기준으로 검증되면 카운터 초기화
+        let fifth = packet(5, 0.55, 0.0, 0.0, model);
+        let (delta, delta_theta) = model.prediction_delta(
+            &KinematicState::from_packet(&forge(4)),
+            &KinematicState::from_packet(&fifth),
+            50_000_000,
+        );
+        assert_eq!(
+            verifier.verify(&fifth.with_delta(delta, delta_theta)),
+            DeltaVerification::Verified
+        );
+        let sixth = packet(6, 0.6, 0.0, 0.0, model).with_baseline(1);
+        assert_eq!(verifier.verify(&sixth), DeltaVerification::NoBaseline);
+    }
+
+    #[test]
+    fn test_unknown_model_and_prediction() {
+        let mut verifier = DeltaVerifier::with_default_config();
+        let legacy = DeltaTickPacket::new(1, 42, 1);
+        assert_eq!(verifier.verify(&legacy), DeltaVerification::UnknownModel);
+        assert!(verifier.predict(42, 0).is_none());
+
+        verifier.verify(&packet(1, 0.0, 0.0, 0.0, MotionModel::ConstantVelocity));
+        let predicted = verifier.predict(42, 1_050_000_000).unwrap();
+        assert!((predicted.position.x - 1.0).abs() < 1e-5);
+        assert_eq!(verifier.robot_count(), 1);
+    }
+}
@@ -0,0 +1,709 @@
+//! LinkEmulator - 결정적 네트워크 링크 에뮬레이터
+//!
+//! 시드 기반 난수로 지연 분포, 지터, 유실(Gilbert–Elliott 버스트 포함), 중복,
+//! 재정렬, 대역폭 제한을 적용한다. 같은 시드와 같은 송신 순서면 항상 같은
+//! 도착 시각/순서를 재현하므로 PredictiveSync와 롤백을 재현 가능하게 시험할 수 있다.
+//!
+//! ```text
+//! send ─▶ [대역폭 큐] ─▶ [유실] ─▶ [지연+지터] ─▶ [재정렬/중복] ─▶ 도착 큐 ─▶ poll
+//! ```
+//!
+//! 신뢰 메시지(`SapMessage::is_reliable`)는 QUIC 스트림처럼 유실 대신
+//! 재전송 지연(`retransmit_delay_ms`)을 더하며, 중복·재정렬 없이 송신 순서대로
+//! 도착한다 (`SessionLink`의 단일 송신 큐와 동일).
+//!
+//! PPR 매핑: AI_make_LinkEmulator
+
+use super::{MessageLink, SapMessage, TransportError};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+
+/// 신뢰 메시지 최대 재전송 횟수 (초과 시 마지막 시도로 전달)
+const MAX_RETRANSMITS: u32 = 16;
+
+/// 단방향 지연 분포
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LatencyModel {
+    /// 고정 지연
+    Constant { latency_ms: f64 },
+
+    /// 균등 분포
+    Uniform { min_ms: f64, max_ms: f64 },
+
+    /// 정규 분포 (음수는 0으로 절단)
+    Normal { mean_ms: f64, std_dev_ms: f64 },
+
+    /// 파레토 분포 - Wi-Fi 재전송 같은 긴 꼬리 지연
+    Pareto { scale_ms: f64, shape: f64 },
+}
+
+impl Default for LatencyModel {
+    fn default() -> Self {
+        Self::Constant { latency_ms: 5.0 }
+    }
+}
+
+impl LatencyModel {
+    fn sample_ms(&self, rng: &mut SimRng) -> f64 {
+        let ms = match *self {
+            Self::Constant { latency_ms } => latency_ms,
+            Self::Uniform { min_ms, max_ms } => min_ms + (max_ms - min_ms) * rng.next_f64(),
+            Self::Normal {
+                mean_ms,
+                std_dev_ms,
+            } => mean_ms + std_dev_ms * rng.standard_normal(),
+            Self::Pareto { scale_ms, shape } => {
+                // 역변환: x = scale / U^(1/shape), U ∈ (0, 1]
+                scale_ms / (1.0 - rng.next_f64()).powf(1.0 / shape)
+            }
+        };
+        ms.max(0.0)
+    }
+}
+
+/// 유실 모델
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LossModel {
+    /// 유실 없음
+    #[default]
+    None,
+
+    /// 독립 유실
+    Bernoulli { loss_rate: f64 },
+
+    /// Gilbert–Elliott 2상태 버스트 유실 (패킷마다 상태 전이)
+    GilbertElliott {
+        /// Good → Bad 전이 확률
+        p_good_to_bad: f64,
+        /// Bad → Good 전이 확률
+        p_bad_to_good: f64,
+        /// Good 상태 유실률
+        loss_good: f64,
+        /// Bad 상태 유실률
+        loss_bad: f64,
+    },
+}
+
+/// 링크 에뮬레이터 설정 (단방향)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LinkEmulatorConfig {
+    /// 기본 지연 분포
+    pub latency: LatencyModel,
+
+    /// 지터 - 균등 ±jitter (밀리초)
+    pub jitter_ms: f64,
+
+    /// 유실 모델
+    pub loss: LossModel,
+
+    /// 중복 확률 (0.0 ~ 1.0)
+    pub duplicate_rate: f64,
+
+    /// 재정렬 확률 - 선택된 패킷은 추가 지연되어 후속 패킷에 추월됨
+    pub reorder_rate: f64,
+
+    /// 재정렬 패킷 추가 지연 (밀리초)
+    pub reorder_delay_ms: f64,
+
+    /// 대역폭 제한 (bit/s, 0이면 무제한)
+    pub bandwidth_bps: u64,
+
+    /// 송신 큐 최대 대기 (밀리초) - 초과 시 tail drop
+    pub max_queue_delay_ms: f64,
+
+    /// 신뢰 메시지 재전송 지연 (밀리초)
+    pub retransmit_delay_ms: f64,
+}
+
+impl Default for LinkEmulatorConfig {
+    fn default() -> Self {
+        Self {
+            latency: LatencyModel::default(),
+            jitter_ms: 0.0,
+            loss: LossModel::None,
+            duplicate_rate: 0.0,
+            reorder_rate: 0.0,
+            reorder_delay_ms: 20.0,
+            bandwidth_bps: 0,
+            max_queue_delay_ms: 200.0,
+            retransmit_delay_ms: 100.0, // QUIC 초기 PTO 근사
+        }
+    }
+}
+
+impl LinkEmulatorConfig {
+    /// 지연 0, 손상 없음 (기준 시험용)
+    pub fn ideal() -> Self {
+        Self {
+            latency: LatencyModel::Constant { latency_ms: 0.0 },
+            ..Default::default()
+        }
+    }
+
+    /// 혼잡한 창고 Wi-Fi 근사 - 긴 꼬리 지연, 버스트 유실, 약간의 재정렬
+    pub fn lossy_wifi() -> Self {
+        Self {
+            latency: LatencyModel::Pareto {
+                scale_ms: 4.0,
+                shape: 2.5,
+            },
+            jitter_ms: 2.0,
+            loss: LossModel::GilbertElliott {
+                p_good_to_bad: 0.02,
+                p_bad_to_good: 0.25,
+                loss_good: 0.005,
+                loss_bad: 0.5,
+            },
+            duplicate_rate: 0.005,
+            reorder_rate: 0.01,
+            reorder_delay_ms: 15.0,
+            bandwidth_bps: 20_000_000,
+            ..Default::default()
+        }
+    }
+}
+
+/// 링크 통계
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkStats {
+    /// 송신 메시지 수
+    pub sent: u64,
+    /// 송신 바이트 (프레임 기준)
+    pub bytes_sent: u64,
+    /// 수신측에 전달된 메시지 수 (중복 포함)
+    pub delivered: u64,
+    /// 유실 메시지 수
+    pub dropped: u64,
+    /// 큐 초과로 버린 메시지 수 (`dropped`에 포함)
+    pub queue_dropped: u64,
+    /// 중복 생성 수
+    pub duplicated: u64,
+    /// 재정렬 지연 적용 수
+    pub reordered: u64,
+    /// 신뢰 메시지 재전송 수
+    pub retransmitted: u64,
+}
+
+/// 전송 중 메시지 (도착 시각, 송신 순번 순으로 정렬)
+#[derive(Debug)]
+struct InFlight {
+    arrival_ns: u64,
+    seq: u64,
+    message: SapMessage,
+}
+
+impl PartialEq for InFlight {
+    fn eq(&self, other: &Self) -> bool {
+        (self.arrival_ns, self.seq) == (other.arrival_ns, other.seq)
+    }
+}
+
+impl Eq for InFlight {}
+
+impl PartialOrd for InFlight {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InFlight {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.arrival_ns, self.seq).cmp(&(other.arrival_ns, other.seq))
+    }
+}
+
+/// 단방향 링크 에뮬레이터
+///
+/// PPR: AI_make_LinkEmulator(config, seed) -> LinkEmulator
+#[derive(Debug)]
+pub struct LinkEmulator {
+    config: LinkEmulatorConfig,
+    rng: SimRng,
+    in_flight: BinaryHeap<Reverse<InFlight>>,
+    next_seq: u64,
+    /// 대역폭 큐가 비는 시각
+    link_free_ns: u64,
+    /// 순서 보존 패킷의 마지막 도착 시각
+    last_ordered_arrival_ns: u64,
+    /// 신뢰 메시지의 마지막 도착 시각
+    last_reliable_arrival_ns: u64,
+    /// Gilbert–Elliott Bad 상태 여부
+    burst: bool,
+    connected: bool,
+    stats: LinkStats,
+}
+
+impl LinkEmulator {
+    /// 새 LinkEmulator 생성
+    pub fn new(config: LinkEmulatorConfig, seed: u64) -> Self {
+        Self {
+            config,
+            rng: SimRng::new(seed),
+            in_flight: BinaryHeap::new(),
+            next_seq: 0,
+            link_free_ns: 0,
+            last_ordered_arrival_ns: 0,
+            last_reliable_arrival_ns: 0,
+            burst: false,
+            connected: true,
+            stats: LinkStats::default(),
+        }
+    }
+
+    /// 메시지 송신 (`now_ns` 시각)
+    pub fn send(&mut self, message: SapMessage, now_ns: u64) -> Result<(), TransportError> {
+        let bytes = message.encode()?.len() as u64;
+        self.stats.sent += 1;
+        self.stats.bytes_sent += bytes;
+
+        if !self.connected {
+            self.stats.dropped += 1;
+            return Ok(());
+        }
+
+        // 대역폭 큐: 직렬화 완료 시각이 출발 시각
+        let mut depart_ns = now_ns;
+        if let Some(tx_ns) = (bytes * 8 * 1_000_000_000).checked_div(self.config.bandwidth_bps) {
+            let start_ns = self.link_free_ns.max(now_ns);
+            if ms_to_ns(self.config.max_queue_delay_ms) < start_ns - now_ns {
+                self.stats.dropped += 1;
+                self.stats.queue_dropped += 1;
+                return Ok(());
+            }
+            self.link_free_ns = start_ns + tx_ns;
+            depart_ns = self.link_free_ns;
+        }
+
+        let reliable = message.is_reliable();
+        let mut retransmits = 0;
+        while self.roll_loss() {
+            if !reliable {
+                self.stats.dropped += 1;
+                return Ok(());
+            }
+            if retransmits == MAX_RETRANSMITS {
+                break;
+            }
+            retransmits += 1;
+        }
+        self.stats.retransmitted += retransmits as u64;
+        depart_ns += retransmits as u64 * ms_to_ns(self.config.retransmit_delay_ms);
+
+        let mut arrival_ns = depart_ns + self.sample_delay_ns();
+        if reliable {
+            // 앞선 신뢰 메시지가 재전송 중이면 뒤 메시지도 대기 (head-of-line)
+            arrival_ns = arrival_ns.max(self.last_reliable_arrival_ns);
+            self.last_reliable_arrival_ns = arrival_ns;
+        } else if self.rng.chance(self.config.reorder_rate) {
+            arrival_ns += ms_to_ns(self.config.reorder_delay_ms);
+            self.stats.reordered += 1;
+        } else {
+            // 지터만으로는 순서가 바뀌지 않음 (FIFO 큐)
+            arrival_ns = arrival_ns.max(self.last_ordered_arrival_ns);
+            self.last_ordered_arrival_ns = arrival_ns;
+        }
+
+        if !reliable && self.rng.chance(self.config.duplicate_rate) {
+            let duplicate_ns = depart_ns + self.sample_delay_ns();
+            self.push(message.clone(), duplicate_ns);
+            self.stats.duplicated += 1;
+        }
+        self.push(message, arrival_ns);
+        Ok(())
+    }
+
+    /// `now_ns`까지 도착한 메시지 (도착 순)
+    pub fn poll(&mut self, now_ns: u64) -> Vec<SapMessage> {
+        let mut arrived = Vec::new();
+        while self
+            .in_flight
+            .peek()
+            .is_some_and(|Reverse(m)| m.arrival_ns <= now_ns)
+        {
+            let Reverse(entry) = self.in_flight.pop().expect("peeked");
+            arrived.push(entry.message);
+        }
+        self.stats.delivered += arrived.len() as u64;
+        arrived
+    }
+
+    /// 링크 연결/단절 (단절 시 전송 중 메시지도 유실)
+    pub fn set_connected(&mut self, connected: bool) {
+        if !connected {
+            self.stats.dropped += self.in_flight.len() as u64;
+            self.in_flight.clear();
+        }
+        self.connected = connected;
+    }
+
+    /// 연결 여부
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// 다음 도착 예정 시각
+    pub fn next_arrival_ns(&self) -> Option<u64> {
+        self.in_flight.peek().map(|Reverse(m)| m.arrival_ns)
+    }
+
+    /// 전송 중 메시지 수
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// 통계 조회
+    pub fn stats(&self) -> &LinkStats {
+        &self.stats
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &LinkEmulatorConfig {
+        &self.config
+    }
+
+    fn push(&mut self, message: SapMessage, arrival_ns: u64) {
+        self.in_flight.push(Reverse(InFlight {
+            arrival_ns,
+            seq: self.next_seq,
+            message,
+        }));
+        self.next_seq += 1;
+    }
+
+    fn roll_loss(&mut self) -> bool {
+        match self.config.loss {
+            LossModel::None => false,
+            LossModel::Bernoulli { loss_rate } => self.rng.chance(loss_rate),
+            LossModel::GilbertElliott {
+                p_good_to_bad,
+                p_bad_to_good,
+                loss_good,
+                loss_bad,
+            } => {
+                let flip = if self.burst {
+                    p_bad_to_good
+                } else {
+                    p_good_to_bad
+                };
+                if self.rng.chance(flip) {
+                    self.burst = !self.burst;
+                }
+                self.rng
+                    .chance(if self.burst { loss_bad } else { loss_good })
+            }
+        }
+    }
+
+    fn sample_delay_ns(&mut self) -> u64 {
+        let mut ms = self.config.latency.sample_ms(&mut self.rng);
+        if self.config.jitter_ms > 0.0 {
+            ms += self.config.jitter_ms * (2.0 * self.rng.next_f64() - 1.0);
+        }
+        ms_to_ns(ms.max(0.0))
+    }
+}
+
+/// 에뮬레이션 링크의 한쪽 끝
+///
+/// 두 방향은 독립된 `LinkEmulator`이며 양 끝점이 공유한다.
+#[derive(Debug, Clone)]
+pub struct EmulatedLink {
+    outgoing: Arc<Mutex<LinkEmulator>>,
+    incoming: Arc<Mutex<LinkEmulator>>,
+}
+
+impl EmulatedLink {
+    /// 연결된 양 끝점 생성 - (A, B), `forward`는 A→B 방향 설정
+    pub fn pair(
+        forward: LinkEmulatorConfig,
+        reverse: LinkEmulatorConfig,
+        seed: u64,
+    ) -> (Self, Self) {
+        let a_to_b = Arc::new(Mutex::new(LinkEmulator::new(forward, seed)));
+        let b_to_a = Arc::new(Mutex::new(LinkEmulator::new(
+            reverse,
+            seed ^ 0x9E37_79B9_7F4A_7C15,
+        )));
+        (
+            Self {
+                outgoing: a_to_b.clone(),
+                incoming: b_to_a.clone(),
+            },
+            Self {
+                outgoing: b_to_a,
+                incoming: a_to_b,
+            },
+        )
+    }
+
+    /// 양방향 같은 설정으로 생성
+    pub fn symmetric(config: LinkEmulatorConfig, seed: u64) -> (Self, Self) {
+        Self::pair(config.clone(), config, seed)
+    }
+
+    /// 양방향 연결/단절
+    pub fn set_connected(&self, connected: bool) {
+        lock(&self.outgoing).set_connected(connected);
+        lock(&self.incoming).set_connected(connected);
+    }
+
+    /// 송신 방향 통계
+    pub fn outgoing_stats(&self) -> LinkStats {
+        lock(&self.outgoing).stats().clone()
+    }
+
+    /// 수신 방향 통계
+    pub fn incoming_stats(&self) -> LinkStats {
+        lock(&self.incoming).stats().clone()
+    }
+
+    /// 수신 방향 다음 도착 예정 시각
+    pub fn next_arrival_ns(&self) -> Option<u64> {
+        lock(&self.incoming).next_arrival_ns()
+    }
+}
+
+impl MessageLink for EmulatedLink {
+    fn send(&mut self, message: SapMessage, now_ns: u64) -> Result<(), TransportError> {
+        lock(&self.outgoing).send(message, now_ns)
+    }
+
+    fn poll(&mut self, now_ns: u64) -> Vec<SapMessage> {
+        lock(&self.incoming).poll(now_ns)
+    }
+}
+
+fn lock(emulator: &Mutex<LinkEmulator>) -> std::sync::MutexGuard<'_, LinkEmulator> {
+    emulator.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[inline]
+fn ms_to_ns(ms: f64) -> u64 {
+    (ms * 1_000_000.0) as u64
+}
+
+/// 시드 기반 난수 생성기 (SplitMix64) - 플랫폼 무관 재현
+#[derive(Debug, Clone)]
+struct SimRng(u64);
+
+impl SimRng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// [0, 1) 균등
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn chance(&mut self, p: f64) -> bool {
+        p > 0.0 && self.next_f64() < p
+    }
+
+    /// 표준 정규 (Box–Muller)
+    fn standard_normal(&mut self) -> f64 {
+        let u1 = 1.0 - self.next_f64(); // (0, 1]
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::packet::DeltaTickPacket;
+    use sap_core::ticket::TransitTicket;
+
+    const MS: u64 = 1_000_000;
+
+    fn delta(tick: u64) -> SapMessage {
+        SapMessage::DeltaTick(DeltaTickPacket::new(1, 42, tick))
+    }
+
+    fn ticks(messages: &[SapMessage]) -> Vec<u64> {
+        messages
+            .iter()
+            .filter_map(|m| match m {
+                SapMessage::DeltaTick(p) => Some(p.tick),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// 50ms 간격으로 `count`개 송신 후 전부 수신
+    fn run(config: LinkEmulatorConfig, seed: u64, count: u64) -> (Vec<u64>, LinkStats) {
+        let mut link = LinkEmulator::new(config, seed);
+        for tick in 0..count {
+            link.send(delta(tick), tick * 50 * MS).unwrap();
+        }
+        let received = ticks(&link.poll(u64::MAX));
+        (received, link.stats().clone())
+    }
+
+    #[test]
+    fn test_constant_latency_preserves_order() {
+        let mut link = LinkEmulator::new(LinkEmulatorConfig::default(), 1);
+        link.send(delta(1), 0).unwrap();
+        link.send(delta(2), MS).unwrap();
+
+        assert!(link.poll(4 * MS).is_empty());
+        assert_eq!(ticks(&link.poll(5 * MS)), vec![1]);
+        assert_eq!(ticks(&link.poll(6 * MS)), vec![2]);
+        assert_eq!(link.stats().delivered, 2);
+    }
+
+    #[test]
+    fn test_same_seed_is_deterministic() {
+        let config = LinkEmulatorConfig::lossy_wifi();
+        let a = run(config.clone(), 7, 500);
+        let b = run(config.clone(), 7, 500);
+        let c = run(config, 8, 500);
+        assert_eq!(a, b);
+        assert_ne!(a.0, c.0);
+    }
+
+    #[test]
+    fn test_jitter_without_reorder_keeps_fifo() {
+        let config = LinkEmulatorConfig {
+            latency: LatencyModel::Normal {
+                mean_ms: 30.0,
+                std_dev_ms: 20.0,
+            },
+            jitter_ms: 40.0,
+            ..Default::default()
+        };
+        let (received, _) = run(config, 3, 200);
+        assert_eq!(received, (0..200).collect::<Vec<_>>());
+
+        let reordering = LinkEmulatorConfig {
+            reorder_rate: 0.2,
+            reorder_delay_ms: 120.0,
+            ..Default::default()
+        };
+        let (received, stats) = run(reordering, 3, 200);
+        assert!(stats.reordered > 0);
+        assert_ne!(received, (0..200).collect::<Vec<_>>());
+        assert_eq!(received.len(), 200);
+    }
+
+    #[test]
+    fn test_gilbert_elliott_losses_are_bursty() {
+        let config = LinkEmulatorConfig {
+            loss: LossModel::GilbertElliott {
+                p_good_to_bad: 0.01,
+                p_bad_to_good: 0.1,
+                loss_good: 0.0,
+                loss_bad: 1.0,
+            },
+            ..Default::default()
+        };
+        let (received, stats) = run(config, 11, 5_000);
+        assert_eq!(stats.dropped as usize, 5_000 - received.len());
+
+        // 유실 구간 길이 평균 ≈ 1/p_bad_to_good = 10
+        let mut gaps = Vec::new();
+        for pair in received.windows(2) {
+            if pair[1] > pair[0] + 1 {
+                gaps.push(pair[1] - pair[0] - 1);
+            }
+        }
+        let mean_gap = gaps.iter().sum::<u64>() as f64 / gaps.len() as f64;
+        assert!(mean_gap > 5.0, "mean burst {mean_gap}");
+
+        let (_, bernoulli) = run(
+            LinkEmulatorConfig {
+                loss: LossModel::Bernoulli { loss_rate: 0.3 },
+                ..Default::default()
+            },
+            11,
+            5_000,
+        );
+        let rate = bernoulli.dropped as f64 / 5_000.0;
+        assert!((rate - 0.3).abs() < 0.03);
+    }
+
+    #[test]
+    fn test_duplicates_and_reliable_retransmit() {
+        let (received, stats) = run(
+            LinkEmulatorConfig {
+                duplicate_rate: 0.5,
+                ..Default::default()
+            },
+            5,
+            100,
+        );
+        assert_eq!(received.len() as u64, 100 + stats.duplicated);
+        assert!(stats.duplicated > 20);
+
+        // 신뢰 메시지는 유실 대신 재전송 지연, 재정렬 없이 송신 순서대로 도착
+        let mut link = LinkEmulator::new(
+            LinkEmulatorConfig {
+                loss: LossModel::Bernoulli { loss_rate: 0.5 },
+                reorder_rate: 0.5,
+                ..LinkEmulatorConfig::ideal()
+            },
+            9,
+        );
+        for id in 0..50 {
+            link.send(SapMessage::Ticket(TransitTicket::new(id, 42, 1)), 0)
+                .unwrap();
+        }
+        let received = link.poll(u64::MAX);
+        let ids: Vec<u128> = received
+            .iter()
+            .map(|message| match message {
+                SapMessage::Ticket(ticket) => ticket.ticket_id,
+                other => panic!("unexpected {other:?}"),
+            })
+            .collect();
+        assert_eq!(ids, (0..50).collect::<Vec<_>>());
+        assert_eq!(link.stats().dropped, 0);
+        assert!(link.stats().retransmitted > 0);
+    }
+
+    #[test]
+    fn test_bandwidth_cap_serializes_and_tail_drops() {
+        let frame_bits = delta(0).encode().unwrap().len() as u64 * 8;
+        let config = LinkEmulatorConfig {
+            latency: LatencyModel::Constant { latency_ms: 0.0 },
+            bandwidth_bps: frame_bits * 100, // 프레임당 10ms
+            max_queue_delay_ms: 25.0,
+            ..Default::default()
+        };
+        let mut link = LinkEmulator::new(config, 1);
+        for tick in 0..5 {
+            link.send(delta(tick), 0).unwrap();
+        }
+        // 0, 10, 20ms 대기까지 허용 → 3개만 큐에 들어감
+        assert_eq!(link.stats().queue_dropped, 2);
+        assert_eq!(ticks(&link.poll(10 * MS)), vec![0]);
+        assert_eq!(ticks(&link.poll(30 * MS)), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_disconnect_drops_in_flight() {
+        let (mut robot, mut edge) = EmulatedLink::symmetric(LinkEmulatorConfig::default(), 1);
+        robot.send(delta(1), 0).unwrap();
+        robot.set_connected(false);
+        robot.send(delta(2), MS).unwrap();
+        assert!(edge.poll(u64::MAX).is_empty());
+        assert_eq!(robot.outgoing_stats().dropped, 2);
+
+        edge.set_connected(true);
+        robot.send(delta(3), 10 * MS).unwrap();
+        assert_eq!(ticks(&edge.poll(20 * MS)), vec![3]);
+    }
+}
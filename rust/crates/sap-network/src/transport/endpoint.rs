@@ -0,0 +1,121 @@
+//! TransportEndpoint - QUIC 엔드포인트 (Edge 서버 / Robot 클라이언트)
+//!
+//! PPR 매핑: AI_make_TransportEndpoint
+
+use super::{Session, SessionConfig, TransportError};
+use std::net::SocketAddr;
+
+/// TLS 인증서/개인키 (DER)
+#[derive(Debug, Clone)]
+pub struct TlsIdentity {
+    certificate_der: Vec<u8>,
+    private_key_der: Vec<u8>,
+}
+
+impl TlsIdentity {
+    /// DER 인증서/PKCS#8 개인키로 생성
+    pub fn from_der(certificate_der: Vec<u8>, private_key_der: Vec<u8>) -> Self {
+        Self {
+            certificate_der,
+            private_key_der,
+        }
+    }
+
+    /// 자체 서명 인증서 생성 (개발/테스트용)
+    pub fn self_signed(server_names: &[&str]) -> Result<Self, TransportError> {
+        let names: Vec<String> = server_names.iter().map(|name| name.to_string()).collect();
+        let certificate = rcgen::generate_simple_self_signed(names)?;
+        Ok(Self::from_der(
+            certificate.serialize_der()?,
+            certificate.serialize_private_key_der(),
+        ))
+    }
+
+    /// 인증서 DER (클라이언트 신뢰 루트로 배포)
+    pub fn certificate_der(&self) -> &[u8] {
+        &self.certificate_der
+    }
+}
+
+/// QUIC 엔드포인트
+///
+/// PPR: AI_make_TransportEndpoint(addr, identity) -> TransportEndpoint
+#[derive(Debug)]
+pub struct TransportEndpoint {
+    endpoint: quinn::Endpoint,
+    config: SessionConfig,
+}
+
+impl TransportEndpoint {
+    /// Edge 서버 엔드포인트 (tokio 런타임 내에서 호출)
+    pub fn server(
+        addr: SocketAddr,
+        identity: &TlsIdentity,
+        config: SessionConfig,
+    ) -> Result<Self, TransportError> {
+        let mut server_config = quinn::ServerConfig::with_single_cert(
+            vec![rustls::Certificate(identity.certificate_der.clone())],
+            rustls::PrivateKey(identity.private_key_der.clone()),
+        )?;
+        server_config.transport_config(config.transport_config()?);
+
+        let endpoint = quinn::Endpoint::server(server_config, addr)?;
+        Ok(Self { endpoint, config })
+    }
+
+    /// Robot 클라이언트 엔드포인트 - `trusted_roots` 인증서만 신뢰
+    pub fn client(
+        addr: SocketAddr,
+        trusted_roots: &[&[u8]],
+        config: SessionConfig,
+    ) -> Result<Self, TransportError> {
+        let mut roots = rustls::RootCertStore::empty();
+        for der in trusted_roots {
+            roots
+                .add(&rustls::Certificate(der.to_vec()))
+                .map_err(|e| TransportError::Config(format!("invalid root certificate: {e}")))?;
+        }
+        let mut client_config = quinn::ClientConfig::with_root_certificates(roots);
+        client_config.transport_config(config.transport_config()?);
+
+        let mut endpoint = quinn::Endpoint::client(addr)?;
+        endpoint.set_default_client_config(client_config);
+        Ok(Self { endpoint, config })
+    }
+
+    /// 바인딩된 로컬 주소
+    pub fn local_addr(&self) -> Result<SocketAddr, TransportError> {
+        Ok(self.endpoint.local_addr()?)
+    }
+
+    /// 다음 수신 세션 (엔드포인트가 닫히면 None)
+    pub async fn accept(&self) -> Option<Result<Session, TransportError>> {
+        let connecting = self.endpoint.accept().await?;
+        Some(
+            connecting
+                .await
+                .map(|connection| Session::new(connection, self.config.clone()))
+                .map_err(TransportError::from),
+        )
+    }
+
+    /// 서버에 연결
+    pub async fn connect(
+        &self,
+        addr: SocketAddr,
+        server_name: &str,
+    ) -> Result<Session, TransportError> {
+        let connection = self.endpoint.connect(addr, server_name)?.await?;
+        Ok(Session::new(connection, self.config.clone()))
+    }
+
+    /// 모든 세션 종료
+    pub fn close(&self) {
+        self.endpoint.close(0u32.into(), b"shutdown");
+    }
+
+    /// 모든 연결이 정리될 때까지 대기
+    pub async fn wait_idle(&self) {
+        self.endpoint.wait_idle().await;
+    }
+}
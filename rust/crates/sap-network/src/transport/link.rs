@@ -0,0 +1,108 @@
+//! MessageLink - 메시지 링크 추상화
+//!
+//! 시나리오 코드가 `MessageLink`만 사용하면 같은 코드를 에뮬레이터(`EmulatedLink`)와
+//! 실제 QUIC 세션(`SessionLink`) 위에서 그대로 실행할 수 있다.
+//! `now_ns`는 에뮬레이터의 시뮬레이션 시각이며 실제 전송은 무시한다.
+//!
+//! PPR 매핑: AI_make_MessageLink
+
+use super::{SapMessage, Session, TransportError};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+/// 양방향 메시지 링크의 한쪽 끝
+pub trait MessageLink {
+    /// 메시지 전송 (종류에 맞는 경로 사용)
+    fn send(&mut self, message: SapMessage, now_ns: u64) -> Result<(), TransportError>;
+
+    /// `now_ns`까지 도착한 메시지 수신 (대기 없음)
+    fn poll(&mut self, now_ns: u64) -> Vec<SapMessage>;
+}
+
+/// 실제 QUIC 세션 어댑터
+///
+/// 수신 태스크가 메시지를 큐에 모으고 `poll`이 꺼내간다.
+/// 신뢰 메시지는 송신 큐를 거쳐 하나의 송신 태스크가 순서대로 보내므로
+/// 상대 측에 `send` 호출 순서대로 도착한다.
+/// tokio 런타임 내에서 생성해야 하며, drop 시 수신 태스크를 중단하고
+/// 송신 태스크는 큐에 남은 메시지를 보낸 뒤 종료한다.
+#[derive(Debug)]
+pub struct SessionLink {
+    session: Session,
+    inbox: mpsc::UnboundedReceiver<SapMessage>,
+    outbox: mpsc::UnboundedSender<SapMessage>,
+    receiver: JoinHandle<()>,
+}
+
+impl SessionLink {
+    /// 세션을 링크로 감싼다
+    pub fn new(session: Session) -> Self {
+        let (tx, inbox) = mpsc::unbounded_channel();
+        let recv_session = session.clone();
+        let receiver = tokio::spawn(async move {
+            loop {
+                match recv_session.recv().await {
+                    Ok(message) => {
+                        if tx.send(message).is_err() {
+                            break;
+                        }
+                    }
+                    Err(TransportError::Connection(_)) => break,
+                    Err(e) => tracing::warn!("session link dropped frame: {e}"),
+                }
+            }
+        });
+        let (outbox, mut queue) = mpsc::unbounded_channel::<SapMessage>();
+        let send_session = session.clone();
+        tokio::spawn(async move {
+            while let Some(message) = queue.recv().await {
+                match send_session.send_reliable(&message).await {
+                    Ok(()) => {}
+                    Err(TransportError::Connection(_)) => break,
+                    Err(e) => tracing::warn!("session link reliable send failed: {e}"),
+                }
+            }
+        });
+        Self {
+            session,
+            inbox,
+            outbox,
+            receiver,
+        }
+    }
+
+    /// 내부 세션
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+}
+
+impl MessageLink for SessionLink {
+    fn send(&mut self, message: SapMessage, _now_ns: u64) -> Result<(), TransportError> {
+        if !message.is_reliable() {
+            return self.session.send_datagram(&message);
+        }
+        // 신뢰 스트림은 송신 태스크에서 비동기 완료 - 태스크 종료는 연결 종료를 의미
+        self.outbox.send(message).map_err(|_| {
+            TransportError::Connection(
+                self.session
+                    .close_reason()
+                    .unwrap_or(quinn::ConnectionError::LocallyClosed),
+            )
+        })
+    }
+
+    fn poll(&mut self, _now_ns: u64) -> Vec<SapMessage> {
+        let mut messages = Vec::new();
+        while let Ok(message) = self.inbox.try_recv() {
+            messages.push(message);
+        }
+        messages
+    }
+}
+
+impl Drop for SessionLink {
+    fn drop(&mut self) {
+        self.receiver.abort();
+    }
+}
@@ -0,0 +1,267 @@
+//! SAP 메시지 프레이밍
+//!
+//! 프레임 = [패킷 타입 1B][페이로드 길이 4B LE][bincode 페이로드].
+//! 데이터그램과 스트림이 같은 프레임 형식을 사용한다.
+//! `Sequenced` 페이로드는 [순번 8B LE][내부 프레임]이다.
+//! `ForwardSeq` 페이로드는 bincode 순번(u64)이다.
+//!
+//! PPR 매핑: AI_make_TransportFrame
+
+use super::TransportError;
+use sap_core::packet::{
+    DeltaTickPacket, EmergencyStopPacket, PacketType, RollbackFrame, SelectiveAck,
+};
+use sap_core::ticket::TransitTicket;
+
+/// 프레임 헤더 크기 (바이트)
+pub const FRAME_HEADER_LEN: usize = 5;
+
+/// 전송 계층 메시지
+#[derive(Debug, Clone)]
+pub enum SapMessage {
+    /// 상태 동기화 - 비신뢰 데이터그램 (최신 값만 의미 있음)
+    DeltaTick(DeltaTickPacket),
+
+    /// 티켓 발행 - 신뢰 스트림
+    Ticket(TransitTicket),
+
+    /// 롤백 프레임 - 신뢰 스트림
+    Rollback(RollbackFrame),
+
+    /// 비상 정지 - 신뢰 스트림
+    EmergencyStop(EmergencyStopPacket),
+
+    /// 단독 수신 확인 - 데이터그램 (piggyback할 DeltaTick이 없을 때)
+    Ack(SelectiveAck),
+
+    /// 순번 부여된 중요 메시지 - 데이터그램 + 신뢰 계층 재전송
+    Sequenced { seq: u64, message: Box<SapMessage> },
+
+    /// 송신측이 포기한 순번 건너뛰기 - 수신측 누적 순번을 이 값까지 전진 (데이터그램)
+    ForwardSeq(u64),
+}
+
+impl SapMessage {
+    /// 패킷 타입
+    pub fn packet_type(&self) -> PacketType {
+        match self {
+            Self::DeltaTick(_) => PacketType::DeltaTick,
+            Self::Ticket(_) => PacketType::TicketIssue,
+            Self::Rollback(_) => PacketType::RollbackFrame,
+            Self::EmergencyStop(_) => PacketType::EmergencyStop,
+            Self::Ack(_) => PacketType::Ack,
+            Self::Sequenced { .. } => PacketType::Sequenced,
+            Self::ForwardSeq(_) => PacketType::ForwardSeq,
+        }
+    }
+
+    /// 신뢰 전송 필요 여부 (false면 데이터그램)
+    ///
+    /// `Sequenced`는 신뢰 계층이 재전송하므로 데이터그램으로 보낸다.
+    #[inline]
+    pub fn is_reliable(&self) -> bool {
+        self.is_critical()
+    }
+
+    /// 반드시 도달해야 하는 메시지 여부 (티켓/롤백/비상 정지)
+    #[inline]
+    pub fn is_critical(&self) -> bool {
+        matches!(
+            self,
+            Self::Ticket(_) | Self::Rollback(_) | Self::EmergencyStop(_)
+        )
+    }
+
+    /// 프레임 인코딩
+    pub fn encode(&self) -> Result<Vec<u8>, TransportError> {
+        let payload = match self {
+            Self::DeltaTick(packet) => bincode::serialize(packet),
+            Self::Ticket(ticket) => bincode::serialize(ticket),
+            Self::Rollback(frame) => bincode::serialize(frame),
+            Self::EmergencyStop(packet) => bincode::serialize(packet),
+            Self::Ack(ack) => bincode::serialize(ack),
+            Self::ForwardSeq(seq) => bincode::serialize(seq),
+            Self::Sequenced { message, .. } if matches!(**message, Self::Sequenced { .. }) => {
+                return Err(TransportError::NestedSequenced);
+            }
+            Self::Sequenced { seq, message } => {
+                let mut payload = seq.to_le_bytes().to_vec();
+                payload.extend_from_slice(&message.encode()?);
+                Ok(payload)
+            }
+        }?;
+
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+        frame.push(self.packet_type() as u8);
+        frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&payload);
+        Ok(frame)
+    }
+
+    /// 프레임 디코딩 (프레임 전체가 정확히 하나의 메시지여야 함)
+    pub fn decode(frame: &[u8]) -> Result<Self, TransportError> {
+        if frame.len() < FRAME_HEADER_LEN {
+            return Err(TransportError::Truncated {
+                expected: FRAME_HEADER_LEN,
+                actual: frame.len(),
+            });
+        }
+        let packet_type = frame[0];
+        let len = u32::from_le_bytes([frame[1], frame[2], frame[3], frame[4]]) as usize;
+        let payload = &frame[FRAME_HEADER_LEN..];
+        if payload.len() != len {
+            return Err(TransportError::Truncated {
+                expected: FRAME_HEADER_LEN + len,
+                actual: frame.len(),
+            });
+        }
+
+        match packet_type {
+            t if t == PacketType::DeltaTick as u8 => {
+                Ok(Self::DeltaTick(bincode::deserialize(payload)?))
+            }
+            t if t == PacketType::TicketIssue as u8 => {
+                Ok(Self::Ticket(bincode::deserialize(payload)?))
+            }
+            t if t == PacketType::RollbackFrame as u8 => {
+                Ok(Self::Rollback(bincode::deserialize(payload)?))
+            }
+            t if t == PacketType::EmergencyStop as u8 => {
+                Ok(Self::EmergencyStop(bincode::deserialize(payload)?))
+            }
+            t if t == PacketType::Ack as u8 => Ok(Self::Ack(bincode::deserialize(payload)?)),
+            t if t == PacketType::ForwardSeq as u8 => {
+                Ok(Self::ForwardSeq(bincode::deserialize(payload)?))
+            }
+            t if t == PacketType::Sequenced as u8 => {
+                let Some((seq, inner)) = payload.split_first_chunk::<8>() else {
+                    return Err(TransportError::Truncated {
+                        expected: FRAME_HEADER_LEN + 8,
+                        actual: frame.len(),
+                    });
+                };
+                // 중첩 금지 - 재귀 깊이를 1로 제한
+                if inner.first() == Some(&(PacketType::Sequenced as u8)) {
+                    return Err(TransportError::NestedSequenced);
+                }
+                Ok(Self::Sequenced {
+                    seq: u64::from_le_bytes(*seq),
+                    message: Box::new(Self::decode(inner)?),
+                })
+            }
+            other => Err(TransportError::UnknownMessageType(other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::{Position, Velocity};
+
+    #[test]
+    fn test_delta_tick_roundtrip() {
+        let packet = DeltaTickPacket::new(1, 42, 100).with_motion(
+            Position::new(1.0, 2.0, 0.0),
+            Velocity::new(0.5, 0.0, 0.0),
+            Default::default(),
+            0.3,
+        );
+        let message = SapMessage::DeltaTick(packet);
+        assert!(!message.is_reliable());
+
+        let frame = message.encode().unwrap();
+        assert_eq!(frame[0], PacketType::DeltaTick as u8);
+
+        match SapMessage::decode(&frame).unwrap() {
+            SapMessage::DeltaTick(decoded) => {
+                assert_eq!(decoded.robot_id, 42);
+                assert_eq!(decoded.tick, 100);
+                assert_eq!(decoded.position.x, 1.0);
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let frame = SapMessage::DeltaTick(DeltaTickPacket::new(1, 1, 1))
+            .encode()
+            .unwrap();
+
+        assert!(matches!(
+            SapMessage::decode(&frame[..3]),
+            Err(TransportError::Truncated { .. })
+        ));
+        assert!(matches!(
+            SapMessage::decode(&frame[..frame.len() - 1]),
+            Err(TransportError::Truncated { .. })
+        ));
+
+        let mut unknown = frame.clone();
+        unknown[0] = 0xEE;
+        assert!(matches!(
+            SapMessage::decode(&unknown),
+            Err(TransportError::UnknownMessageType(0xEE))
+        ));
+    }
+
+    #[test]
+    fn test_sequenced_roundtrip() {
+        let stop = SapMessage::EmergencyStop(EmergencyStopPacket::new(1, 42, 7, 100));
+        let message = SapMessage::Sequenced {
+            seq: 9,
+            message: Box::new(stop),
+        };
+        assert!(!message.is_reliable());
+
+        match SapMessage::decode(&message.encode().unwrap()).unwrap() {
+            SapMessage::Sequenced { seq, message } => {
+                assert_eq!(seq, 9);
+                assert!(matches!(*message, SapMessage::EmergencyStop(p) if p.reason_code == 7));
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        let ack = SapMessage::Ack(SelectiveAck::new(3, 0b10));
+        assert!(matches!(
+            SapMessage::decode(&ack.encode().unwrap()).unwrap(),
+            SapMessage::Ack(a) if a.contains(5)
+        ));
+
+        let forward = SapMessage::ForwardSeq(12);
+        assert!(!forward.is_reliable());
+        assert!(matches!(
+            SapMessage::decode(&forward.encode().unwrap()).unwrap(),
+            SapMessage::ForwardSeq(12)
+        ));
+    }
+
+    #[test]
+    fn test_nested_sequenced_rejected() {
+        let inner = SapMessage::Sequenced {
+            seq: 1,
+            message: Box::new(SapMessage::Ack(SelectiveAck::default())),
+        };
+        let nested = SapMessage::Sequenced {
+            seq: 2,
+            message: Box::new(inner.clone()),
+        };
+        assert!(matches!(
+            nested.encode(),
+            Err(TransportError::NestedSequenced)
+        ));
+
+        // 직접 조립한 중첩 프레임도 재귀 없이 거부
+        let inner_frame = inner.encode().unwrap();
+        let mut payload = 2u64.to_le_bytes().to_vec();
+        payload.extend_from_slice(&inner_frame);
+        let mut frame = vec![PacketType::Sequenced as u8];
+        frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&payload);
+        assert!(matches!(
+            SapMessage::decode(&frame),
+            Err(TransportError::NestedSequenced)
+        ));
+    }
+}
@@ -0,0 +1,77 @@
+//! transport 모듈 - Edge↔Robot QUIC 전송 계층
+//!
+//! DeltaTick은 비신뢰 데이터그램, 티켓/롤백은 신뢰 스트림으로 전송한다.
+//! 시험용 `LinkEmulator`는 같은 `MessageLink` 인터페이스로 실제 세션을 대체한다.
+//! `ReliableChannel`은 중요 메시지를 데이터그램 + SACK 재전송으로 보낸다.
+
+mod emulator;
+mod endpoint;
+mod link;
+mod message;
+mod reliable;
+mod session;
+
+pub use emulator::{
+    EmulatedLink, LatencyModel, LinkEmulator, LinkEmulatorConfig, LinkStats, LossModel,
+};
+pub use endpoint::{TlsIdentity, TransportEndpoint};
+pub use link::{MessageLink, SessionLink};
+pub use message::{SapMessage, FRAME_HEADER_LEN};
+pub use reliable::{
+    DeliveryStatus, ReliableChannel, ReliableConfig, ReliableHub, ReliableStats, RtoEstimator,
+};
+pub use session::{Session, SessionConfig};
+
+use thiserror::Error;
+
+/// 전송 계층 에러
+#[derive(Debug, Error)]
+pub enum TransportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("TLS error: {0}")]
+    Tls(#[from] rustls::Error),
+
+    #[error("certificate generation failed: {0}")]
+    Certificate(#[from] rcgen::RcgenError),
+
+    #[error("invalid transport config: {0}")]
+    Config(String),
+
+    #[error("connect failed: {0}")]
+    Connect(#[from] quinn::ConnectError),
+
+    #[error("connection lost: {0}")]
+    Connection(#[from] quinn::ConnectionError),
+
+    #[error("stream write failed: {0}")]
+    Write(#[from] quinn::WriteError),
+
+    #[error("stream read failed: {0}")]
+    Read(#[from] quinn::ReadToEndError),
+
+    #[error("datagram send failed: {0}")]
+    Datagram(#[from] quinn::SendDatagramError),
+
+    #[error("codec error: {0}")]
+    Codec(#[from] bincode::Error),
+
+    #[error("frame truncated: expected {expected} bytes, got {actual}")]
+    Truncated { expected: usize, actual: usize },
+
+    #[error("frame too large: {len} bytes (max {max})")]
+    FrameTooLarge { len: usize, max: usize },
+
+    #[error("unknown message type: {0:#04x}")]
+    UnknownMessageType(u8),
+
+    #[error("message requires reliable delivery")]
+    ReliableRequired,
+
+    #[error("nested sequenced frame")]
+    NestedSequenced,
+
+    #[error("reliable send window full ({limit} unacked)")]
+    SendWindowFull { limit: usize },
+}
@@ -0,0 +1,909 @@
+//! ReliableChannel - 데이터그램 위 경량 신뢰 전송 계층
+//!
+//! 중요 메시지(티켓/롤백/비상 정지)에 순번을 붙여 데이터그램으로 보내고,
+//! 수신측의 선택적 확인 응답(SACK)을 받을 때까지 RTO마다 재전송한다.
+//! SACK은 DeltaTick에 실어 보내며, 보낼 DeltaTick이 없으면 단독 Ack로 보낸다.
+//! 수신측은 순번으로 중복을 걸러 각 메시지를 정확히 한 번만 전달한다.
+//! 재전송을 포기한 순번은 `ForwardSeq`로 알려 수신측 누적 순번이 그 뒤로 전진하게 하며,
+//! 양쪽 모두 누적 순번 + `SelectiveAck::WINDOW`를 넘는 순번은 보내거나 받지 않는다.
+//!
+//! ```text
+//! Edge                                   Robot
+//!  │── Sequenced{seq=7, Rollback} ──X      │  (유실)
+//!  │      ... RTO 경과 ...                 │
+//!  │── Sequenced{seq=7, Rollback} ───────▶│  전달
+//!  │◀──────── DeltaTick{ack: ≤7} ─────────│
+//!  │  is_rollback_confirmed() == true     │
+//! ```
+//!
+//! PPR 매핑: AI_make_ReliableChannel
+
+use super::{SapMessage, TransportError};
+use sap_core::packet::{RollbackFrame, SelectiveAck};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// 신뢰 계층 설정
+#[derive(Debug, Clone)]
+pub struct ReliableConfig {
+    /// RTT 측정 전 초기 RTO (밀리초)
+    pub initial_rto_ms: u64,
+
+    /// 최소 RTO (밀리초) - ACK가 다음 DeltaTick을 기다리므로 1틱보다 커야 함
+    pub min_rto_ms: u64,
+
+    /// 최대 RTO (밀리초, 지수 백오프 상한)
+    pub max_rto_ms: u64,
+
+    /// 메시지당 최대 재전송 횟수 (초과 시 실패 처리)
+    pub max_retransmits: u32,
+
+    /// 미확인 메시지 최대 개수
+    pub max_unacked: usize,
+}
+
+impl Default for ReliableConfig {
+    fn default() -> Self {
+        Self {
+            initial_rto_ms: 200,
+            min_rto_ms: 60, // 1틱(50ms) + 여유
+            max_rto_ms: 2_000,
+            max_retransmits: 8,
+            max_unacked: SelectiveAck::WINDOW as usize,
+        }
+    }
+}
+
+/// 전달 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// 전송됨, 확인 대기
+    Pending,
+    /// 수신측 확인 완료
+    Confirmed,
+    /// 최대 재전송 초과
+    Failed,
+    /// 보낸 적 없거나 보관 범위(최근 `SelectiveAck::WINDOW`개)를 벗어난 순번
+    Unknown,
+}
+
+/// 신뢰 계층 통계
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReliableStats {
+    /// 최초 전송 수
+    pub sent: u64,
+    /// 재전송 수
+    pub retransmitted: u64,
+    /// 확인 완료 수
+    pub confirmed: u64,
+    /// 실패 수
+    pub failed: u64,
+    /// 수신측에서 버린 중복 수
+    pub duplicates: u64,
+    /// 수신측에서 버린 수신 윈도 밖 순번 수
+    pub out_of_window: u64,
+    /// 순번 건너뛰기 전송 수
+    pub forwarded: u64,
+}
+
+/// RTO 추정기 (RFC 6298)
+#[derive(Debug, Clone)]
+pub struct RtoEstimator {
+    srtt_ns: Option<u64>,
+    rttvar_ns: u64,
+    rto_ns: u64,
+    min_rto_ns: u64,
+    max_rto_ns: u64,
+}
+
+impl RtoEstimator {
+    /// 새 RtoEstimator 생성
+    pub fn new(initial_rto_ms: u64, min_rto_ms: u64, max_rto_ms: u64) -> Self {
+        Self {
+            srtt_ns: None,
+            rttvar_ns: 0,
+            rto_ns: initial_rto_ms * 1_000_000,
+            min_rto_ns: min_rto_ms * 1_000_000,
+            max_rto_ns: max_rto_ms * 1_000_000,
+        }
+    }
+
+    /// RTT 표본 반영 (재전송된 메시지의 표본은 넣지 않음 - Karn)
+    pub fn sample(&mut self, rtt_ns: u64) {
+        let srtt = match self.srtt_ns {
+            None => {
+                self.rttvar_ns = rtt_ns / 2;
+                rtt_ns
+            }
+            Some(srtt) => {
+                // RTTVAR ← 3/4·RTTVAR + 1/4·|SRTT − R|, SRTT ← 7/8·SRTT + 1/8·R
+                self.rttvar_ns = (3 * self.rttvar_ns + srtt.abs_diff(rtt_ns)) / 4;
+                (7 * srtt + rtt_ns) / 8
+            }
+        };
+        self.srtt_ns = Some(srtt);
+        self.rto_ns = (srtt + 4 * self.rttvar_ns).clamp(self.min_rto_ns, self.max_rto_ns);
+    }
+
+    /// 현재 RTO (나노초)
+    pub fn rto_ns(&self) -> u64 {
+        self.rto_ns
+    }
+
+    /// `attempt`번째 재전송 대기 시간 - 지수 백오프 (나노초)
+    pub fn backoff_ns(&self, attempt: u32) -> u64 {
+        self.rto_ns
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.max_rto_ns)
+    }
+
+    /// 평활 RTT (측정 전이면 None)
+    pub fn srtt_ns(&self) -> Option<u64> {
+        self.srtt_ns
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pending {
+    message: SapMessage,
+    first_sent_ns: u64,
+    deadline_ns: u64,
+    retransmits: u32,
+}
+
+/// 상대 1개와의 신뢰 채널 (송신/수신 양쪽)
+///
+/// PPR: AI_make_ReliableChannel(config) -> ReliableChannel
+#[derive(Debug, Clone)]
+pub struct ReliableChannel {
+    config: ReliableConfig,
+    rto: RtoEstimator,
+    next_seq: u64,
+    unacked: BTreeMap<u64, Pending>,
+    failed: BTreeSet<u64>,
+    /// 상대가 확인한 누적 순번 (송신 윈도 기준)
+    peer_cumulative: u64,
+    /// 다음 `ForwardSeq` 전송 시각 (나노초)
+    forward_deadline_ns: u64,
+    /// 빠짐없이 수신한 마지막 순번
+    recv_cumulative: u64,
+    /// `recv_cumulative` 이후 먼저 도착한 순번
+    recv_ahead: BTreeSet<u64>,
+    ack_pending: bool,
+    stats: ReliableStats,
+}
+
+impl ReliableChannel {
+    /// 새 ReliableChannel 생성
+    pub fn new(config: ReliableConfig) -> Self {
+        let rto = RtoEstimator::new(config.initial_rto_ms, config.min_rto_ms, config.max_rto_ms);
+        Self {
+            config,
+            rto,
+            next_seq: 1,
+            unacked: BTreeMap::new(),
+            failed: BTreeSet::new(),
+            peer_cumulative: 0,
+            forward_deadline_ns: 0,
+            recv_cumulative: 0,
+            recv_ahead: BTreeSet::new(),
+            ack_pending: false,
+            stats: ReliableStats::default(),
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(ReliableConfig::default())
+    }
+
+    /// 중요 메시지에 순번 부여 → (순번, 전송할 메시지)
+    ///
+    /// 상대의 누적 순번 + `SelectiveAck::WINDOW`를 넘는 순번은 수신측이 버리므로 거부한다.
+    pub fn send_critical(
+        &mut self,
+        message: SapMessage,
+        now_ns: u64,
+    ) -> Result<(u64, SapMessage), TransportError> {
+        if self.unacked.len() >= self.config.max_unacked {
+            return Err(TransportError::SendWindowFull {
+                limit: self.config.max_unacked,
+            });
+        }
+        let seq = self.next_seq;
+        if seq > self.peer_cumulative.saturating_add(SelectiveAck::WINDOW) {
+            return Err(TransportError::SendWindowFull {
+                limit: SelectiveAck::WINDOW as usize,
+            });
+        }
+        self.next_seq += 1;
+        self.prune_failed();
+        self.unacked.insert(
+            seq,
+            Pending {
+                message: message.clone(),
+                first_sent_ns: now_ns,
+                deadline_ns: now_ns + self.rto.rto_ns(),
+                retransmits: 0,
+            },
+        );
+        self.stats.sent += 1;
+        Ok((seq, sequenced(seq, message)))
+    }
+
+    /// 송신 메시지 가공 - 중요 메시지는 순번 부여, DeltaTick에는 ACK 탑재
+    pub fn outgoing(
+        &mut self,
+        message: SapMessage,
+        now_ns: u64,
+    ) -> Result<SapMessage, TransportError> {
+        match message {
+            SapMessage::DeltaTick(mut packet) => {
+                packet.ack = self.ack();
+                self.ack_pending = false;
+                Ok(SapMessage::DeltaTick(packet))
+            }
+            message if message.is_critical() => Ok(self.send_critical(message, now_ns)?.1),
+            message => Ok(message),
+        }
+    }
+
+    /// 수신 메시지 처리 - 새 메시지만 반환 (ACK/중복은 소비)
+    pub fn receive(&mut self, message: SapMessage, now_ns: u64) -> Option<SapMessage> {
+        match message {
+            SapMessage::DeltaTick(packet) => {
+                self.on_ack(&packet.ack, now_ns);
+                Some(SapMessage::DeltaTick(packet))
+            }
+            SapMessage::Ack(ack) => {
+                self.on_ack(&ack, now_ns);
+                None
+            }
+            SapMessage::ForwardSeq(up_to) => {
+                self.ack_pending = true;
+                self.skip_to(up_to);
+                None
+            }
+            SapMessage::Sequenced { seq, message } => {
+                // 중복이어도 ACK 재전송 (이전 ACK가 유실됐을 수 있음)
+                self.ack_pending = true;
+                if seq > self.recv_cumulative.saturating_add(SelectiveAck::WINDOW) {
+                    // SACK으로 표현할 수 없는 순번 - 기록하지 않고 재전송을 기다림
+                    self.stats.out_of_window += 1;
+                    None
+                } else if self.accept(seq) {
+                    Some(*message)
+                } else {
+                    self.stats.duplicates += 1;
+                    None
+                }
+            }
+            message => Some(message),
+        }
+    }
+
+    /// RTO가 지난 미확인 메시지 재전송
+    ///
+    /// 상대가 아직 건너뛰지 않은 실패 순번이 있으면 RTO마다 `ForwardSeq`도 함께 보낸다.
+    pub fn poll_retransmit(&mut self, now_ns: u64) -> Vec<SapMessage> {
+        let mut resend = Vec::new();
+        let mut expired = Vec::new();
+        for (&seq, pending) in self.unacked.iter_mut() {
+            if pending.deadline_ns > now_ns {
+                continue;
+            }
+            if pending.retransmits >= self.config.max_retransmits {
+                expired.push(seq);
+                continue;
+            }
+            pending.retransmits += 1;
+            pending.deadline_ns = now_ns + self.rto.backoff_ns(pending.retransmits);
+            resend.push(sequenced(seq, pending.message.clone()));
+        }
+        self.stats.retransmitted += resend.len() as u64;
+        if !expired.is_empty() {
+            // 새로 포기한 순번은 즉시 알림
+            self.forward_deadline_ns = now_ns;
+        }
+        for seq in expired {
+            self.unacked.remove(&seq);
+            self.failed.insert(seq);
+            self.stats.failed += 1;
+            tracing::warn!("reliable message {seq} dropped after max retransmits");
+        }
+        if let Some(up_to) = self.pending_forward() {
+            if self.forward_deadline_ns <= now_ns {
+                self.forward_deadline_ns = now_ns + self.rto.rto_ns();
+                self.stats.forwarded += 1;
+                resend.push(SapMessage::ForwardSeq(up_to));
+            }
+        }
+        resend
+    }
+
+    /// 단독 ACK (DeltaTick으로 ACK를 보내지 못한 경우)
+    pub fn take_ack(&mut self) -> Option<SapMessage> {
+        if !self.ack_pending {
+            return None;
+        }
+        self.ack_pending = false;
+        Some(SapMessage::Ack(self.ack()))
+    }
+
+    /// 현재 수신 현황
+    pub fn ack(&self) -> SelectiveAck {
+        let base = self.recv_cumulative.saturating_add(1);
+        let bitmap = self
+            .recv_ahead
+            .range(base..base.saturating_add(SelectiveAck::WINDOW))
+            .fold(0u64, |bits, &seq| bits | 1 << (seq - base));
+        SelectiveAck::new(self.recv_cumulative, bitmap)
+    }
+
+    /// 순번 전달 상태 (최근 `SelectiveAck::WINDOW`개 순번만 보관)
+    pub fn status(&self, seq: u64) -> DeliveryStatus {
+        if seq < self.retained_from() || seq >= self.next_seq {
+            DeliveryStatus::Unknown
+        } else if self.unacked.contains_key(&seq) {
+            DeliveryStatus::Pending
+        } else if self.failed.contains(&seq) {
+            DeliveryStatus::Failed
+        } else {
+            DeliveryStatus::Confirmed
+        }
+    }
+
+    /// 미확인 메시지 수
+    pub fn unacked_count(&self) -> usize {
+        self.unacked.len()
+    }
+
+    /// RTO 추정기
+    pub fn rto(&self) -> &RtoEstimator {
+        &self.rto
+    }
+
+    /// 통계 조회
+    pub fn stats(&self) -> &ReliableStats {
+        &self.stats
+    }
+
+    /// 상태를 보관하는 가장 오래된 순번
+    ///
+    /// 송신 윈도 때문에 이보다 오래된 순번은 모두 상대 누적 순번 이하
+    /// (확인 또는 건너뜀)이므로 `ForwardSeq`에도 더 이상 필요 없다.
+    fn retained_from(&self) -> u64 {
+        self.next_seq.saturating_sub(SelectiveAck::WINDOW).max(1)
+    }
+
+    /// 보관 범위를 벗어난 실패 순번 제거
+    fn prune_failed(&mut self) {
+        let from = self.retained_from();
+        while self.failed.first().is_some_and(|&seq| seq < from) {
+            self.failed.pop_first();
+        }
+    }
+
+    /// 상대가 건너뛰어야 할 순번 상한 (실패 순번이 상대 누적 순번 뒤에 남은 경우)
+    ///
+    /// 가장 오래된 미확인 순번 직전까지는 모두 확인 또는 실패 상태다.
+    fn pending_forward(&self) -> Option<u64> {
+        let up_to = self
+            .unacked
+            .keys()
+            .next()
+            .map_or(self.next_seq - 1, |&seq| seq - 1);
+        if up_to <= self.peer_cumulative {
+            return None;
+        }
+        self.failed
+            .range(self.peer_cumulative + 1..=up_to)
+            .next()
+            .map(|_| up_to)
+    }
+
+    /// 상대 ACK 반영 - 보낸 적 없는 순번까지 확인했다는 ACK는 무시
+    fn on_ack(&mut self, ack: &SelectiveAck, now_ns: u64) {
+        if ack.cumulative >= self.next_seq {
+            return;
+        }
+        self.peer_cumulative = self.peer_cumulative.max(ack.cumulative);
+        if ack.is_empty() {
+            return;
+        }
+        let confirmed: Vec<u64> = self
+            .unacked
+            .range(..=ack.highest())
+            .map(|(&seq, _)| seq)
+            .filter(|&seq| ack.contains(seq))
+            .collect();
+        for seq in confirmed {
+            let pending = self.unacked.remove(&seq).expect("collected from unacked");
+            if pending.retransmits == 0 {
+                self.rto
+                    .sample(now_ns.saturating_sub(pending.first_sent_ns));
+            }
+            self.stats.confirmed += 1;
+        }
+    }
+
+    /// 새 순번이면 기록 후 true
+    fn accept(&mut self, seq: u64) -> bool {
+        if seq <= self.recv_cumulative || !self.recv_ahead.insert(seq) {
+            return false;
+        }
+        self.advance();
+        true
+    }
+
+    /// 송신측이 포기한 순번까지 누적 순번 전진
+    ///
+    /// 송신측은 윈도 밖 순번을 보낼 수 없으므로 수신 윈도를 넘는 값은 무시한다.
+    fn skip_to(&mut self, up_to: u64) {
+        let limit = self.recv_cumulative.saturating_add(SelectiveAck::WINDOW);
+        if up_to <= self.recv_cumulative || up_to > limit {
+            return;
+        }
+        self.recv_cumulative = up_to;
+        self.recv_ahead = self.recv_ahead.split_off(&up_to.saturating_add(1));
+        self.advance();
+    }
+
+    fn advance(&mut self) {
+        while let Some(next) = self.recv_cumulative.checked_add(1) {
+            if !self.recv_ahead.remove(&next) {
+                break;
+            }
+            self.recv_cumulative = next;
+        }
+    }
+}
+
+impl Default for ReliableChannel {
+    fn default() -> Self {
+        Self::with_default_config()
+    }
+}
+
+/// Edge 측 로봇별 신뢰 채널 모음
+///
+/// 롤백 프레임을 (로봇, 롤백 틱, 생성 시각)으로 색인하여 수신 확인 여부를 조회한다.
+/// 색인은 채널이 상태를 보관하는 최근 순번 범위까지만 유지한다.
+#[derive(Debug, Clone, Default)]
+pub struct ReliableHub {
+    config: ReliableConfig,
+    channels: HashMap<u64, ReliableChannel>,
+    rollbacks: HashMap<(u64, u64, u64), u64>,
+}
+
+impl ReliableHub {
+    /// 새 ReliableHub 생성
+    pub fn new(config: ReliableConfig) -> Self {
+        Self {
+            config,
+            channels: HashMap::new(),
+            rollbacks: HashMap::new(),
+        }
+    }
+
+    /// 로봇 채널 (없으면 생성)
+    pub fn channel(&mut self, robot_id: u64) -> &mut ReliableChannel {
+        self.channels
+            .entry(robot_id)
+            .or_insert_with(|| ReliableChannel::new(self.config.clone()))
+    }
+
+    /// 중요 메시지 전송 → (순번, 전송할 메시지)
+    pub fn send_critical(
+        &mut self,
+        robot_id: u64,
+        message: SapMessage,
+        now_ns: u64,
+    ) -> Result<(u64, SapMessage), TransportError> {
+        self.channel(robot_id).send_critical(message, now_ns)
+    }
+
+    /// 롤백 프레임 전송 (대상은 `frame.robot_id`)
+    pub fn send_rollback(
+        &mut self,
+        frame: RollbackFrame,
+        now_ns: u64,
+    ) -> Result<SapMessage, TransportError> {
+        let key = rollback_key(&frame);
+        let robot_id = frame.robot_id;
+        let (seq, message) = self.send_critical(robot_id, SapMessage::Rollback(frame), now_ns)?;
+        self.rollbacks.insert(key, seq);
+        // 채널이 상태를 잊은 (확인 또는 실패 후 윈도를 벗어난) 롤백 색인 정리
+        let channel = &self.channels[&robot_id];
+        self.rollbacks.retain(|&(robot, _, _), &mut seq| {
+            robot != robot_id || channel.status(seq) != DeliveryStatus::Unknown
+        });
+        Ok(message)
+    }
+
+    /// 로봇에서 온 메시지 처리 - 새 메시지만 반환
+    pub fn receive(
+        &mut self,
+        robot_id: u64,
+        message: SapMessage,
+        now_ns: u64,
+    ) -> Option<SapMessage> {
+        self.channel(robot_id).receive(message, now_ns)
+    }
+
+    /// 모든 채널의 재전송 대상 → (로봇 ID, 메시지)
+    pub fn poll_retransmit(&mut self, now_ns: u64) -> Vec<(u64, SapMessage)> {
+        let mut resend: Vec<(u64, SapMessage)> = self
+            .channels
+            .iter_mut()
+            .flat_map(|(&robot_id, channel)| {
+                channel
+                    .poll_retransmit(now_ns)
+                    .into_iter()
+                    .map(move |message| (robot_id, message))
+            })
+            .collect();
+        resend.sort_by_key(|(robot_id, _)| *robot_id);
+        resend
+    }
+
+    /// 롤백 프레임 전달 상태
+    pub fn rollback_status(&self, robot_id: u64, frame: &RollbackFrame) -> DeliveryStatus {
+        let seq = self.rollbacks.get(&rollback_key(frame));
+        match (seq, self.channels.get(&robot_id)) {
+            (Some(&seq), Some(channel)) if frame.robot_id == robot_id => channel.status(seq),
+            _ => DeliveryStatus::Unknown,
+        }
+    }
+
+    /// 로봇이 롤백 프레임 수신을 확인했는지 여부
+    pub fn is_rollback_confirmed(&self, robot_id: u64, frame: &RollbackFrame) -> bool {
+        self.rollback_status(robot_id, frame) == DeliveryStatus::Confirmed
+    }
+
+    /// 로봇 채널 및 롤백 색인 제거
+    pub fn remove_robot(&mut self, robot_id: u64) {
+        self.channels.remove(&robot_id);
+        self.rollbacks.retain(|&(robot, _, _), _| robot != robot_id);
+    }
+
+    /// 채널 수
+    pub fn robot_count(&self) -> usize {
+        self.channels.len()
+    }
+}
+
+fn sequenced(seq: u64, message: SapMessage) -> SapMessage {
+    SapMessage::Sequenced {
+        seq,
+        message: Box::new(message),
+    }
+}
+
+fn rollback_key(frame: &RollbackFrame) -> (u64, u64, u64) {
+    (frame.robot_id, frame.rollback_tick, frame.created_at_ns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{EmulatedLink, LinkEmulatorConfig, LossModel, MessageLink};
+    use sap_core::packet::{DeltaTickPacket, EmergencyStopPacket, RollbackReason};
+
+    const MS: u64 = 1_000_000;
+
+    fn rollback(tick: u64) -> RollbackFrame {
+        RollbackFrame::new(1, 42, tick, RollbackReason::Manual).with_timestamp(tick * 50 * MS)
+    }
+
+    fn seq_of(message: &SapMessage) -> u64 {
+        match message {
+            SapMessage::Sequenced { seq, .. } => *seq,
+            other => panic!("expected Sequenced, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rto_estimator() {
+        let mut rto = RtoEstimator::new(200, 60, 2_000);
+        assert_eq!(rto.rto_ns(), 200 * MS);
+
+        rto.sample(40 * MS);
+        // SRTT 40, RTTVAR 20 → 40 + 80
+        assert_eq!(rto.srtt_ns(), Some(40 * MS));
+        assert_eq!(rto.rto_ns(), 120 * MS);
+
+        for _ in 0..50 {
+            rto.sample(10 * MS);
+        }
+        assert_eq!(rto.rto_ns(), 60 * MS); // 최소값
+        assert_eq!(rto.backoff_ns(2), 240 * MS);
+        assert_eq!(rto.backoff_ns(10), 2_000 * MS); // 상한
+    }
+
+    #[test]
+    fn test_selective_ack_and_duplicates() {
+        let mut edge = ReliableChannel::with_default_config();
+        let mut robot = ReliableChannel::with_default_config();
+
+        let wire: Vec<SapMessage> = (1..=4)
+            .map(|tick| {
+                edge.outgoing(SapMessage::Rollback(rollback(tick)), 0)
+                    .unwrap()
+            })
+            .collect();
+
+        // 2 유실, 4 중복 도착
+        for index in [0, 2, 3, 3] {
+            robot.receive(wire[index].clone(), 10 * MS);
+        }
+        assert_eq!(robot.stats().duplicates, 1);
+        let ack = robot.ack();
+        assert_eq!(ack.cumulative, 1);
+        assert!(ack.contains(3) && ack.contains(4) && !ack.contains(2));
+
+        let delta = robot
+            .outgoing(
+                SapMessage::DeltaTick(DeltaTickPacket::new(1, 42, 1)),
+                20 * MS,
+            )
+            .unwrap();
+        assert!(robot.take_ack().is_none()); // DeltaTick에 실림
+        edge.receive(delta, 20 * MS);
+
+        assert_eq!(edge.status(1), DeliveryStatus::Confirmed);
+        assert_eq!(edge.status(2), DeliveryStatus::Pending);
+        assert_eq!(edge.status(99), DeliveryStatus::Unknown);
+        assert_eq!(edge.unacked_count(), 1);
+
+        // RTO 경과 후 2만 재전송
+        let resend = edge.poll_retransmit(200 * MS);
+        assert_eq!(resend.iter().map(seq_of).collect::<Vec<_>>(), vec![2]);
+        assert!(robot.receive(resend[0].clone(), 210 * MS).is_some());
+        assert_eq!(robot.ack().cumulative, 4);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_retransmits() {
+        let config = ReliableConfig {
+            max_retransmits: 2,
+            ..Default::default()
+        };
+        let mut edge = ReliableChannel::new(config);
+        let (seq, _) = edge
+            .send_critical(
+                SapMessage::EmergencyStop(EmergencyStopPacket::new(1, 42, 1, 0)),
+                0,
+            )
+            .unwrap();
+
+        let mut now = 0;
+        let mut sends = 0;
+        while edge.status(seq) == DeliveryStatus::Pending {
+            now += 10 * MS;
+            sends += edge
+                .poll_retransmit(now)
+                .iter()
+                .filter(|m| matches!(m, SapMessage::Sequenced { .. }))
+                .count();
+        }
+        assert_eq!(sends, 2);
+        assert_eq!(edge.stats().forwarded, 1);
+        assert_eq!(edge.status(seq), DeliveryStatus::Failed);
+        assert_eq!(edge.stats().failed, 1);
+    }
+
+    #[test]
+    fn test_failed_seq_forwarded_past() {
+        let config = ReliableConfig {
+            max_retransmits: 0,
+            ..Default::default()
+        };
+        let mut edge = ReliableChannel::new(config);
+        let mut robot = ReliableChannel::with_default_config();
+
+        // 첫 메시지는 유실 후 포기
+        let stop = SapMessage::EmergencyStop(EmergencyStopPacket::new(1, 42, 1, 0));
+        let (lost, _) = edge.send_critical(stop, 0).unwrap();
+
+        let mut now = 0;
+        for tick in 1..=100 {
+            now += 50 * MS;
+            let (_, message) = edge
+                .send_critical(SapMessage::Rollback(rollback(tick)), now)
+                .unwrap();
+            robot.receive(message, now);
+            for message in edge.poll_retransmit(now) {
+                robot.receive(message, now);
+            }
+            edge.receive(robot.take_ack().unwrap(), now);
+            if tick == 10 {
+                assert_eq!(edge.status(lost), DeliveryStatus::Failed);
+            }
+        }
+
+        // 윈도를 벗어난 실패 순번은 정리됨
+        assert_eq!(edge.status(lost), DeliveryStatus::Unknown);
+        assert!(edge.failed.is_empty());
+        assert_eq!(edge.stats().confirmed, 100);
+        assert_eq!(edge.unacked_count(), 0);
+        assert_eq!(edge.stats().forwarded, 1);
+        // 수신측 누적 순번이 실패 순번을 지나 끝까지 전진 (선행 수신 집합 비움)
+        assert_eq!(robot.ack(), SelectiveAck::new(101, 0));
+    }
+
+    #[test]
+    fn test_window_bounded_by_cumulative() {
+        let config = ReliableConfig {
+            max_unacked: 128,
+            ..Default::default()
+        };
+        let mut edge = ReliableChannel::new(config);
+        let wire: Vec<SapMessage> = (1..=SelectiveAck::WINDOW)
+            .map(|tick| {
+                edge.outgoing(SapMessage::Rollback(rollback(tick)), 0)
+                    .unwrap()
+            })
+            .collect();
+        // 상대 누적 순번이 0이면 65번은 보낼 수 없음
+        assert!(matches!(
+            edge.send_critical(SapMessage::Rollback(rollback(65)), 0),
+            Err(TransportError::SendWindowFull { limit: 64 })
+        ));
+
+        // 수신측도 윈도 밖 순번은 기록하지 않음
+        let mut robot = ReliableChannel::with_default_config();
+        let far = sequenced(SelectiveAck::WINDOW + 1, SapMessage::Rollback(rollback(65)));
+        assert!(robot.receive(far, 0).is_none());
+        assert_eq!(robot.stats().out_of_window, 1);
+        assert!(robot.receive(wire[0].clone(), 0).is_some());
+        assert_eq!(robot.ack(), SelectiveAck::new(1, 0));
+    }
+
+    #[test]
+    fn test_hub_rollback_index_bounded() {
+        let config = ReliableConfig {
+            max_retransmits: 0,
+            ..Default::default()
+        };
+        let mut hub = ReliableHub::new(config);
+        let mut robot = ReliableChannel::with_default_config();
+
+        let mut now = 0;
+        for tick in 1..=1_000u64 {
+            now += 50 * MS;
+            let message = hub.send_rollback(rollback(tick), now).unwrap();
+            // 홀수 틱 롤백은 유실되어 실패
+            if tick % 2 == 0 {
+                robot.receive(message, now);
+            }
+            for (_, message) in hub.poll_retransmit(now) {
+                robot.receive(message, now);
+            }
+            if let Some(ack) = robot.take_ack() {
+                hub.receive(42, ack, now);
+            }
+        }
+
+        let channel = hub.channel(42);
+        assert!(channel.stats().failed > 400);
+        assert!(channel.failed.len() <= SelectiveAck::WINDOW as usize);
+        assert!(hub.rollbacks.len() <= SelectiveAck::WINDOW as usize);
+        assert!(hub.is_rollback_confirmed(42, &rollback(998)));
+        assert_eq!(
+            hub.rollback_status(42, &rollback(1)),
+            DeliveryStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn test_hostile_forward_seq_ignored() {
+        let mut edge = ReliableChannel::with_default_config();
+        let mut robot = ReliableChannel::with_default_config();
+
+        assert!(robot.receive(SapMessage::ForwardSeq(u64::MAX), 0).is_none());
+        assert!(robot
+            .receive(SapMessage::ForwardSeq(SelectiveAck::WINDOW + 1), 0)
+            .is_none());
+        assert_eq!(robot.ack(), SelectiveAck::new(0, 0));
+
+        // 이후 메시지도 정상 전달
+        let (_, message) = edge
+            .send_critical(SapMessage::Rollback(rollback(1)), 0)
+            .unwrap();
+        assert!(robot.receive(message, 0).is_some());
+        assert_eq!(robot.ack(), SelectiveAck::new(1, 0));
+    }
+
+    #[test]
+    fn test_hostile_ack_ignored() {
+        let config = ReliableConfig {
+            max_unacked: 128,
+            ..Default::default()
+        };
+        let mut edge = ReliableChannel::new(config);
+        let (seq, _) = edge
+            .send_critical(SapMessage::Rollback(rollback(1)), 0)
+            .unwrap();
+
+        // 보낸 적 없는 순번까지 확인했다는 단독 ACK
+        edge.receive(SapMessage::Ack(SelectiveAck::new(u64::MAX, u64::MAX)), 0);
+        assert_eq!(edge.status(seq), DeliveryStatus::Pending);
+
+        // DeltaTick에 실린 ACK도 동일
+        let mut packet = DeltaTickPacket::new(1, 42, 1);
+        packet.ack = SelectiveAck::new(u64::MAX - 1, 0);
+        assert!(edge.receive(SapMessage::DeltaTick(packet), 0).is_some());
+        assert_eq!(edge.status(seq), DeliveryStatus::Pending);
+
+        // 송신 윈도는 그대로 - 위조된 누적 순번으로 넓어지지 않음
+        for tick in 2..=SelectiveAck::WINDOW {
+            edge.send_critical(SapMessage::Rollback(rollback(tick)), 0)
+                .unwrap();
+        }
+        assert!(matches!(
+            edge.send_critical(SapMessage::Rollback(rollback(65)), 0),
+            Err(TransportError::SendWindowFull { limit: 64 })
+        ));
+    }
+
+    #[test]
+    fn test_rollbacks_confirmed_over_lossy_link() {
+        let lossy = LinkEmulatorConfig {
+            loss: LossModel::Bernoulli { loss_rate: 0.3 },
+            ..Default::default()
+        };
+        let (mut edge_link, mut robot_link) = EmulatedLink::symmetric(lossy, 17);
+        let mut hub = ReliableHub::default();
+        let mut robot = ReliableChannel::with_default_config();
+
+        let frames: Vec<RollbackFrame> = (1..=10).map(rollback).collect();
+        for frame in &frames {
+            let message = hub.send_rollback(frame.clone(), 0).unwrap();
+            edge_link.send(message, 0).unwrap();
+        }
+        let stop = SapMessage::EmergencyStop(EmergencyStopPacket::new(1, 42, 9, 0));
+        let (stop_seq, message) = hub.send_critical(42, stop, 0).unwrap();
+        edge_link.send(message, 0).unwrap();
+
+        let mut delivered = Vec::new();
+        for tick in 1..=100u64 {
+            let now = tick * 50 * MS;
+            for message in robot_link.poll(now) {
+                if let Some(message) = robot.receive(message, now) {
+                    delivered.push(message);
+                }
+            }
+            let delta = SapMessage::DeltaTick(DeltaTickPacket::new(1, 42, tick));
+            robot_link
+                .send(robot.outgoing(delta, now).unwrap(), now)
+                .unwrap();
+
+            for message in edge_link.poll(now) {
+                hub.receive(42, message, now);
+            }
+            for (_, message) in hub.poll_retransmit(now) {
+                edge_link.send(message, now).unwrap();
+            }
+        }
+
+        // 정확히 한 번씩 전달, 모두 확인 완료
+        assert_eq!(delivered.len(), 11);
+        let mut ticks: Vec<u64> = delivered
+            .iter()
+            .filter_map(|m| match m {
+                SapMessage::Rollback(frame) => Some(frame.rollback_tick),
+                _ => None,
+            })
+            .collect();
+        ticks.sort_unstable();
+        assert_eq!(ticks, (1..=10).collect::<Vec<_>>());
+        assert!(frames.iter().all(|f| hub.is_rollback_confirmed(42, f)));
+        assert_eq!(hub.channel(42).status(stop_seq), DeliveryStatus::Confirmed);
+        assert!(hub.channel(42).stats().retransmitted > 0);
+        assert!(!hub.is_rollback_confirmed(7, &frames[0]));
+        assert_eq!(
+            hub.rollback_status(42, &rollback(99)),
+            DeliveryStatus::Unknown
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Session - Edge↔Robot 단일 QUIC 연결
+//!
+//! PPR 매핑: AI_make_TransportSession
+
+use super::{SapMessage, TransportError};
+use bytes::Bytes;
+use sap_core::packet::{DeltaTickPacket, RollbackFrame};
+use sap_core::ticket::TransitTicket;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 세션 설정
+#[derive(Debug, Clone)]
+pub struct SessionConfig {
+    /// 유휴 연결 유지를 위한 keepalive 주기
+    pub keep_alive_interval: Duration,
+
+    /// 유휴 타임아웃 - 이 시간 동안 수신이 없으면 연결 종료
+    pub idle_timeout: Duration,
+
+    /// 신뢰 스트림 최대 프레임 크기 (바이트)
+    pub max_frame_len: usize,
+
+    /// 데이터그램 수신 버퍼 크기 (바이트)
+    pub datagram_buffer_len: usize,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            keep_alive_interval: Duration::from_millis(500), // 10틱
+            idle_timeout: Duration::from_secs(3),
+            max_frame_len: 1 << 20,         // 1MB
+            datagram_buffer_len: 256 << 10, // 256KB
+        }
+    }
+}
+
+impl SessionConfig {
+    /// QUIC 전송 설정으로 변환
+    pub(super) fn transport_config(&self) -> Result<Arc<quinn::TransportConfig>, TransportError> {
+        let idle_timeout = quinn::IdleTimeout::try_from(self.idle_timeout)
+            .map_err(|_| TransportError::Config("idle_timeout out of range".into()))?;
+
+        let mut transport = quinn::TransportConfig::default();
+        transport
+            .keep_alive_interval(Some(self.keep_alive_interval))
+            .max_idle_timeout(Some(idle_timeout))
+            .datagram_receive_buffer_size(Some(self.datagram_buffer_len));
+        Ok(Arc::new(transport))
+    }
+}
+
+/// Edge↔Robot 세션
+///
+/// PPR: AI_make_TransportSession(connection) -> Session
+#[derive(Debug, Clone)]
+pub struct Session {
+    connection: quinn::Connection,
+    config: SessionConfig,
+}
+
+impl Session {
+    pub(super) fn new(connection: quinn::Connection, config: SessionConfig) -> Self {
+        Self { connection, config }
+    }
+
+    /// 상대 주소
+    pub fn remote_address(&self) -> SocketAddr {
+        self.connection.remote_address()
+    }
+
+    /// 추정 왕복 지연
+    pub fn rtt(&self) -> Duration {
+        self.connection.rtt()
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    /// 연결 종료 사유 (열려 있으면 None)
+    pub fn close_reason(&self) -> Option<quinn::ConnectionError> {
+        self.connection.close_reason()
+    }
+
+    /// DeltaTick 전송 (비신뢰 데이터그램, 유실 시 재전송 없음)
+    pub fn send_delta(&self, packet: &DeltaTickPacket) -> Result<(), TransportError> {
+        self.send_datagram(&SapMessage::DeltaTick(packet.clone()))
+    }
+
+    /// 티켓 전송 (신뢰 스트림)
+    pub async fn send_ticket(&self, ticket: &TransitTicket) -> Result<(), TransportError> {
+        self.send_reliable(&SapMessage::Ticket(ticket.clone()))
+            .await
+    }
+
+    /// 롤백 프레임 전송 (신뢰 스트림)
+    pub async fn send_rollback(&self, frame: &RollbackFrame) -> Result<(), TransportError> {
+        self.send_reliable(&SapMessage::Rollback(frame.clone()))
+            .await
+    }
+
+    /// 메시지 종류에 맞는 경로로 전송
+    pub async fn send(&self, message: &SapMessage) -> Result<(), TransportError> {
+        if message.is_reliable() {
+            self.send_reliable(message).await
+        } else {
+            self.send_datagram(message)
+        }
+    }
+
+    /// 데이터그램 전송 - 신뢰 전송이 필요한 메시지는 거부
+    pub fn send_datagram(&self, message: &SapMessage) -> Result<(), TransportError> {
+        if message.is_reliable() {
+            return Err(TransportError::ReliableRequired);
+        }
+        let frame = message.encode()?;
+        if let Some(max) = self.connection.max_datagram_size() {
+            if frame.len() > max {
+                return Err(TransportError::FrameTooLarge {
+                    len: frame.len(),
+                    max,
+                });
+            }
+        }
+        self.connection.send_datagram(Bytes::from(frame))?;
+        Ok(())
+    }
+
+    /// 신뢰 스트림 전송 - 메시지당 단방향 스트림 1개
+    pub async fn send_reliable(&self, message: &SapMessage) -> Result<(), TransportError> {
+        let frame = message.encode()?;
+        if frame.len() > self.config.max_frame_len {
+            return Err(TransportError::FrameTooLarge {
+                len: frame.len(),
+                max: self.config.max_frame_len,
+            });
+        }
+        let mut stream = self.connection.open_uni().await?;
+        stream.write_all(&frame).await?;
+        stream.finish().await?;
+        Ok(())
+    }
+
+    /// 다음 메시지 수신 (데이터그램/스트림 중 먼저 도착한 것)
+    ///
+    /// 디코딩에 실패한 프레임은 에러로 반환하며 세션은 계속 사용할 수 있다.
+    pub async fn recv(&self) -> Result<SapMessage, TransportError> {
+        tokio::select! {
+            datagram = self.connection.read_datagram() => {
+                SapMessage::decode(&datagram?)
+            }
+            stream = self.connection.accept_uni() => {
+                let frame = stream?.read_to_end(self.config.max_frame_len).await?;
+                SapMessage::decode(&frame)
+            }
+        }
+    }
+
+    /// 연결 종료
+    pub fn close(&self, reason: &str) {
+        self.connection.close(0u32.into(), reason.as_bytes());
+    }
+
+    /// 연결이 닫힐 때까지 대기
+    pub async fn closed(&self) -> quinn::ConnectionError {
+        self.connection.closed().await
+    }
+}
@@ -0,0 +1,98 @@
+//! MessageLink 시나리오 - 에뮬레이터와 실제 QUIC 세션에서 같은 코드로 실행
+
+use sap_core::packet::{DeltaTickPacket, RollbackFrame, RollbackReason};
+use sap_network::transport::{
+    EmulatedLink, LinkEmulatorConfig, MessageLink, SapMessage, SessionConfig, SessionLink,
+    TlsIdentity, TransportEndpoint,
+};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+const SERVER_NAME: &str = "edge.local";
+const STEP_NS: u64 = 1_000_000; // 1ms
+const ROLLBACK_TICKS: [u64; 8] = [3, 4, 5, 1, 2, 9, 7, 8];
+
+/// 로봇이 DeltaTick을 보내고 Edge가 롤백 프레임들로 응답하는 왕복 시나리오
+///
+/// DeltaTick은 데이터그램이라 순서가 바뀔 수 있지만, 롤백 프레임(신뢰 메시지)은
+/// 송신 순서대로 도착해야 한다.
+async fn delta_and_rollback_roundtrip<L: MessageLink>(edge: &mut L, robot: &mut L) {
+    let mut now_ns = 0;
+    for tick in 1..=5 {
+        robot
+            .send(
+                SapMessage::DeltaTick(DeltaTickPacket::new(1, 42, tick)),
+                now_ns,
+            )
+            .unwrap();
+    }
+
+    let mut ticks = Vec::new();
+    let mut rollbacks = Vec::new();
+    for _ in 0..5_000 {
+        now_ns += STEP_NS;
+        for message in edge.poll(now_ns) {
+            if let SapMessage::DeltaTick(packet) = message {
+                ticks.push(packet.tick);
+                if packet.tick == 5 {
+                    for rollback_tick in ROLLBACK_TICKS {
+                        let frame = RollbackFrame::new(
+                            1,
+                            42,
+                            rollback_tick,
+                            RollbackReason::PredictionError {
+                                delta_magnitude: 0.5,
+                            },
+                        );
+                        edge.send(SapMessage::Rollback(frame), now_ns).unwrap();
+                    }
+                }
+            }
+        }
+        for message in robot.poll(now_ns) {
+            if let SapMessage::Rollback(frame) = message {
+                rollbacks.push(frame.rollback_tick);
+            }
+        }
+        if rollbacks.len() == ROLLBACK_TICKS.len() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(1)).await;
+    }
+
+    ticks.sort_unstable();
+    assert_eq!(ticks, vec![1, 2, 3, 4, 5]);
+    assert_eq!(rollbacks, ROLLBACK_TICKS);
+}
+
+#[tokio::test]
+async fn test_roundtrip_over_emulator() {
+    let config = LinkEmulatorConfig {
+        jitter_ms: 3.0,
+        reorder_rate: 0.3,
+        ..Default::default()
+    };
+    let (mut edge, mut robot) = EmulatedLink::symmetric(config, 42);
+    delta_and_rollback_roundtrip(&mut edge, &mut robot).await;
+    assert!(edge.incoming_stats().reordered > 0);
+}
+
+#[tokio::test]
+async fn test_roundtrip_over_quic() {
+    let localhost: SocketAddr = "127.0.0.1:0".parse().unwrap();
+    let identity = TlsIdentity::self_signed(&[SERVER_NAME]).unwrap();
+    let server = TransportEndpoint::server(localhost, &identity, SessionConfig::default()).unwrap();
+    let client = TransportEndpoint::client(
+        localhost,
+        &[identity.certificate_der()],
+        SessionConfig::default(),
+    )
+    .unwrap();
+
+    let server_addr = server.local_addr().unwrap();
+    let (accepted, connected) =
+        tokio::join!(server.accept(), client.connect(server_addr, SERVER_NAME));
+    let mut edge = SessionLink::new(accepted.unwrap().unwrap());
+    let mut robot = SessionLink::new(connected.unwrap());
+    delta_and_rollback_roundtrip(&mut edge, &mut robot).await;
+}
@@ -0,0 +1,146 @@
+//! QUIC 전송 계층 localhost 통합 테스트
+
+use sap_core::packet::{DeltaTickPacket, RollbackFrame, RollbackReason};
+use sap_core::ticket::TransitTicket;
+use sap_network::transport::{
+    SapMessage, Session, SessionConfig, TlsIdentity, TransportEndpoint, TransportError,
+};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+const SERVER_NAME: &str = "edge.local";
+
+fn localhost() -> SocketAddr {
+    "127.0.0.1:0".parse().unwrap()
+}
+
+/// (서버 엔드포인트, 서버 세션, 클라이언트 세션)
+async fn connected_pair(config: SessionConfig) -> (TransportEndpoint, Session, Session) {
+    let identity = TlsIdentity::self_signed(&[SERVER_NAME]).unwrap();
+    let server = TransportEndpoint::server(localhost(), &identity, config.clone()).unwrap();
+    let client =
+        TransportEndpoint::client(localhost(), &[identity.certificate_der()], config).unwrap();
+
+    let server_addr = server.local_addr().unwrap();
+    let (accepted, connected) =
+        tokio::join!(server.accept(), client.connect(server_addr, SERVER_NAME));
+    (server, accepted.unwrap().unwrap(), connected.unwrap())
+}
+
+async fn recv(session: &Session) -> SapMessage {
+    tokio::time::timeout(Duration::from_secs(5), session.recv())
+        .await
+        .expect("recv timed out")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_delta_tick_over_datagram() {
+    let (_server, edge, robot) = connected_pair(SessionConfig::default()).await;
+
+    let packet = DeltaTickPacket::new(1, 42, 100).with_timestamp(5_000_000_000);
+    robot.send_delta(&packet).unwrap();
+
+    match recv(&edge).await {
+        SapMessage::DeltaTick(received) => {
+            assert_eq!(received.robot_id, 42);
+            assert_eq!(received.tick, 100);
+            assert_eq!(received.timestamp_ns, 5_000_000_000);
+        }
+        other => panic!("unexpected message: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_ticket_and_rollback_over_streams() {
+    let (_server, edge, robot) = connected_pair(SessionConfig::default()).await;
+
+    edge.send_ticket(&TransitTicket::new(7, 42, 1))
+        .await
+        .unwrap();
+    edge.send_rollback(&RollbackFrame::new(
+        1,
+        42,
+        90,
+        RollbackReason::PredictionError {
+            delta_magnitude: 0.4,
+        },
+    ))
+    .await
+    .unwrap();
+
+    let mut got_ticket = false;
+    let mut got_rollback = false;
+    for _ in 0..2 {
+        match recv(&robot).await {
+            SapMessage::Ticket(ticket) => {
+                assert_eq!(ticket.ticket_id, 7);
+                got_ticket = true;
+            }
+            SapMessage::Rollback(frame) => {
+                assert_eq!(frame.rollback_tick, 90);
+                got_rollback = true;
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+    }
+    assert!(got_ticket && got_rollback);
+
+    // 신뢰 전송 메시지는 데이터그램으로 보낼 수 없음
+    let ticket = SapMessage::Ticket(TransitTicket::new(8, 42, 1));
+    assert!(matches!(
+        robot.send_datagram(&ticket),
+        Err(TransportError::ReliableRequired)
+    ));
+}
+
+#[tokio::test]
+async fn test_keepalive_holds_idle_session() {
+    let config = SessionConfig {
+        keep_alive_interval: Duration::from_millis(50),
+        idle_timeout: Duration::from_millis(300),
+        ..SessionConfig::default()
+    };
+    let (_server, edge, robot) = connected_pair(config).await;
+
+    // 유휴 타임아웃의 3배 동안 아무것도 보내지 않아도 연결 유지
+    tokio::time::sleep(Duration::from_millis(900)).await;
+    assert!(edge.close_reason().is_none());
+    assert!(robot.close_reason().is_none());
+
+    robot.close("done");
+    let reason = tokio::time::timeout(Duration::from_secs(5), edge.closed())
+        .await
+        .unwrap();
+    assert!(matches!(
+        reason,
+        quinn::ConnectionError::ApplicationClosed(_)
+    ));
+}
+
+#[tokio::test]
+async fn test_untrusted_server_rejected() {
+    let identity = TlsIdentity::self_signed(&[SERVER_NAME]).unwrap();
+    let other = TlsIdentity::self_signed(&[SERVER_NAME]).unwrap();
+    let server =
+        TransportEndpoint::server(localhost(), &identity, SessionConfig::default()).unwrap();
+    let client = TransportEndpoint::client(
+        localhost(),
+        &[other.certificate_der()],
+        SessionConfig::default(),
+    )
+    .unwrap();
+
+    let server_addr = server.local_addr().unwrap();
+    let server_task = tokio::spawn(async move {
+        let _ = server.accept().await;
+    });
+    let result = tokio::time::timeout(
+        Duration::from_secs(5),
+        client.connect(server_addr, SERVER_NAME),
+    )
+    .await
+    .unwrap();
+    assert!(result.is_err());
+    server_task.abort();
+}
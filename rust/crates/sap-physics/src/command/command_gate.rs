@@ -3,6 +3,7 @@
 //! PPR 매핑: AI_make_CommandGate, AI_process_eBPFFilter
 
 use super::MotionCommand;
+use sap_core::types::RobotState;
 use sap_core::validation::ValidationResult;
 
 /// CommandGate - 명령 필터링 게이트
@@ -19,16 +20,44 @@ pub struct CommandGate {
     adjusted_count: u64,
 }
 
+/// 정책 평가 컨텍스트
+///
+/// 배터리/온도/방향각 등 명령 외 정보가 필요한 정책에 전달
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolicyContext<'a> {
+    /// 로봇 최신 상태 (없으면 상태 기반 정책은 통과)
+    pub state: Option<&'a RobotState>,
+
+    /// 평가 시각 (나노초)
+    pub timestamp_ns: u64,
+}
+
+impl<'a> PolicyContext<'a> {
+    /// 새 PolicyContext 생성
+    pub fn new(timestamp_ns: u64) -> Self {
+        Self {
+            state: None,
+            timestamp_ns,
+        }
+    }
+
+    /// 로봇 상태 설정
+    pub fn with_state(mut self, state: &'a RobotState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
 /// 명령 정책 트레이트
 pub trait CommandPolicy: Send + Sync {
     /// 정책 이름
     fn name(&self) -> &str;
 
     /// 명령 검사
-    fn check(&self, cmd: &MotionCommand) -> PolicyResult;
+    fn check(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> PolicyResult;
 
     /// 명령 조정 (가능하면)
-    fn adjust(&self, cmd: &MotionCommand) -> Option<MotionCommand>;
+    fn adjust(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> Option<MotionCommand>;
 }
 
 /// 정책 검사 결과
@@ -59,31 +88,70 @@ impl CommandGate {
         self.policies.push(policy);
     }
 
+    /// 정책 추가 (빌더)
+    pub fn with_policy(mut self, policy: Box<dyn CommandPolicy>) -> Self {
+        self.add_policy(policy);
+        self
+    }
+
+    /// 명령 필터링 (로봇 상태 없이)
+    pub fn filter(&mut self, cmd: &MotionCommand) -> GateResult {
+        self.filter_with_context(cmd, &PolicyContext::default())
+    }
+
     /// 명령 필터링 (PPR: AI_make_CommandGate)
     ///
-    /// 모든 정책을 순차적으로 적용
-    pub fn filter(&mut self, cmd: &MotionCommand) -> GateResult {
+    /// 모든 정책을 순서대로 적용하며, 각 정책은 앞 정책이 조정한 명령을 검사한다.
+    /// 조정이 필요하지만 조정할 수 없는 정책은 거부로 처리한다.
+    pub fn filter_with_context(
+        &mut self,
+        cmd: &MotionCommand,
+        ctx: &PolicyContext<'_>,
+    ) -> GateResult {
+        let mut current = cmd.clone();
+        let mut reasons = Vec::new();
+        let mut fired_policies = Vec::new();
+
         for policy in &self.policies {
-            match policy.check(cmd) {
+            match policy.check(&current, ctx) {
                 PolicyResult::Pass => continue,
-                PolicyResult::Adjust(reason) => {
-                    self.adjusted_count += 1;
-                    if let Some(adjusted_cmd) = policy.adjust(cmd) {
-                        return GateResult::Adjusted {
-                            original: cmd.clone(),
-                            adjusted: adjusted_cmd,
+                PolicyResult::Adjust(reason) => match policy.adjust(&current, ctx) {
+                    Some(adjusted) => {
+                        current = adjusted;
+                        reasons.push(reason);
+                        fired_policies.push(policy.name().to_string());
+                    }
+                    None => {
+                        fired_policies.push(policy.name().to_string());
+                        self.rejected_count += 1;
+                        return GateResult::Rejected {
                             reason,
+                            fired_policies,
                         };
                     }
-                }
+                },
                 PolicyResult::Reject(reason) => {
+                    fired_policies.push(policy.name().to_string());
                     self.rejected_count += 1;
-                    return GateResult::Rejected { reason };
+                    return GateResult::Rejected {
+                        reason,
+                        fired_policies,
+                    };
                 }
             }
         }
 
-        GateResult::Passed
+        if fired_policies.is_empty() {
+            return GateResult::Passed;
+        }
+
+        self.adjusted_count += 1;
+        GateResult::Adjusted {
+            original: cmd.clone(),
+            adjusted: current,
+            reason: reasons.join("; "),
+            fired_policies,
+        }
     }
 
     /// 통계 조회
@@ -115,15 +183,19 @@ pub enum GateResult {
     /// 통과
     Passed,
 
-    /// 조정됨
+    /// 조정됨 (발동한 정책들의 조정이 누적된 명령)
     Adjusted {
         original: MotionCommand,
         adjusted: MotionCommand,
         reason: String,
+        fired_policies: Vec<String>,
     },
 
-    /// 거부됨
-    Rejected { reason: String },
+    /// 거부됨 (마지막 항목이 거부한 정책)
+    Rejected {
+        reason: String,
+        fired_policies: Vec<String>,
+    },
 }
 
 impl GateResult {
@@ -141,6 +213,15 @@ impl GateResult {
     pub fn is_passed(&self) -> bool {
         matches!(self, GateResult::Passed)
     }
+
+    /// 발동한 정책 이름 목록 (순서대로)
+    pub fn fired_policies(&self) -> &[String] {
+        match self {
+            GateResult::Passed => &[],
+            GateResult::Adjusted { fired_policies, .. }
+            | GateResult::Rejected { fired_policies, .. } => fired_policies,
+        }
+    }
 }
 
 /// 게이트 통계
@@ -154,6 +235,7 @@ pub struct GateStats {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::command::{BatteryDeratingPolicy, TicketRequiredPolicy};
     use sap_core::types::Velocity;
 
     // ===== 테스트용 정책 구현 =====
@@ -174,7 +256,7 @@ mod tests {
             "VelocityLimitPolicy"
         }
 
-        fn check(&self, cmd: &MotionCommand) -> PolicyResult {
+        fn check(&self, cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> PolicyResult {
             let speed = cmd.target_speed();
             if speed <= self.max_velocity {
                 PolicyResult::Pass
@@ -186,7 +268,7 @@ mod tests {
             }
         }
 
-        fn adjust(&self, cmd: &MotionCommand) -> Option<MotionCommand> {
+        fn adjust(&self, cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
             let clamped = cmd.target_velocity.clamp(self.max_velocity);
             Some(MotionCommand {
                 target_velocity: clamped,
@@ -195,27 +277,6 @@ mod tests {
         }
     }
 
-    /// 티켓 필수 정책
-    struct TicketRequiredPolicy;
-
-    impl CommandPolicy for TicketRequiredPolicy {
-        fn name(&self) -> &str {
-            "TicketRequiredPolicy"
-        }
-
-        fn check(&self, cmd: &MotionCommand) -> PolicyResult {
-            if cmd.ticket_id > 0 {
-                PolicyResult::Pass
-            } else {
-                PolicyResult::Reject("No valid ticket".to_string())
-            }
-        }
-
-        fn adjust(&self, _cmd: &MotionCommand) -> Option<MotionCommand> {
-            None // 조정 불가
-        }
-    }
-
     #[test]
     fn test_gate_pass() {
         let mut gate = CommandGate::new();
@@ -258,7 +319,7 @@ mod tests {
         let result = gate.filter(&cmd);
 
         match result {
-            GateResult::Rejected { reason } => {
+            GateResult::Rejected { reason, .. } => {
                 assert!(reason.contains("ticket"));
             }
             _ => panic!("Expected Rejected"),
@@ -283,4 +344,50 @@ mod tests {
         assert_eq!(stats.policy_count, 2);
         assert!(stats.adjusted_count > 0 || stats.rejected_count > 0);
     }
+
+    #[test]
+    fn test_gate_chains_adjustments() {
+        let mut gate = CommandGate::new()
+            .with_policy(Box::new(VelocityLimitPolicy::new(4.0)))
+            .with_policy(Box::new(BatteryDeratingPolicy::new(4.0)))
+            .with_policy(Box::new(TicketRequiredPolicy));
+
+        let mut state = RobotState::new(1);
+        state.battery_soc_milli = 5_000; // 5%: 최저 비율까지 감속
+        let ctx = PolicyContext::new(0).with_state(&state);
+
+        let cmd = MotionCommand::new(1)
+            .with_velocity(Velocity::new(10.0, 0.0, 0.0))
+            .with_ticket(1);
+
+        let result = gate.filter_with_context(&cmd, &ctx);
+        assert_eq!(
+            result.fired_policies(),
+            ["VelocityLimitPolicy", "BatteryDeratingPolicy"]
+        );
+        match result {
+            GateResult::Adjusted { adjusted, .. } => {
+                // 두 번째 정책이 첫 조정 결과(4 m/s)를 다시 감속
+                assert!((adjusted.target_speed() - 1.2).abs() < 0.01);
+            }
+            _ => panic!("Expected Adjusted"),
+        }
+        assert_eq!(gate.stats().adjusted_count, 1);
+    }
+
+    #[test]
+    fn test_gate_reject_lists_fired_policies() {
+        let mut gate = CommandGate::new()
+            .with_policy(Box::new(VelocityLimitPolicy::new(4.0)))
+            .with_policy(Box::new(TicketRequiredPolicy));
+
+        let cmd = MotionCommand::new(1).with_velocity(Velocity::new(10.0, 0.0, 0.0));
+        let result = gate.filter(&cmd);
+
+        assert_eq!(result.to_validation_result(), ValidationResult::REJECT);
+        assert_eq!(
+            result.fired_policies(),
+            ["VelocityLimitPolicy", "TicketRequiredPolicy"]
+        );
+    }
 }
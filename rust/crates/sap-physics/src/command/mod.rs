@@ -2,6 +2,13 @@
 
 mod command_gate;
 mod motion_command;
+mod policies;
 
-pub use command_gate::CommandGate;
+pub use command_gate::{
+    CommandGate, CommandPolicy, GateResult, GateStats, PolicyContext, PolicyResult,
+};
 pub use motion_command::MotionCommand;
+pub use policies::{
+    BatteryDeratingPolicy, ReverseMotionPolicy, SpeedZone, SpeedZonePolicy, ThermalDeratingPolicy,
+    TicketRequiredPolicy,
+};
@@ -21,6 +21,12 @@ pub struct MotionCommand {
     /// 목표 가속도
     pub target_acceleration: Acceleration,
 
+    /// 목표 각속도 (rad/s, 요 축, 반시계 방향 +)
+    pub target_angular_velocity: f32,
+
+    /// 목표 각가속도 (rad/s²)
+    pub target_angular_acceleration: f32,
+
     /// 사용 중인 티켓 ID
     pub ticket_id: u128,
 }
@@ -33,6 +39,8 @@ impl MotionCommand {
             current_position: Position::ORIGIN,
             target_velocity: Velocity::ZERO,
             target_acceleration: Acceleration::ZERO,
+            target_angular_velocity: 0.0,
+            target_angular_acceleration: 0.0,
             ticket_id: 0,
         }
     }
@@ -55,6 +63,13 @@ impl MotionCommand {
         self
     }
 
+    /// 목표 각속도/각가속도 설정
+    pub fn with_angular(mut self, angular_velocity: f32, angular_acceleration: f32) -> Self {
+        self.target_angular_velocity = angular_velocity;
+        self.target_angular_acceleration = angular_acceleration;
+        self
+    }
+
     /// 티켓 설정
     pub fn with_ticket(mut self, ticket_id: u128) -> Self {
         self.ticket_id = ticket_id;
@@ -70,7 +85,9 @@ impl MotionCommand {
     /// 정지 명령인지 확인
     #[inline]
     pub fn is_stop_command(&self) -> bool {
-        self.target_velocity.magnitude() < 0.001 && self.target_acceleration.magnitude() < 0.001
+        self.target_velocity.magnitude() < 0.001
+            && self.target_acceleration.magnitude() < 0.001
+            && self.target_angular_velocity.abs() < 0.001
     }
 }
 
@@ -82,6 +99,11 @@ impl Hash for MotionCommand {
         self.target_velocity.vx.to_bits().hash(state);
         self.target_velocity.vy.to_bits().hash(state);
         self.target_velocity.vz.to_bits().hash(state);
+        self.target_acceleration.ax.to_bits().hash(state);
+        self.target_acceleration.ay.to_bits().hash(state);
+        self.target_acceleration.az.to_bits().hash(state);
+        self.target_angular_velocity.to_bits().hash(state);
+        self.target_angular_acceleration.to_bits().hash(state);
     }
 }
 
@@ -146,4 +168,29 @@ mod tests {
         assert_eq!(hash1, hash2);
         assert_ne!(hash1, hash3);
     }
+
+    #[test]
+    fn test_motion_command_hash_covers_acceleration_and_rotation() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |cmd: &MotionCommand| {
+            let mut hasher = DefaultHasher::new();
+            cmd.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let base = MotionCommand::new(1).with_velocity(Velocity::new(1.0, 0.0, 0.0));
+        let accelerating = base
+            .clone()
+            .with_acceleration(Acceleration::new(0.5, 0.0, 0.0));
+        let turning = base.clone().with_angular(0.3, 0.0);
+        let spinning_up = base.clone().with_angular(0.0, 0.5);
+
+        assert_ne!(hash(&base), hash(&accelerating));
+        assert_ne!(hash(&base), hash(&turning));
+        assert_ne!(hash(&base), hash(&spinning_up));
+        assert!(!MotionCommand::new(1)
+            .with_angular(0.3, 0.0)
+            .is_stop_command());
+    }
 }
@@ -0,0 +1,473 @@
+//! 기본 제공 CommandGate 정책
+//!
+//! 속도 구역, 티켓 필수, 배터리/온도 감속, 후진 제한
+//!
+//! PPR 매핑: AI_process_eBPFFilter
+
+use super::command_gate::{CommandPolicy, PolicyContext, PolicyResult};
+use super::MotionCommand;
+use sap_core::types::{Position, Velocity};
+
+/// 속력을 제한값으로 클램핑한 명령
+fn with_speed_limit(cmd: &MotionCommand, limit: f32) -> MotionCommand {
+    MotionCommand {
+        target_velocity: cmd.target_velocity.clamp(limit),
+        ..cmd.clone()
+    }
+}
+
+/// 제한 초과 시 Adjust, 아니면 Pass
+fn check_speed_limit(cmd: &MotionCommand, limit: f32, label: &str) -> PolicyResult {
+    let speed = cmd.target_speed();
+    if speed <= limit {
+        PolicyResult::Pass
+    } else {
+        PolicyResult::Adjust(format!("{label}: speed {speed:.2} exceeds {limit:.2}"))
+    }
+}
+
+/// 시작 → 끝 구간에서 1.0 → min_ratio로 선형 감소하는 비율 (값이 커질수록 감속)
+///
+/// 시작 == 끝이면 계단 함수: 임계값 미만 1.0, 이상 min_ratio
+fn derate_ratio(value: f32, start: f32, end: f32, min_ratio: f32) -> f32 {
+    if (end - start).abs() < f32::EPSILON {
+        return if value < start { 1.0 } else { min_ratio };
+    }
+    let t = ((value - start) / (end - start)).clamp(0.0, 1.0);
+    1.0 - t * (1.0 - min_ratio)
+}
+
+// ============================================================================
+// 속도 구역
+// ============================================================================
+
+/// 속도 제한 구역 (축 정렬 박스)
+#[derive(Debug, Clone)]
+pub struct SpeedZone {
+    /// 구역 ID
+    pub id: u32,
+
+    /// 최소 모서리
+    pub min: Position,
+
+    /// 최대 모서리
+    pub max: Position,
+
+    /// 구역 내 최대 속력 (m/s)
+    pub max_speed: f32,
+}
+
+impl SpeedZone {
+    /// 새 SpeedZone 생성
+    pub fn new(id: u32, min: Position, max: Position, max_speed: f32) -> Self {
+        Self {
+            id,
+            min,
+            max,
+            max_speed,
+        }
+    }
+
+    /// 위치가 구역 내부인지 확인
+    pub fn contains(&self, position: &Position) -> bool {
+        (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y)
+            && (self.min.z..=self.max.z).contains(&position.z)
+    }
+}
+
+/// 속도 구역 정책
+///
+/// 현재 위치가 속한 구역 중 가장 낮은 제한을 적용
+#[derive(Debug, Clone, Default)]
+pub struct SpeedZonePolicy {
+    zones: Vec<SpeedZone>,
+}
+
+impl SpeedZonePolicy {
+    /// 새 SpeedZonePolicy 생성
+    pub fn new(zones: Vec<SpeedZone>) -> Self {
+        Self { zones }
+    }
+
+    /// 위치의 속도 제한 (구역 밖이면 None)
+    pub fn limit_at(&self, position: &Position) -> Option<f32> {
+        self.zones
+            .iter()
+            .filter(|zone| zone.contains(position))
+            .map(|zone| zone.max_speed)
+            .reduce(f32::min)
+    }
+}
+
+impl CommandPolicy for SpeedZonePolicy {
+    fn name(&self) -> &str {
+        "SpeedZonePolicy"
+    }
+
+    fn check(&self, cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> PolicyResult {
+        match self.limit_at(&cmd.current_position) {
+            Some(limit) => check_speed_limit(cmd, limit, "Speed zone"),
+            None => PolicyResult::Pass,
+        }
+    }
+
+    fn adjust(&self, cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
+        let limit = self.limit_at(&cmd.current_position)?;
+        Some(with_speed_limit(cmd, limit))
+    }
+}
+
+// ============================================================================
+// 티켓 필수
+// ============================================================================
+
+/// 티켓 필수 정책
+///
+/// 티켓 없는 명령 거부
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TicketRequiredPolicy;
+
+impl CommandPolicy for TicketRequiredPolicy {
+    fn name(&self) -> &str {
+        "TicketRequiredPolicy"
+    }
+
+    fn check(&self, cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> PolicyResult {
+        if cmd.ticket_id > 0 {
+            PolicyResult::Pass
+        } else {
+            PolicyResult::Reject("No valid ticket".to_string())
+        }
+    }
+
+    fn adjust(&self, _cmd: &MotionCommand, _ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
+        None // 조정 불가
+    }
+}
+
+// ============================================================================
+// 배터리 감속
+// ============================================================================
+
+/// 배터리 감속 정책
+///
+/// 잔량이 `derate_start_soc_milli` 아래로 내려가면 최대 속력을 선형으로 낮추고,
+/// `min_soc_milli` 이하에서는 충전소 복귀용 최저 비율만 허용
+#[derive(Debug, Clone)]
+pub struct BatteryDeratingPolicy {
+    /// 정격 최대 속력 (m/s)
+    pub max_speed: f32,
+
+    /// 감속 시작 잔량 (밀리 퍼센트)
+    pub derate_start_soc_milli: u32,
+
+    /// 최저 비율 도달 잔량 (밀리 퍼센트)
+    pub min_soc_milli: u32,
+
+    /// 최저 속력 비율 (0.0 ~ 1.0)
+    pub min_speed_ratio: f32,
+}
+
+impl BatteryDeratingPolicy {
+    /// 새 BatteryDeratingPolicy 생성 (30% → 5% 구간에서 30%까지 감속)
+    pub fn new(max_speed: f32) -> Self {
+        Self {
+            max_speed,
+            derate_start_soc_milli: 30_000,
+            min_soc_milli: 5_000,
+            min_speed_ratio: 0.3,
+        }
+    }
+
+    /// 잔량에 따른 속도 제한
+    pub fn limit_for(&self, soc_milli: u32) -> f32 {
+        // 잔량이 줄수록 감속하므로 부호를 뒤집어 계산
+        self.max_speed
+            * derate_ratio(
+                -(soc_milli as f32),
+                -(self.derate_start_soc_milli as f32),
+                -(self.min_soc_milli as f32),
+                self.min_speed_ratio,
+            )
+    }
+}
+
+impl CommandPolicy for BatteryDeratingPolicy {
+    fn name(&self) -> &str {
+        "BatteryDeratingPolicy"
+    }
+
+    fn check(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> PolicyResult {
+        match ctx.state {
+            Some(state) => {
+                check_speed_limit(cmd, self.limit_for(state.battery_soc_milli), "Battery")
+            }
+            None => PolicyResult::Pass,
+        }
+    }
+
+    fn adjust(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
+        let state = ctx.state?;
+        Some(with_speed_limit(
+            cmd,
+            self.limit_for(state.battery_soc_milli),
+        ))
+    }
+}
+
+// ============================================================================
+// 온도 감속
+// ============================================================================
+
+/// 컨트롤러 온도 감속 정책
+///
+/// `derate_start_c`부터 `shutdown_c`까지 선형 감속,
+/// `shutdown_c` 이상에서는 이동 명령 거부
+#[derive(Debug, Clone)]
+pub struct ThermalDeratingPolicy {
+    /// 정격 최대 속력 (m/s)
+    pub max_speed: f32,
+
+    /// 감속 시작 온도 (섭씨)
+    pub derate_start_c: i16,
+
+    /// 이동 차단 온도 (섭씨)
+    pub shutdown_c: i16,
+
+    /// 차단 직전 최저 속력 비율 (0.0 ~ 1.0)
+    pub min_speed_ratio: f32,
+}
+
+impl ThermalDeratingPolicy {
+    /// 새 ThermalDeratingPolicy 생성 (60℃ → 85℃ 구간에서 20%까지 감속)
+    pub fn new(max_speed: f32) -> Self {
+        Self {
+            max_speed,
+            derate_start_c: 60,
+            shutdown_c: 85,
+            min_speed_ratio: 0.2,
+        }
+    }
+
+    /// 온도에 따른 속도 제한
+    pub fn limit_for(&self, temp_c: i16) -> f32 {
+        self.max_speed
+            * derate_ratio(
+                temp_c as f32,
+                self.derate_start_c as f32,
+                self.shutdown_c as f32,
+                self.min_speed_ratio,
+            )
+    }
+}
+
+impl CommandPolicy for ThermalDeratingPolicy {
+    fn name(&self) -> &str {
+        "ThermalDeratingPolicy"
+    }
+
+    fn check(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> PolicyResult {
+        let Some(state) = ctx.state else {
+            return PolicyResult::Pass;
+        };
+        if state.controller_temp_c >= self.shutdown_c && !cmd.is_stop_command() {
+            return PolicyResult::Reject(format!(
+                "Controller overheated: {}C",
+                state.controller_temp_c
+            ));
+        }
+        check_speed_limit(cmd, self.limit_for(state.controller_temp_c), "Thermal")
+    }
+
+    fn adjust(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
+        let state = ctx.state?;
+        Some(with_speed_limit(
+            cmd,
+            self.limit_for(state.controller_temp_c),
+        ))
+    }
+}
+
+// ============================================================================
+// 후진 제한
+// ============================================================================
+
+/// 후진 속력 제한 정책
+///
+/// 로봇 방향각 기준 후방 성분만 제한하고 전방/측방 성분은 유지
+#[derive(Debug, Clone)]
+pub struct ReverseMotionPolicy {
+    /// 최대 후진 속력 (m/s)
+    pub max_reverse_speed: f32,
+}
+
+impl ReverseMotionPolicy {
+    /// 새 ReverseMotionPolicy 생성
+    pub fn new(max_reverse_speed: f32) -> Self {
+        Self { max_reverse_speed }
+    }
+
+    /// 방향각 기준 전방 속력 (후진이면 음수)
+    fn forward_speed(velocity: &Velocity, theta: f32) -> f32 {
+        velocity.vx * theta.cos() + velocity.vy * theta.sin()
+    }
+}
+
+impl CommandPolicy for ReverseMotionPolicy {
+    fn name(&self) -> &str {
+        "ReverseMotionPolicy"
+    }
+
+    fn check(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> PolicyResult {
+        let Some(state) = ctx.state else {
+            return PolicyResult::Pass;
+        };
+        let reverse = -Self::forward_speed(&cmd.target_velocity, state.theta);
+        if reverse <= self.max_reverse_speed {
+            PolicyResult::Pass
+        } else {
+            PolicyResult::Adjust(format!(
+                "Reverse speed {reverse:.2} exceeds {:.2}",
+                self.max_reverse_speed
+            ))
+        }
+    }
+
+    fn adjust(&self, cmd: &MotionCommand, ctx: &PolicyContext<'_>) -> Option<MotionCommand> {
+        let state = ctx.state?;
+        let forward = Self::forward_speed(&cmd.target_velocity, state.theta);
+        let excess = -forward - self.max_reverse_speed;
+        if excess <= 0.0 {
+            return Some(cmd.clone());
+        }
+        // 후방 초과분만 제거 (전방 단위벡터 방향으로 보정)
+        let v = cmd.target_velocity;
+        Some(MotionCommand {
+            target_velocity: Velocity::new(
+                v.vx + excess * state.theta.cos(),
+                v.vy + excess * state.theta.sin(),
+                v.vz,
+            ),
+            ..cmd.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::RobotState;
+
+    fn moving(vx: f32, vy: f32) -> MotionCommand {
+        MotionCommand::new(1)
+            .with_velocity(Velocity::new(vx, vy, 0.0))
+            .with_ticket(1)
+    }
+
+    #[test]
+    fn test_speed_zone_policy() {
+        let policy = SpeedZonePolicy::new(vec![
+            SpeedZone::new(1, Position::ORIGIN, Position::new(10.0, 10.0, 1.0), 2.0),
+            SpeedZone::new(2, Position::ORIGIN, Position::new(5.0, 5.0, 1.0), 1.0),
+        ]);
+        let ctx = PolicyContext::default();
+
+        // 겹치는 구역은 더 낮은 제한
+        let cmd = moving(3.0, 0.0).with_position(Position::new(1.0, 1.0, 0.0));
+        assert!(matches!(policy.check(&cmd, &ctx), PolicyResult::Adjust(_)));
+        let adjusted = policy.adjust(&cmd, &ctx).unwrap();
+        assert!((adjusted.target_speed() - 1.0).abs() < 1e-5);
+
+        // 구역 밖은 통과
+        let outside = moving(3.0, 0.0).with_position(Position::new(20.0, 1.0, 0.0));
+        assert!(matches!(policy.check(&outside, &ctx), PolicyResult::Pass));
+    }
+
+    #[test]
+    fn test_derate_ratio_step_for_degenerate_interval() {
+        assert_eq!(derate_ratio(59.0, 60.0, 60.0, 0.2), 1.0);
+        assert_eq!(derate_ratio(60.0, 60.0, 60.0, 0.2), 0.2);
+        assert_eq!(derate_ratio(70.0, 60.0, 60.0, 0.2), 0.2);
+
+        // 배터리: 임계 잔량 초과는 전속, 이하는 최소 비율
+        let mut policy = BatteryDeratingPolicy::new(4.0);
+        policy.derate_start_soc_milli = 20_000;
+        policy.min_soc_milli = 20_000;
+        assert!((policy.limit_for(20_001) - 4.0).abs() < 1e-5);
+        assert!((policy.limit_for(20_000) - 4.0 * policy.min_speed_ratio).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_battery_derating() {
+        let policy = BatteryDeratingPolicy::new(4.0);
+        assert!((policy.limit_for(80_000) - 4.0).abs() < 1e-5);
+        assert!((policy.limit_for(17_500) - 2.6).abs() < 1e-4);
+        assert!((policy.limit_for(1_000) - 1.2).abs() < 1e-5);
+
+        // 상태 없으면 통과
+        let cmd = moving(3.0, 0.0);
+        assert!(matches!(
+            policy.check(&cmd, &PolicyContext::default()),
+            PolicyResult::Pass
+        ));
+
+        let mut state = RobotState::new(1);
+        state.battery_soc_milli = 10_000;
+        let ctx = PolicyContext::new(0).with_state(&state);
+        assert!(matches!(policy.check(&cmd, &ctx), PolicyResult::Adjust(_)));
+        assert!(policy.adjust(&cmd, &ctx).unwrap().target_speed() <= policy.limit_for(10_000));
+    }
+
+    #[test]
+    fn test_thermal_derating_and_shutdown() {
+        let policy = ThermalDeratingPolicy::new(5.0);
+        let mut state = RobotState::new(1);
+
+        state.controller_temp_c = 40;
+        let ctx = PolicyContext::new(0).with_state(&state);
+        assert!(matches!(
+            policy.check(&moving(5.0, 0.0), &ctx),
+            PolicyResult::Pass
+        ));
+
+        state.controller_temp_c = 70;
+        let ctx = PolicyContext::new(0).with_state(&state);
+        assert!(matches!(
+            policy.check(&moving(5.0, 0.0), &ctx),
+            PolicyResult::Adjust(_)
+        ));
+
+        state.controller_temp_c = 90;
+        let ctx = PolicyContext::new(0).with_state(&state);
+        assert!(matches!(
+            policy.check(&moving(0.5, 0.0), &ctx),
+            PolicyResult::Reject(_)
+        ));
+        assert!(matches!(
+            policy.check(&MotionCommand::new(1), &ctx),
+            PolicyResult::Pass
+        ));
+    }
+
+    #[test]
+    fn test_reverse_motion_limit() {
+        let policy = ReverseMotionPolicy::new(0.5);
+        let mut state = RobotState::new(1);
+        state.theta = std::f32::consts::FRAC_PI_2; // +Y 방향
+        let ctx = PolicyContext::new(0).with_state(&state);
+
+        // -Y 2 m/s 후진 + X 1 m/s 측방
+        let cmd = moving(1.0, -2.0);
+        assert!(matches!(policy.check(&cmd, &ctx), PolicyResult::Adjust(_)));
+        let adjusted = policy.adjust(&cmd, &ctx).unwrap();
+        assert!((adjusted.target_velocity.vx - 1.0).abs() < 1e-5);
+        assert!((adjusted.target_velocity.vy + 0.5).abs() < 1e-5);
+
+        // 전진은 통과
+        assert!(matches!(
+            policy.check(&moving(0.0, 3.0), &ctx),
+            PolicyResult::Pass
+        ));
+    }
+}
@@ -2,11 +2,14 @@
 //!
 //! PPR 매핑: AI_process_CollisionPrediction, AI_process_SpatialIndex
 
+use super::SpatialIndex;
 use crate::validator::physics_validator::CollisionResult;
 use sap_core::types::{Position, Velocity};
+use serde::{Deserialize, Serialize};
 
 /// 동적 예측 지평 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct DynamicHorizonConfig {
     /// 최소 예측 지평 (초)
     pub min_horizon_secs: f32,
@@ -80,6 +83,12 @@ impl CollisionPredictor {
         }
     }
 
+    /// 안전 거리 변경
+    pub fn with_safety_distance(mut self, safety_distance: f32) -> Self {
+        self.safety_distance = safety_distance;
+        self
+    }
+
     /// 동적 horizon 사용 설정
     pub fn with_dynamic_horizon(mut self, config: DynamicHorizonConfig) -> Self {
         self.dynamic_horizon = Some(config);
@@ -95,6 +104,15 @@ impl CollisionPredictor {
         }
     }
 
+    /// 주어진 속력으로 예측 범위 내에 도달 가능한 거리 (m)
+    pub fn reach(&self, speed: f32) -> f32 {
+        if speed > 0.001 {
+            self.safety_distance + speed * self.effective_horizon(speed)
+        } else {
+            self.safety_distance
+        }
+    }
+
     /// 충돌 예측 (PPR: AI_process_CollisionPrediction)
     ///
     /// # Arguments
@@ -115,14 +133,57 @@ impl CollisionPredictor {
                 will_collide: false,
                 time_to_collision: None,
                 nearest_obstacle_distance: f32::MAX,
+                obstacle_index: None,
             };
         }
 
+        self.scan(position, velocity, obstacles.iter().enumerate())
+    }
+
+    /// 공간 인덱스 기반 충돌 예측
+    ///
+    /// 안전 거리 + 예측 지평 이동 거리 이내의 장애물만 검사하며,
+    /// 충돌 판정은 `predict`와 동일하다.
+    /// `nearest_obstacle_distance`는 질의 반경 내 장애물 기준 (없으면 `f32::MAX`).
+    pub fn predict_indexed(
+        &self,
+        position: &Position,
+        velocity: &Velocity,
+        index: &SpatialIndex,
+    ) -> CollisionResult {
+        self.predict_indexed_with(position, velocity, index, &mut Vec::new())
+    }
+
+    /// 후보 버퍼를 재사용하는 `predict_indexed` (배치 검증용)
+    pub fn predict_indexed_with(
+        &self,
+        position: &Position,
+        velocity: &Velocity,
+        index: &SpatialIndex,
+        candidates: &mut Vec<usize>,
+    ) -> CollisionResult {
+        index.query_radius(position, self.reach(velocity.magnitude()), candidates);
+        let points = index.points();
+        self.scan(
+            position,
+            velocity,
+            candidates.iter().map(|&i| (i, &points[i])),
+        )
+    }
+
+    /// 장애물 순회 검사 (인덱스 오름차순 입력)
+    fn scan<'a>(
+        &self,
+        position: &Position,
+        velocity: &Velocity,
+        obstacles: impl Iterator<Item = (usize, &'a Position)>,
+    ) -> CollisionResult {
         let mut nearest_distance = f32::MAX;
         let mut will_collide = false;
         let mut ttc: Option<f32> = None;
+        let mut obstacle_index: Option<usize> = None;
 
-        for obstacle in obstacles {
+        for (index, obstacle) in obstacles {
             // 현재 거리 계산
             let current_distance = position.distance(obstacle);
 
@@ -134,6 +195,7 @@ impl CollisionPredictor {
             if current_distance < self.safety_distance {
                 will_collide = true;
                 ttc = Some(0.0);
+                obstacle_index = Some(index);
                 break;
             }
 
@@ -160,6 +222,7 @@ impl CollisionPredictor {
                     if time_to_reach < effective_horizon {
                         will_collide = true;
                         ttc = Some(time_to_reach.max(0.0));
+                        obstacle_index = Some(index);
                     }
                 }
             }
@@ -169,6 +232,7 @@ impl CollisionPredictor {
             will_collide,
             time_to_collision: ttc,
             nearest_obstacle_distance: nearest_distance,
+            obstacle_index,
         }
     }
 
@@ -205,6 +269,21 @@ impl CollisionPredictor {
         true // 모든 점이 안전
     }
 
+    /// 공간 인덱스 기반 즉각적 충돌 검사
+    pub fn check_immediate_indexed(&self, position: &Position, index: &SpatialIndex) -> bool {
+        index.any_within(position, self.safety_distance)
+    }
+
+    /// 공간 인덱스 기반 안전 경로 검사 (샘플링 없이 선분 전체 검사)
+    pub fn check_path_indexed(
+        &self,
+        start: &Position,
+        end: &Position,
+        index: &SpatialIndex,
+    ) -> bool {
+        index.segment_clear(start, end, self.safety_distance)
+    }
+
     /// 안전 거리 조회
     pub fn safety_distance(&self) -> f32 {
         self.safety_distance
@@ -238,6 +317,7 @@ mod tests {
 
         assert!(result.will_collide);
         assert_eq!(result.time_to_collision, Some(0.0));
+        assert_eq!(result.obstacle_index, Some(0));
     }
 
     #[test]
@@ -286,6 +366,43 @@ mod tests {
         assert!(!predictor.check_path(&start, &end, &obstacles, 10));
     }
 
+    #[test]
+    fn test_indexed_matches_linear() {
+        let predictor = CollisionPredictor::new(1.0, 2.0);
+        let obstacles: Vec<Position> = (0..400)
+            .map(|i| Position::new((i % 20) as f32 * 3.0, (i / 20) as f32 * 3.0, 0.0))
+            .collect();
+        let index = SpatialIndex::build(&obstacles, 1.0, 1);
+
+        let cases = [
+            (Position::new(1.5, 1.5, 0.0), Velocity::new(2.0, 0.0, 0.0)),
+            (Position::new(0.5, 0.2, 0.0), Velocity::ZERO),
+            (
+                Position::new(100.0, 100.0, 0.0),
+                Velocity::new(1.0, 1.0, 0.0),
+            ),
+            (
+                Position::new(30.0, 31.5, 0.0),
+                Velocity::new(0.0, -3.0, 0.0),
+            ),
+        ];
+        for (pos, vel) in cases {
+            let linear = predictor.predict(&pos, &vel, &obstacles);
+            let indexed = predictor.predict_indexed(&pos, &vel, &index);
+            assert_eq!(linear.will_collide, indexed.will_collide);
+            assert_eq!(linear.time_to_collision, indexed.time_to_collision);
+            assert_eq!(linear.obstacle_index, indexed.obstacle_index);
+            assert_eq!(
+                predictor.check_immediate(&pos, &obstacles),
+                predictor.check_immediate_indexed(&pos, &index)
+            );
+        }
+
+        let start = Position::new(1.5, 1.5, 0.0);
+        assert!(predictor.check_path_indexed(&start, &Position::new(40.0, 1.5, 0.0), &index));
+        assert!(!predictor.check_path_indexed(&start, &Position::new(40.0, 3.0, 0.0), &index));
+    }
+
     #[test]
     fn test_dynamic_horizon_low_speed() {
         let config = DynamicHorizonConfig::default();
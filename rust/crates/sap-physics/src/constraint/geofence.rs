@@ -0,0 +1,104 @@
+//! Geofence - 지오펜스 (축 정렬 경계 상자)
+//!
+//! PPR 매핑: AI_process_Geofence
+
+use sap_core::types::Position;
+use serde::{Deserialize, Serialize};
+
+/// 지오펜스 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GeofenceKind {
+    /// 허용 영역 - 로봇은 내부에 머물러야 함
+    KeepIn,
+    /// 금지 영역 - 로봇은 외부에 머물러야 함
+    KeepOut,
+}
+
+/// 지오펜스
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Geofence {
+    /// 지오펜스 ID
+    pub id: u32,
+
+    /// 최소 모서리 (m)
+    pub min: Position,
+
+    /// 최대 모서리 (m)
+    pub max: Position,
+
+    /// 종류
+    pub kind: GeofenceKind,
+}
+
+impl Geofence {
+    /// 허용 영역 생성
+    pub fn keep_in(id: u32, min: Position, max: Position) -> Self {
+        Self {
+            id,
+            min,
+            max,
+            kind: GeofenceKind::KeepIn,
+        }
+    }
+
+    /// 금지 영역 생성
+    pub fn keep_out(id: u32, min: Position, max: Position) -> Self {
+        Self {
+            id,
+            min,
+            max,
+            kind: GeofenceKind::KeepOut,
+        }
+    }
+
+    /// 위치가 상자 내부인지 확인
+    pub fn contains(&self, position: &Position) -> bool {
+        position.x >= self.min.x
+            && position.x <= self.max.x
+            && position.y >= self.min.y
+            && position.y <= self.max.y
+            && position.z >= self.min.z
+            && position.z <= self.max.z
+    }
+
+    /// 위치가 지오펜스를 위반하는지 확인
+    pub fn is_violated_by(&self, position: &Position) -> bool {
+        match self.kind {
+            GeofenceKind::KeepIn => !self.contains(position),
+            GeofenceKind::KeepOut => self.contains(position),
+        }
+    }
+
+    /// 상자 위의 최근접점
+    pub fn closest_point(&self, position: &Position) -> Position {
+        Position::new(
+            position.x.clamp(self.min.x, self.max.x),
+            position.y.clamp(self.min.y, self.max.y),
+            position.z.clamp(self.min.z, self.max.z),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geofence_violation() {
+        let min = Position::new(0.0, 0.0, 0.0);
+        let max = Position::new(10.0, 10.0, 2.0);
+        let inside = Position::new(5.0, 5.0, 1.0);
+        let outside = Position::new(12.0, 5.0, 1.0);
+
+        let keep_in = Geofence::keep_in(1, min, max);
+        assert!(!keep_in.is_violated_by(&inside));
+        assert!(keep_in.is_violated_by(&outside));
+
+        let keep_out = Geofence::keep_out(2, min, max);
+        assert!(keep_out.is_violated_by(&inside));
+        assert!(!keep_out.is_violated_by(&outside));
+
+        let closest = keep_out.closest_point(&outside);
+        assert_eq!(closest, Position::new(10.0, 5.0, 1.0));
+    }
+}
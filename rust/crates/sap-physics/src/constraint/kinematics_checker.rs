@@ -7,7 +7,7 @@ use sap_core::types::{Acceleration, Velocity};
 
 /// 동역학 제한 검사기
 ///
-/// 속도, 가속도, 저크 제한을 검사합니다.
+/// 속도, 가속도, 저크, 각속도, 각가속도 제한을 검사합니다.
 #[derive(Debug, Clone)]
 pub struct KinematicsChecker {
     /// 최대 속도 (m/s)
@@ -19,6 +19,12 @@ pub struct KinematicsChecker {
     /// 최대 저크 (m/s³)
     max_jerk: f32,
 
+    /// 최대 각속도 (rad/s)
+    max_angular_velocity: f32,
+
+    /// 최대 각가속도 (rad/s²)
+    max_angular_acceleration: f32,
+
     /// 이전 가속도 (저크 계산용)
     prev_acceleration: Option<Acceleration>,
 
@@ -33,11 +39,41 @@ impl KinematicsChecker {
             max_velocity,
             max_acceleration,
             max_jerk,
+            max_angular_velocity: f32::INFINITY,
+            max_angular_acceleration: f32::INFINITY,
             prev_acceleration: None,
             prev_time_ns: 0,
         }
     }
 
+    /// 회전 제한 설정 (기본: 제한 없음)
+    pub fn with_angular_limits(
+        mut self,
+        max_angular_velocity: f32,
+        max_angular_acceleration: f32,
+    ) -> Self {
+        self.max_angular_velocity = max_angular_velocity;
+        self.max_angular_acceleration = max_angular_acceleration;
+        self
+    }
+
+    /// 직선 + 회전 동역학 제한 검사
+    pub fn check_motion(
+        &self,
+        velocity: &Velocity,
+        acceleration: &Acceleration,
+        angular_velocity: f32,
+        angular_acceleration: f32,
+    ) -> KinematicsResult {
+        let mut result = self.check(velocity, acceleration);
+        result.actual_angular_velocity = angular_velocity.abs();
+        result.actual_angular_acceleration = angular_acceleration.abs();
+        result.angular_velocity_ok = result.actual_angular_velocity <= self.max_angular_velocity;
+        result.angular_acceleration_ok =
+            result.actual_angular_acceleration <= self.max_angular_acceleration;
+        result
+    }
+
     /// 동역학 제한 검사 (PPR: AI_process_KinematicsCheck)
     ///
     /// # Arguments
@@ -60,8 +96,12 @@ impl KinematicsChecker {
             velocity_ok,
             acceleration_ok,
             jerk_ok,
+            angular_velocity_ok: true,
+            angular_acceleration_ok: true,
             actual_velocity,
             actual_acceleration,
+            actual_angular_velocity: 0.0,
+            actual_angular_acceleration: 0.0,
         }
     }
 
@@ -100,8 +140,12 @@ impl KinematicsChecker {
             velocity_ok,
             acceleration_ok,
             jerk_ok,
+            angular_velocity_ok: true,
+            angular_acceleration_ok: true,
             actual_velocity,
             actual_acceleration,
+            actual_angular_velocity: 0.0,
+            actual_angular_acceleration: 0.0,
         }
     }
 
@@ -122,6 +166,24 @@ impl KinematicsChecker {
         velocity.clamp(self.max_velocity)
     }
 
+    /// 조정된 가속도 반환 (제한 내로 클램핑)
+    pub fn clamp_acceleration(&self, acceleration: &Acceleration) -> Acceleration {
+        acceleration.clamp(self.max_acceleration)
+    }
+
+    /// 조정된 각속도 반환 (부호 유지, 제한 내로 클램핑)
+    pub fn clamp_angular_velocity(&self, angular_velocity: f32) -> f32 {
+        angular_velocity.clamp(-self.max_angular_velocity, self.max_angular_velocity)
+    }
+
+    /// 조정된 각가속도 반환 (부호 유지, 제한 내로 클램핑)
+    pub fn clamp_angular_acceleration(&self, angular_acceleration: f32) -> f32 {
+        angular_acceleration.clamp(
+            -self.max_angular_acceleration,
+            self.max_angular_acceleration,
+        )
+    }
+
     /// 상태 리셋
     pub fn reset(&mut self) {
         self.prev_acceleration = None;
@@ -170,6 +232,15 @@ mod tests {
         assert!((clamped.magnitude() - 5.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_clamp_acceleration() {
+        let checker = KinematicsChecker::new(5.0, 10.0, 50.0);
+        let accel = Acceleration::new(0.0, 20.0, 0.0);
+
+        let clamped = checker.clamp_acceleration(&accel);
+        assert!((clamped.magnitude() - 10.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_update_and_check_jerk() {
         let mut checker = KinematicsChecker::new(10.0, 10.0, 50.0);
@@ -1,7 +1,25 @@
 //! constraint 모듈 - 제약조건 검사기
 
 mod collision_predictor;
+mod geofence;
 mod kinematics_checker;
+mod safety_filter;
+mod spatial_index;
+mod speed_separation;
+mod ticket_constraint;
+mod uncertain_collision;
 
 pub use collision_predictor::{CollisionPredictor, DynamicHorizonConfig};
+pub use geofence::{Geofence, GeofenceKind};
 pub use kinematics_checker::KinematicsChecker;
+pub use safety_filter::{FilterState, SafetyFilter, SafetyFilterConfig, SafetyFilterResult};
+pub use spatial_index::SpatialIndex;
+pub use speed_separation::{
+    PartyKind, SeparationParty, SeparationResult, SpeedSeparationConfig, SpeedSeparationMonitor,
+};
+pub use ticket_constraint::{
+    TicketCheckResult, TicketConstraint, TicketConstraintConfig, OUT_OF_GRID_VOXEL,
+};
+pub use uncertain_collision::{
+    UncertainCollisionChecker, UncertainCollisionResult, UncertaintyConfig,
+};
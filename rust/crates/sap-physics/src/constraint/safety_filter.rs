@@ -0,0 +1,626 @@
+//! SafetyFilter - 최소 편차 안전 필터
+//!
+//! 요청된 속도에 가장 가까우면서 모든 제약을 동시에 만족하는 속도를 찾는다.
+//! 각 제약은 속도 공간의 볼록 집합(구 또는 반공간)으로 표현되고,
+//! Dykstra 교대 투영으로 교집합 위의 최근접점을 구한다.
+//!
+//! | 제약 | 속도 공간 집합 |
+//! |------|----------------|
+//! | 속도 | \|v\| ≤ v_max |
+//! | 가속도 | \|v - v0\| ≤ a_max·dt |
+//! | 저크 | \|v - (v0 + a0·dt)\| ≤ j_max·dt² |
+//! | 장애물 (CBF) | n·v ≥ -γ·h, h = \|p - o\| - d_safe |
+//! | 지오펜스 (CBF) | 면/최근접점 기준 반공간 |
+//!
+//! PPR 매핑: AI_process_SafetyFilter
+
+use super::geofence::{Geofence, GeofenceKind};
+use crate::command::MotionCommand;
+use crate::validator::PhysicsValidatorConfig;
+use sap_core::{
+    types::{Acceleration, Position, Velocity},
+    validation::constraint_ids,
+};
+
+/// 안전 필터 설정
+#[derive(Debug, Clone)]
+pub struct SafetyFilterConfig {
+    /// 최대 속도 (m/s)
+    pub max_velocity: f32,
+
+    /// 최대 가속도 (m/s²)
+    pub max_acceleration: f32,
+
+    /// 최대 저크 (m/s³)
+    pub max_jerk: f32,
+
+    /// 제어 주기 (초)
+    pub control_period_secs: f32,
+
+    /// 장애물 안전 거리 (m)
+    pub safety_distance: f32,
+
+    /// CBF 감쇠 계수 γ (1/s) - 클수록 경계 근처까지 빠르게 접근
+    pub cbf_gamma: f32,
+
+    /// 지오펜스 여유 거리 (m)
+    pub geofence_margin: f32,
+
+    /// 최대 투영 반복 횟수
+    pub max_iterations: u32,
+
+    /// 수렴/실현 가능성 허용 오차 (m/s)
+    pub tolerance: f32,
+}
+
+impl Default for SafetyFilterConfig {
+    fn default() -> Self {
+        Self::from_validator_config(&PhysicsValidatorConfig::default())
+    }
+}
+
+impl SafetyFilterConfig {
+    /// 검증기 설정으로부터 생성
+    pub fn from_validator_config(config: &PhysicsValidatorConfig) -> Self {
+        Self {
+            max_velocity: config.max_velocity,
+            max_acceleration: config.max_acceleration,
+            max_jerk: config.max_jerk,
+            control_period_secs: config.control_period_secs,
+            safety_distance: config.collision_safety_distance,
+            cbf_gamma: 2.0,
+            geofence_margin: 0.2,
+            max_iterations: 200,
+            tolerance: 1e-3,
+        }
+    }
+}
+
+/// 필터 입력 상태 (로봇의 현재 운동 상태)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FilterState {
+    /// 현재 속도 (m/s)
+    pub velocity: Velocity,
+
+    /// 현재 가속도 (m/s²)
+    pub acceleration: Acceleration,
+}
+
+impl FilterState {
+    /// 새 FilterState 생성
+    pub fn new(velocity: Velocity, acceleration: Acceleration) -> Self {
+        Self {
+            velocity,
+            acceleration,
+        }
+    }
+
+    /// 정지 상태
+    pub fn at_rest() -> Self {
+        Self::default()
+    }
+}
+
+/// 안전 필터 결과
+#[derive(Debug, Clone)]
+pub struct SafetyFilterResult {
+    /// 필터링된 명령 (활성 제약이 없으면 원본과 동일)
+    pub command: MotionCommand,
+
+    /// 모든 제약을 만족하는 해를 찾았는지 여부
+    pub feasible: bool,
+
+    /// 요청 속도와의 편차 (m/s)
+    pub deviation: f32,
+
+    /// 투영 반복 횟수
+    pub iterations: u32,
+
+    /// 요청 명령이 위반한 제약 비트맵 (constraint_ids 기준)
+    pub active_constraints: u64,
+
+    /// 위반된 지오펜스 ID (있는 경우 첫 번째)
+    pub active_geofence: Option<u32>,
+
+    /// 실현 불가로 저크 제약을 완화했는지 여부
+    pub jerk_relaxed: bool,
+}
+
+impl SafetyFilterResult {
+    /// 제약이 명령을 수정했는지 여부
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        self.active_constraints != 0
+    }
+
+    /// 특정 제약이 활성화되었는지 확인
+    #[inline]
+    pub fn is_active(&self, constraint_id: u8) -> bool {
+        constraint_id < 64 && (self.active_constraints & (1 << constraint_id)) != 0
+    }
+}
+
+type Vec3 = [f32; 3];
+
+/// 속도 공간의 볼록 제약 집합
+#[derive(Debug, Clone, Copy)]
+enum ConvexSet {
+    /// |v - center| ≤ radius
+    Ball { center: Vec3, radius: f32 },
+    /// normal · v ≤ bound
+    HalfSpace { normal: Vec3, bound: f32 },
+}
+
+impl ConvexSet {
+    fn project(&self, v: Vec3) -> Vec3 {
+        match *self {
+            ConvexSet::Ball { center, radius } => {
+                let d = sub(v, center);
+                let len = norm(d);
+                if len <= radius || len == 0.0 {
+                    v
+                } else {
+                    add(center, scale(d, radius / len))
+                }
+            }
+            ConvexSet::HalfSpace { normal, bound } => {
+                let excess = dot(normal, v) - bound;
+                if excess <= 0.0 {
+                    v
+                } else {
+                    // normal은 단위 벡터
+                    sub(v, scale(normal, excess))
+                }
+            }
+        }
+    }
+
+    fn violation(&self, v: Vec3) -> f32 {
+        match *self {
+            ConvexSet::Ball { center, radius } => (norm(sub(v, center)) - radius).max(0.0),
+            ConvexSet::HalfSpace { normal, bound } => (dot(normal, v) - bound).max(0.0),
+        }
+    }
+}
+
+/// 제약 항목 (집합 + 식별 정보)
+#[derive(Debug, Clone, Copy)]
+struct Constraint {
+    set: ConvexSet,
+    constraint_id: u8,
+    geofence_id: Option<u32>,
+}
+
+/// 최소 편차 안전 필터
+///
+/// PPR: AI_process_SafetyFilter(cmd, state, obstacles, geofences) -> SafetyFilterResult
+#[derive(Debug, Clone)]
+pub struct SafetyFilter {
+    config: SafetyFilterConfig,
+}
+
+impl SafetyFilter {
+    /// 새 SafetyFilter 생성
+    pub fn new(config: SafetyFilterConfig) -> Self {
+        Self { config }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(SafetyFilterConfig::default())
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &SafetyFilterConfig {
+        &self.config
+    }
+
+    /// 명령 필터링
+    ///
+    /// 요청 속도를 제약 교집합으로 투영한다. 저크 제약과 함께 실현 불가하면
+    /// 저크를 완화하고 다시 시도하며, 그래도 불가하면 `feasible = false`.
+    pub fn filter(
+        &self,
+        cmd: &MotionCommand,
+        state: &FilterState,
+        obstacles: &[Position],
+        geofences: &[Geofence],
+    ) -> SafetyFilterResult {
+        let desired = to_vec3(&cmd.target_velocity);
+        let mut constraints = Vec::with_capacity(3 + obstacles.len() + geofences.len() * 6);
+        self.build_constraints(cmd, state, obstacles, geofences, &mut constraints);
+
+        let mut active_constraints = 0u64;
+        let mut active_geofence = None;
+        for c in &constraints {
+            if c.set.violation(desired) > self.config.tolerance {
+                active_constraints |= 1 << c.constraint_id;
+                if active_geofence.is_none() {
+                    active_geofence = c.geofence_id;
+                }
+            }
+        }
+
+        if active_constraints == 0 {
+            return SafetyFilterResult {
+                command: cmd.clone(),
+                feasible: true,
+                deviation: 0.0,
+                iterations: 0,
+                active_constraints,
+                active_geofence,
+                jerk_relaxed: false,
+            };
+        }
+
+        let (mut solution, mut iterations, mut feasible) = self.project(desired, &constraints);
+        let mut jerk_relaxed = false;
+
+        if !feasible {
+            // 안전 제약이 우선: 승차감(저크)을 포기하고 재시도
+            constraints.retain(|c| c.constraint_id != constraint_ids::JERK_LIMIT);
+            let (relaxed, relaxed_iters, relaxed_feasible) = self.project(desired, &constraints);
+            solution = relaxed;
+            iterations += relaxed_iters;
+            feasible = relaxed_feasible;
+            jerk_relaxed = true;
+        }
+
+        let velocity = Velocity::new(solution[0], solution[1], solution[2]);
+        let dt = self.config.control_period_secs;
+        let acceleration = Acceleration::new(
+            (velocity.vx - state.velocity.vx) / dt,
+            (velocity.vy - state.velocity.vy) / dt,
+            (velocity.vz - state.velocity.vz) / dt,
+        );
+
+        SafetyFilterResult {
+            command: MotionCommand {
+                target_velocity: velocity,
+                target_acceleration: acceleration,
+                ..cmd.clone()
+            },
+            feasible,
+            deviation: norm(sub(solution, desired)),
+            iterations,
+            active_constraints,
+            active_geofence,
+            jerk_relaxed,
+        }
+    }
+
+    fn build_constraints(
+        &self,
+        cmd: &MotionCommand,
+        state: &FilterState,
+        obstacles: &[Position],
+        geofences: &[Geofence],
+        out: &mut Vec<Constraint>,
+    ) {
+        let cfg = &self.config;
+        let dt = cfg.control_period_secs;
+        let v0 = to_vec3(&state.velocity);
+        let a0 = [
+            state.acceleration.ax,
+            state.acceleration.ay,
+            state.acceleration.az,
+        ];
+        let p = to_position_vec3(&cmd.current_position);
+
+        out.push(Constraint {
+            set: ConvexSet::Ball {
+                center: [0.0; 3],
+                radius: cfg.max_velocity,
+            },
+            constraint_id: constraint_ids::VELOCITY_LIMIT,
+            geofence_id: None,
+        });
+        out.push(Constraint {
+            set: ConvexSet::Ball {
+                center: v0,
+                radius: cfg.max_acceleration * dt,
+            },
+            constraint_id: constraint_ids::ACCELERATION_LIMIT,
+            geofence_id: None,
+        });
+        out.push(Constraint {
+            set: ConvexSet::Ball {
+                center: add(v0, scale(a0, dt)),
+                radius: cfg.max_jerk * dt * dt,
+            },
+            constraint_id: constraint_ids::JERK_LIMIT,
+            geofence_id: None,
+        });
+
+        // 장애물 CBF: ḣ + γh ≥ 0  →  -n·v ≤ γh
+        for obstacle in obstacles {
+            let diff = sub(p, to_position_vec3(obstacle));
+            let dist = norm(diff);
+            let normal = if dist > f32::EPSILON {
+                scale(diff, 1.0 / dist)
+            } else {
+                [1.0, 0.0, 0.0]
+            };
+            out.push(Constraint {
+                set: ConvexSet::HalfSpace {
+                    normal: scale(normal, -1.0),
+                    bound: cfg.cbf_gamma * (dist - cfg.safety_distance),
+                },
+                constraint_id: constraint_ids::COLLISION_PREDICTION,
+                geofence_id: None,
+            });
+        }
+
+        for fence in geofences {
+            self.push_geofence_constraints(fence, p, out);
+        }
+    }
+
+    fn push_geofence_constraints(&self, fence: &Geofence, p: Vec3, out: &mut Vec<Constraint>) {
+        let gamma = self.config.cbf_gamma;
+        let margin = self.config.geofence_margin;
+        let min = to_position_vec3(&fence.min);
+        let max = to_position_vec3(&fence.max);
+
+        match fence.kind {
+            GeofenceKind::KeepIn => {
+                // 각 면에 대해 v_k ≤ γ(max_k - m - p_k), -v_k ≤ γ(p_k - min_k - m)
+                for axis in 0..3 {
+                    let mut normal = [0.0; 3];
+                    normal[axis] = 1.0;
+                    out.push(Constraint {
+                        set: ConvexSet::HalfSpace {
+                            normal,
+                            bound: gamma * (max[axis] - margin - p[axis]),
+                        },
+                        constraint_id: constraint_ids::GEOFENCE,
+                        geofence_id: Some(fence.id),
+                    });
+                    normal[axis] = -1.0;
+                    out.push(Constraint {
+                        set: ConvexSet::HalfSpace {
+                            normal,
+                            bound: gamma * (p[axis] - min[axis] - margin),
+                        },
+                        constraint_id: constraint_ids::GEOFENCE,
+                        geofence_id: Some(fence.id),
+                    });
+                }
+            }
+            GeofenceKind::KeepOut => {
+                let closest =
+                    to_position_vec3(&fence.closest_point(&Position::new(p[0], p[1], p[2])));
+                let diff = sub(p, closest);
+                let dist = norm(diff);
+                if dist <= f32::EPSILON {
+                    // 이미 금지 영역 내부: 어떤 속도도 안전하지 않음
+                    out.push(Constraint {
+                        set: ConvexSet::Ball {
+                            center: [0.0; 3],
+                            radius: -1.0,
+                        },
+                        constraint_id: constraint_ids::GEOFENCE,
+                        geofence_id: Some(fence.id),
+                    });
+                    return;
+                }
+                out.push(Constraint {
+                    set: ConvexSet::HalfSpace {
+                        normal: scale(diff, -1.0 / dist),
+                        bound: gamma * (dist - margin),
+                    },
+                    constraint_id: constraint_ids::GEOFENCE,
+                    geofence_id: Some(fence.id),
+                });
+            }
+        }
+    }
+
+    /// Dykstra 교대 투영 - 교집합 위의 최근접점
+    fn project(&self, desired: Vec3, constraints: &[Constraint]) -> (Vec3, u32, bool) {
+        let tol = self.config.tolerance;
+        let mut x = desired;
+        let mut increments = vec![[0.0f32; 3]; constraints.len()];
+        let mut iterations = 0;
+
+        while iterations < self.config.max_iterations {
+            iterations += 1;
+            let mut moved = 0.0f32;
+
+            for (c, inc) in constraints.iter().zip(increments.iter_mut()) {
+                let y = add(x, *inc);
+                let next = c.set.project(y);
+                *inc = sub(y, next);
+                moved = moved.max(norm(sub(next, x)));
+                x = next;
+            }
+
+            if moved < tol * 0.1 && max_violation(x, constraints) <= tol {
+                break;
+            }
+        }
+
+        let feasible = max_violation(x, constraints) <= tol;
+        (x, iterations, feasible)
+    }
+}
+
+fn max_violation(v: Vec3, constraints: &[Constraint]) -> f32 {
+    constraints
+        .iter()
+        .map(|c| c.set.violation(v))
+        .fold(0.0, f32::max)
+}
+
+#[inline]
+fn to_vec3(v: &Velocity) -> Vec3 {
+    [v.vx, v.vy, v.vz]
+}
+
+#[inline]
+fn to_position_vec3(p: &Position) -> Vec3 {
+    [p.x, p.y, p.z]
+}
+
+#[inline]
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+#[inline]
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[inline]
+fn scale(a: Vec3, s: f32) -> Vec3 {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+#[inline]
+fn dot(a: Vec3, b: Vec3) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn norm(a: Vec3) -> f32 {
+    dot(a, a).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cmd_with_velocity(vx: f32, vy: f32) -> MotionCommand {
+        MotionCommand::new(1).with_velocity(Velocity::new(vx, vy, 0.0))
+    }
+
+    #[test]
+    fn test_filter_passthrough_when_safe() {
+        let filter = SafetyFilter::with_default_config();
+        let cmd = cmd_with_velocity(1.0, 0.0);
+        let state = FilterState::new(Velocity::new(1.0, 0.0, 0.0), Acceleration::ZERO);
+
+        let result = filter.filter(&cmd, &state, &[], &[]);
+        assert!(result.feasible);
+        assert!(!result.is_modified());
+        assert_eq!(result.command.target_velocity, cmd.target_velocity);
+    }
+
+    #[test]
+    fn test_control_period_from_validator_config() {
+        let cmd = cmd_with_velocity(2.0, 0.0);
+        let reachable = |control_period_secs: f32| {
+            let config = SafetyFilterConfig::from_validator_config(&PhysicsValidatorConfig {
+                control_period_secs,
+                ..Default::default()
+            });
+            assert_eq!(config.control_period_secs, control_period_secs);
+            let result = SafetyFilter::new(config).filter(&cmd, &FilterState::at_rest(), &[], &[]);
+            result.command.target_speed()
+        };
+
+        // 정지 상태에서 한 주기 동안 도달 가능한 속도 = j_max × dt² (저크 제약이 지배)
+        assert!((reachable(0.1) - 0.5).abs() < 0.01);
+        assert!((reachable(0.05) - 0.125).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_filter_speed_limit_keeps_direction() {
+        let filter = SafetyFilter::with_default_config();
+        let cmd = cmd_with_velocity(6.0, 0.0);
+        let state = FilterState::new(Velocity::new(5.0, 0.0, 0.0), Acceleration::ZERO);
+
+        let result = filter.filter(&cmd, &state, &[], &[]);
+        assert!(result.feasible);
+        assert!(result.is_active(constraint_ids::VELOCITY_LIMIT));
+        assert!((result.command.target_speed() - 5.0).abs() < 0.01);
+        assert!(result.command.target_velocity.vy.abs() < 0.01);
+    }
+
+    #[test]
+    fn test_filter_acceleration_limits_step() {
+        let filter = SafetyFilter::with_default_config();
+        // 정지 상태에서 4 m/s 요청 → 한 주기(0.1s)에 최대 0.98 m/s, 저크 0.5 m/s
+        let cmd = cmd_with_velocity(4.0, 0.0);
+        let result = filter.filter(&cmd, &FilterState::at_rest(), &[], &[]);
+
+        assert!(result.feasible);
+        assert!(result.is_active(constraint_ids::ACCELERATION_LIMIT));
+        assert!(result.is_active(constraint_ids::JERK_LIMIT));
+        assert!((result.command.target_velocity.vx - 0.5).abs() < 0.01);
+        assert!(result.command.target_acceleration.magnitude() <= 9.8 + 0.01);
+    }
+
+    #[test]
+    fn test_filter_cbf_slides_around_obstacle() {
+        let filter = SafetyFilter::with_default_config();
+        // 장애물 정면 1.5m, 대각선 방향 요청 → 접근 성분만 줄이고 측면 이동 유지
+        let cmd = cmd_with_velocity(1.5, 1.5);
+        let state = FilterState::new(Velocity::new(1.5, 1.5, 0.0), Acceleration::ZERO);
+        let obstacles = [Position::new(1.5, 0.0, 0.0)];
+
+        let result = filter.filter(&cmd, &state, &obstacles, &[]);
+        assert!(result.feasible);
+        assert!(result.is_active(constraint_ids::COLLISION_PREDICTION));
+
+        // 접근 성분 ≤ γ(d - d_safe) = 2.0 * 0.5 = 1.0
+        let v = result.command.target_velocity;
+        assert!(v.vx <= 1.0 + 0.01);
+        assert!(
+            (v.vy - 1.5).abs() < 0.01,
+            "lateral motion preserved: {:?}",
+            v
+        );
+        assert!(!result.jerk_relaxed);
+    }
+
+    #[test]
+    fn test_filter_cbf_blocks_close_approach() {
+        let filter = SafetyFilter::with_default_config();
+        let cmd = cmd_with_velocity(1.0, 0.0);
+        let state = FilterState::new(Velocity::new(1.0, 0.0, 0.0), Acceleration::ZERO);
+        // 안전 거리 1m에 거의 도달 → 접근 속도 ≈ 0
+        let obstacles = [Position::new(1.1, 0.0, 0.0)];
+
+        let result = filter.filter(&cmd, &state, &obstacles, &[]);
+        assert!(result.is_active(constraint_ids::COLLISION_PREDICTION));
+        assert!(result.command.target_velocity.vx <= 0.2 + 0.01);
+        // 저크 한계로는 한 주기에 도달 불가 → 저크 완화
+        assert!(result.jerk_relaxed);
+        assert!(result.feasible);
+    }
+
+    #[test]
+    fn test_filter_keep_in_geofence() {
+        let filter = SafetyFilter::with_default_config();
+        let fence = Geofence::keep_in(
+            7,
+            Position::new(-10.0, -10.0, -1.0),
+            Position::new(1.0, 10.0, 1.0),
+        );
+        let cmd = cmd_with_velocity(2.0, 0.0);
+        let state = FilterState::new(Velocity::new(2.0, 0.0, 0.0), Acceleration::ZERO);
+
+        let result = filter.filter(&cmd, &state, &[], &[fence]);
+        assert!(result.is_active(constraint_ids::GEOFENCE));
+        assert_eq!(result.active_geofence, Some(7));
+        // γ(1.0 - 0.2 - 0.0) = 1.6
+        assert!(result.command.target_velocity.vx <= 1.6 + 0.01);
+    }
+
+    #[test]
+    fn test_filter_infeasible_inside_keep_out() {
+        let filter = SafetyFilter::with_default_config();
+        let fence = Geofence::keep_out(
+            3,
+            Position::new(-1.0, -1.0, -1.0),
+            Position::new(1.0, 1.0, 1.0),
+        );
+        let cmd = cmd_with_velocity(0.5, 0.0);
+
+        let result = filter.filter(&cmd, &FilterState::at_rest(), &[], &[fence]);
+        assert!(!result.feasible);
+        assert_eq!(result.active_geofence, Some(3));
+    }
+}
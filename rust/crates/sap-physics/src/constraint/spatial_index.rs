@@ -0,0 +1,280 @@
+//! SpatialIndex - 정적 장애물 공간 인덱스
+//!
+//! 균일 격자(해시 셀)에 장애물 점을 버킷팅하여 반경/선분 질의를
+//! 전체 선형 탐색 대신 주변 셀만 탐색한다. 맵 버전마다 한 번 생성.
+//!
+//! PPR 매핑: AI_process_SpatialIndex
+
+use sap_core::types::Position;
+use std::collections::{HashMap, HashSet};
+
+type CellKey = [i32; 3];
+
+/// 균일 격자 공간 인덱스
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    /// 셀 한 변 길이 (m)
+    cell_size: f32,
+
+    /// 맵 버전
+    map_version: u64,
+
+    /// 원본 장애물 (인덱스 = 장애물 ID)
+    points: Vec<Position>,
+
+    /// 셀 → 장애물 인덱스 (오름차순)
+    cells: HashMap<CellKey, Vec<u32>>,
+
+    /// 점유 셀 범위 (질의 범위 축소용, 비어있으면 None)
+    bounds: Option<(CellKey, CellKey)>,
+}
+
+impl SpatialIndex {
+    /// 장애물 목록으로 인덱스 생성
+    ///
+    /// `cell_size`는 주로 질의 반경(안전 거리) 수준으로 설정한다.
+    pub fn build(obstacles: &[Position], cell_size: f32, map_version: u64) -> Self {
+        let cell_size = cell_size.max(1e-3);
+        let mut cells: HashMap<CellKey, Vec<u32>> = HashMap::new();
+        let mut bounds: Option<(CellKey, CellKey)> = None;
+        for (index, point) in obstacles.iter().enumerate() {
+            let key = Self::key(point, cell_size);
+            cells.entry(key).or_default().push(index as u32);
+            bounds = Some(match bounds {
+                Some((lo, hi)) => (
+                    std::array::from_fn(|axis| lo[axis].min(key[axis])),
+                    std::array::from_fn(|axis| hi[axis].max(key[axis])),
+                ),
+                None => (key, key),
+            });
+        }
+        Self {
+            cell_size,
+            map_version,
+            points: obstacles.to_vec(),
+            cells,
+            bounds,
+        }
+    }
+
+    /// 맵 버전
+    #[inline]
+    pub fn map_version(&self) -> u64 {
+        self.map_version
+    }
+
+    /// 셀 크기 (m)
+    #[inline]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// 장애물 수
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 비어있는지 확인
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// 장애물 목록
+    #[inline]
+    pub fn points(&self) -> &[Position] {
+        &self.points
+    }
+
+    /// 반경 질의 - `center`에서 `radius` 미만인 장애물 인덱스 (오름차순)
+    pub fn query_radius(&self, center: &Position, radius: f32, out: &mut Vec<usize>) {
+        out.clear();
+        self.for_each_cell_near(center, radius, |bucket| {
+            out.extend(
+                bucket
+                    .iter()
+                    .map(|&i| i as usize)
+                    .filter(|&i| center.distance(&self.points[i]) < radius),
+            );
+        });
+        out.sort_unstable();
+    }
+
+    /// 반경 내 가장 작은 인덱스의 장애물
+    pub fn first_within(&self, center: &Position, radius: f32) -> Option<usize> {
+        let mut first: Option<usize> = None;
+        self.for_each_cell_near(center, radius, |bucket| {
+            if let Some(&i) = bucket
+                .iter()
+                .find(|&&i| center.distance(&self.points[i as usize]) < radius)
+            {
+                first = Some(first.map_or(i as usize, |f| f.min(i as usize)));
+            }
+        });
+        first
+    }
+
+    /// 반경 내 장애물 존재 여부
+    #[inline]
+    pub fn any_within(&self, center: &Position, radius: f32) -> bool {
+        self.first_within(center, radius).is_some()
+    }
+
+    /// 선분 질의 - 선분 `start`→`end`와의 거리가 `radius` 미만인 장애물 인덱스 (오름차순)
+    ///
+    /// 선분을 셀 크기 간격으로 따라가며 주변 셀만 방문하므로
+    /// 긴 대각선 선분도 AABB 전체를 훑지 않는다.
+    pub fn query_segment(
+        &self,
+        start: &Position,
+        end: &Position,
+        radius: f32,
+        out: &mut Vec<usize>,
+    ) {
+        out.clear();
+        let length = start.distance(end);
+        let steps = (length / self.cell_size).ceil().max(1.0) as usize;
+        // 샘플 간격의 절반만큼 반경을 넓혀 샘플 사이 구간도 포함
+        let reach = radius + length / steps as f32 * 0.5;
+        let mut visited: HashSet<CellKey> = HashSet::new();
+
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let sample = *start + (*end - *start).scale(t);
+            let (lo, hi) = self.cell_range(&sample, reach);
+            for x in lo[0]..=hi[0] {
+                for y in lo[1]..=hi[1] {
+                    for z in lo[2]..=hi[2] {
+                        let key = [x, y, z];
+                        if !visited.insert(key) {
+                            continue;
+                        }
+                        if let Some(bucket) = self.cells.get(&key) {
+                            out.extend(bucket.iter().map(|&i| i as usize).filter(|&i| {
+                                Self::segment_distance(start, end, &self.points[i]) < radius
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+        out.sort_unstable();
+    }
+
+    /// 선분이 장애물과 `radius` 이상 떨어져 있는지 확인
+    pub fn segment_clear(&self, start: &Position, end: &Position, radius: f32) -> bool {
+        let mut hits = Vec::new();
+        self.query_segment(start, end, radius, &mut hits);
+        hits.is_empty()
+    }
+
+    /// 점과 선분 사이 최단 거리
+    pub fn segment_distance(start: &Position, end: &Position, point: &Position) -> f32 {
+        let seg = *end - *start;
+        let len_sq = seg.x * seg.x + seg.y * seg.y + seg.z * seg.z;
+        if len_sq < f32::EPSILON {
+            return start.distance(point);
+        }
+        let rel = *point - *start;
+        let t = ((rel.x * seg.x + rel.y * seg.y + rel.z * seg.z) / len_sq).clamp(0.0, 1.0);
+        point.distance(&(*start + seg.scale(t)))
+    }
+
+    fn key(point: &Position, cell_size: f32) -> CellKey {
+        [
+            (point.x / cell_size).floor() as i32,
+            (point.y / cell_size).floor() as i32,
+            (point.z / cell_size).floor() as i32,
+        ]
+    }
+
+    /// 질의 셀 범위 (점유 범위와 교집합, 비면 lo > hi)
+    fn cell_range(&self, center: &Position, radius: f32) -> (CellKey, CellKey) {
+        let offset = Position::new(radius, radius, radius);
+        let lo = Self::key(&(*center - offset), self.cell_size);
+        let hi = Self::key(&(*center + offset), self.cell_size);
+        match self.bounds {
+            Some((min, max)) => (
+                std::array::from_fn(|axis| lo[axis].max(min[axis])),
+                std::array::from_fn(|axis| hi[axis].min(max[axis])),
+            ),
+            None => ([0; 3], [-1; 3]),
+        }
+    }
+
+    fn for_each_cell_near(&self, center: &Position, radius: f32, mut visit: impl FnMut(&[u32])) {
+        let (lo, hi) = self.cell_range(center, radius);
+        for x in lo[0]..=hi[0] {
+            for y in lo[1]..=hi[1] {
+                for z in lo[2]..=hi[2] {
+                    if let Some(bucket) = self.cells.get(&[x, y, z]) {
+                        visit(bucket);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 결정론적 의사 난수 점 (LCG)
+    fn scattered_points(count: usize) -> Vec<Position> {
+        let mut seed: u32 = 12345;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) as f32 / (1 << 24) as f32
+        };
+        (0..count)
+            .map(|_| Position::new(next() * 100.0 - 50.0, next() * 100.0 - 50.0, next() * 2.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_radius_query_matches_linear_scan() {
+        let points = scattered_points(2_000);
+        let index = SpatialIndex::build(&points, 1.5, 1);
+        assert_eq!(index.len(), 2_000);
+        assert_eq!(index.map_version(), 1);
+
+        let mut hits = Vec::new();
+        for center in scattered_points(50) {
+            index.query_radius(&center, 3.0, &mut hits);
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|&i| center.distance(&points[i]) < 3.0)
+                .collect();
+            assert_eq!(hits, expected);
+            assert_eq!(index.first_within(&center, 3.0), expected.first().copied());
+        }
+    }
+
+    #[test]
+    fn test_segment_query_matches_linear_scan() {
+        let points = scattered_points(2_000);
+        let index = SpatialIndex::build(&points, 1.0, 1);
+        let ends = scattered_points(20);
+
+        let mut hits = Vec::new();
+        for pair in ends.chunks(2) {
+            index.query_segment(&pair[0], &pair[1], 1.0, &mut hits);
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|&i| SpatialIndex::segment_distance(&pair[0], &pair[1], &points[i]) < 1.0)
+                .collect();
+            assert_eq!(hits, expected);
+        }
+    }
+
+    #[test]
+    fn test_segment_clear_and_negative_coordinates() {
+        let points = vec![Position::new(-2.5, -0.2, 0.0), Position::new(3.0, 4.0, 0.0)];
+        let index = SpatialIndex::build(&points, 1.0, 7);
+
+        assert!(!index.segment_clear(&Position::new(-5.0, 0.0, 0.0), &Position::ORIGIN, 0.5));
+        assert!(index.segment_clear(&Position::ORIGIN, &Position::new(5.0, 0.0, 0.0), 0.5));
+        assert!(index.any_within(&Position::new(3.0, 3.5, 0.0), 1.0));
+        assert!(!index.any_within(&Position::new(10.0, 10.0, 0.0), 1.0));
+    }
+}
@@ -0,0 +1,413 @@
+//! SpeedSeparationMonitor - 속도-분리 감시 (ISO/TS 15066 SSM)
+//!
+//! 최근접 로봇/장애물/사람까지의 거리로부터 허용 속력을 연속적으로 계산한다.
+//! 보호 분리 거리 S_p = S_h + S_r + S_s + C 가 현재 거리를 넘지 않도록
+//! 로봇의 접근 속력을 제한하므로, 거리가 줄수록 속력 상한이 부드럽게 감소한다.
+//!
+//! - S_h: 상대방이 로봇 정지 완료까지 접근하는 거리
+//! - S_r: 로봇 반응 시간 동안 이동 거리
+//! - S_s: 로봇 정지 거리 (`KinematicsParams::max_deceleration`)
+//! - C: 보호 분리 여유 (침입 거리 + 위치 불확실성)
+//!
+//! PPR 매핑: AI_process_SpeedSeparation
+
+use crate::kinematics::KinematicsParams;
+use sap_core::types::{Position, Velocity};
+
+/// 분리 감시 대상 유형
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartyKind {
+    /// 정적 장애물
+    Obstacle,
+
+    /// 사람 - 로봇이 정지할 때까지 계속 접근한다고 가정
+    Human,
+
+    /// 다른 로봇 - 반응 시간 후 자체 감속도로 정지
+    Robot {
+        /// 최대 감속도 (m/s²)
+        max_deceleration: f32,
+    },
+}
+
+/// 분리 감시 대상
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeparationParty {
+    /// 대상 유형
+    pub kind: PartyKind,
+
+    /// 위치
+    pub position: Position,
+
+    /// 속도
+    pub velocity: Velocity,
+
+    /// 점유 반경 (m) - 분리 거리는 반경을 뺀 표면 간 거리
+    pub radius: f32,
+}
+
+impl SeparationParty {
+    /// 정적 장애물
+    pub fn obstacle(position: Position) -> Self {
+        Self {
+            kind: PartyKind::Obstacle,
+            position,
+            velocity: Velocity::ZERO,
+            radius: 0.0,
+        }
+    }
+
+    /// 사람
+    pub fn human(position: Position, velocity: Velocity) -> Self {
+        Self {
+            kind: PartyKind::Human,
+            position,
+            velocity,
+            radius: 0.0,
+        }
+    }
+
+    /// 다른 로봇
+    pub fn robot(position: Position, velocity: Velocity, kinematics: &KinematicsParams) -> Self {
+        Self {
+            kind: PartyKind::Robot {
+                max_deceleration: kinematics.max_deceleration,
+            },
+            position,
+            velocity,
+            radius: 0.0,
+        }
+    }
+
+    /// 점유 반경 설정
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+}
+
+/// 속도-분리 감시 설정
+#[derive(Debug, Clone)]
+pub struct SpeedSeparationConfig {
+    /// 시스템 반응 시간 (초) - 감지부터 제동 시작까지
+    pub reaction_time_secs: f32,
+
+    /// 보호 분리 여유 C (m)
+    pub protective_separation: f32,
+
+    /// 사람 접근 속력 가정값 (m/s, ISO 13855)
+    pub human_approach_speed: f32,
+}
+
+impl Default for SpeedSeparationConfig {
+    fn default() -> Self {
+        Self {
+            reaction_time_secs: 0.1,    // 100ms
+            protective_separation: 0.3, // 30cm
+            human_approach_speed: 1.6,  // 1.6 m/s
+        }
+    }
+}
+
+/// 속도-분리 감시 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeparationResult {
+    /// 명령 방향 기준 허용 최대 속력 (m/s, 제한 없으면 무한대)
+    pub speed_cap: f32,
+
+    /// 명령 속도 배율 (0.0~1.0, 1.0이면 제한 없음)
+    pub scale: f32,
+
+    /// 속력을 가장 강하게 제한한 대상 인덱스
+    pub limiting_party: Option<usize>,
+
+    /// 제한 대상까지 표면 간 거리 (m)
+    pub separation: f32,
+
+    /// 현재 명령 속력 기준 보호 분리 거리 (m)
+    pub protective_distance: f32,
+}
+
+impl SeparationResult {
+    fn unlimited() -> Self {
+        Self {
+            speed_cap: f32::INFINITY,
+            scale: 1.0,
+            limiting_party: None,
+            separation: f32::INFINITY,
+            protective_distance: 0.0,
+        }
+    }
+
+    /// 속력 제한 여부
+    #[inline]
+    pub fn is_limited(&self) -> bool {
+        self.scale < 1.0
+    }
+}
+
+/// 속도-분리 감시기
+///
+/// PPR: AI_process_SpeedSeparation(robot, parties) -> SeparationResult
+#[derive(Debug, Clone)]
+pub struct SpeedSeparationMonitor {
+    config: SpeedSeparationConfig,
+}
+
+impl SpeedSeparationMonitor {
+    /// 새 SpeedSeparationMonitor 생성
+    pub fn new(config: SpeedSeparationConfig) -> Self {
+        Self { config }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(SpeedSeparationConfig::default())
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &SpeedSeparationConfig {
+        &self.config
+    }
+
+    /// 대상 방향 허용 접근 속력 (m/s)
+    ///
+    /// S_p(v) = v²/(2a) + v·T_r + S_h(v) + C ≤ d 를 만족하는 최대 v
+    pub fn allowed_approach_speed(
+        &self,
+        separation: f32,
+        party: &SeparationParty,
+        party_approach_speed: f32,
+        kinematics: &KinematicsParams,
+    ) -> f32 {
+        let decel = kinematics.max_deceleration.max(1e-3);
+        let reaction = self.config.reaction_time_secs;
+
+        // 상대방 이동 거리 = fixed + v·linear
+        let (fixed, linear) = match party.kind {
+            PartyKind::Obstacle => (0.0, 0.0),
+            PartyKind::Human => {
+                let speed = party_approach_speed.max(self.config.human_approach_speed);
+                // 로봇 반응 + 정지 시간(v/a) 동안 접근
+                (speed * reaction, speed / decel)
+            }
+            PartyKind::Robot { max_deceleration } => {
+                let speed = party_approach_speed;
+                let stopping = speed * speed / (2.0 * max_deceleration.max(1e-3));
+                (speed * reaction + stopping, 0.0)
+            }
+        };
+
+        let available = separation - self.config.protective_separation - fixed;
+        if available <= 0.0 {
+            return 0.0;
+        }
+
+        // v²/(2a) + v·b - available = 0 의 양의 근
+        let b = reaction + linear;
+        decel * (-b + (b * b + 2.0 * available / decel).sqrt())
+    }
+
+    /// 명령 속도에 대한 속력 상한 계산
+    ///
+    /// 분리 거리는 중심 간 거리에서 로봇과 대상의 반경을 뺀 표면 간 거리다.
+    pub fn check(
+        &self,
+        position: &Position,
+        velocity: &Velocity,
+        kinematics: &KinematicsParams,
+        robot_radius: f32,
+        parties: &[SeparationParty],
+    ) -> SeparationResult {
+        let speed = velocity.magnitude();
+        let mut result = SeparationResult::unlimited();
+        if speed < f32::EPSILON {
+            return result;
+        }
+
+        for (index, party) in parties.iter().enumerate() {
+            let offset = party.position - *position;
+            let distance = offset.magnitude();
+            let separation = (distance - robot_radius - party.radius).max(0.0);
+
+            // 대상 방향 단위 벡터 기준 접근 속력
+            let (approach, party_approach) = if distance < f32::EPSILON {
+                (speed, 0.0)
+            } else {
+                let toward =
+                    |v: &Velocity| (v.vx * offset.x + v.vy * offset.y + v.vz * offset.z) / distance;
+                (toward(velocity), (-toward(&party.velocity)).max(0.0))
+            };
+            if approach <= 0.0 {
+                continue; // 멀어지는 방향은 제한하지 않음
+            }
+
+            let allowed =
+                self.allowed_approach_speed(separation, party, party_approach, kinematics);
+            // 접근 성분이 allowed가 되는 명령 방향 속력
+            let cap = allowed * speed / approach;
+            if cap < result.speed_cap {
+                result.speed_cap = cap;
+                result.limiting_party = Some(index);
+                result.separation = separation;
+                result.protective_distance =
+                    self.protective_distance(approach, party, party_approach, kinematics);
+            }
+        }
+
+        result.scale = (result.speed_cap / speed).min(1.0);
+        result
+    }
+
+    /// 접근 속력 기준 보호 분리 거리 S_p (m)
+    pub fn protective_distance(
+        &self,
+        approach_speed: f32,
+        party: &SeparationParty,
+        party_approach_speed: f32,
+        kinematics: &KinematicsParams,
+    ) -> f32 {
+        let decel = kinematics.max_deceleration.max(1e-3);
+        let reaction = self.config.reaction_time_secs;
+        let stop_time = approach_speed / decel;
+        let party_travel = match party.kind {
+            PartyKind::Obstacle => 0.0,
+            PartyKind::Human => {
+                party_approach_speed.max(self.config.human_approach_speed) * (reaction + stop_time)
+            }
+            PartyKind::Robot { max_deceleration } => {
+                party_approach_speed * reaction
+                    + party_approach_speed * party_approach_speed
+                        / (2.0 * max_deceleration.max(1e-3))
+            }
+        };
+        approach_speed * reaction
+            + kinematics.stopping_distance(approach_speed)
+            + party_travel
+            + self.config.protective_separation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> SpeedSeparationMonitor {
+        SpeedSeparationMonitor::with_default_config()
+    }
+
+    fn forward(speed: f32) -> Velocity {
+        Velocity::new(speed, 0.0, 0.0)
+    }
+
+    #[test]
+    fn test_cap_decreases_smoothly_with_distance() {
+        let monitor = monitor();
+        let kinematics = KinematicsParams::default();
+        let mut previous = f32::INFINITY;
+
+        for step in (0..=40).rev() {
+            let distance = step as f32 * 0.1;
+            let parties = [SeparationParty::obstacle(Position::new(distance, 0.0, 0.0))];
+            let result =
+                monitor.check(&Position::ORIGIN, &forward(2.0), &kinematics, 0.0, &parties);
+            assert!(result.speed_cap <= previous);
+            previous = result.speed_cap;
+        }
+        // 보호 여유 안쪽이면 정지
+        assert_eq!(previous, 0.0);
+    }
+
+    #[test]
+    fn test_cap_satisfies_protective_distance() {
+        let monitor = monitor();
+        let kinematics = KinematicsParams::default();
+        let party = SeparationParty::human(Position::new(2.0, 0.0, 0.0), Velocity::ZERO);
+
+        let result = monitor.check(&Position::ORIGIN, &forward(2.0), &kinematics, 0.0, &[party]);
+        assert!(result.is_limited());
+        assert_eq!(result.limiting_party, Some(0));
+
+        // 허용 속력에서 보호 분리 거리 = 현재 거리
+        let sp = monitor.protective_distance(result.speed_cap, &party, 0.0, &kinematics);
+        assert!((sp - 2.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_human_stricter_than_obstacle() {
+        let monitor = monitor();
+        let kinematics = KinematicsParams::default();
+        let at = Position::new(3.0, 0.0, 0.0);
+
+        let obstacle = monitor.check(
+            &Position::ORIGIN,
+            &forward(2.0),
+            &kinematics,
+            0.0,
+            &[SeparationParty::obstacle(at)],
+        );
+        let human = monitor.check(
+            &Position::ORIGIN,
+            &forward(2.0),
+            &kinematics,
+            0.0,
+            &[SeparationParty::human(at, Velocity::ZERO)],
+        );
+        let robot = monitor.check(
+            &Position::ORIGIN,
+            &forward(2.0),
+            &kinematics,
+            0.0,
+            &[SeparationParty::robot(
+                at,
+                Velocity::new(-1.0, 0.0, 0.0),
+                &kinematics,
+            )],
+        );
+
+        assert!(human.speed_cap < obstacle.speed_cap);
+        assert!(robot.speed_cap < obstacle.speed_cap);
+    }
+
+    #[test]
+    fn test_radii_reduce_separation() {
+        let monitor = monitor();
+        let kinematics = KinematicsParams::default();
+        let at = Position::new(3.0, 0.0, 0.0);
+        let point = [SeparationParty::human(at, Velocity::ZERO)];
+        let wide = [SeparationParty::human(at, Velocity::ZERO).with_radius(0.5)];
+
+        let centers = monitor.check(&Position::ORIGIN, &forward(2.0), &kinematics, 0.0, &point);
+        let surfaces = monitor.check(&Position::ORIGIN, &forward(2.0), &kinematics, 0.7, &wide);
+        assert!((surfaces.separation - 1.8).abs() < 1e-5);
+        assert!(surfaces.speed_cap < centers.speed_cap);
+
+        // 반경이 겹치면 정지
+        let touching = monitor.check(&Position::ORIGIN, &forward(2.0), &kinematics, 2.6, &wide);
+        assert_eq!(touching.speed_cap, 0.0);
+    }
+
+    #[test]
+    fn test_receding_and_lateral_motion() {
+        let monitor = monitor();
+        let kinematics = KinematicsParams::default();
+        let parties = [SeparationParty::obstacle(Position::new(1.0, 0.0, 0.0))];
+
+        // 멀어지는 방향: 제한 없음
+        let away = monitor.check(
+            &Position::ORIGIN,
+            &forward(-2.0),
+            &kinematics,
+            0.0,
+            &parties,
+        );
+        assert!(!away.is_limited());
+        assert_eq!(away.limiting_party, None);
+
+        // 비스듬한 접근은 접근 성분만 제한
+        let diagonal = Velocity::new(1.0, 1.0, 0.0);
+        let oblique = monitor.check(&Position::ORIGIN, &diagonal, &kinematics, 0.0, &parties);
+        let direct = monitor.check(&Position::ORIGIN, &forward(1.0), &kinematics, 0.0, &parties);
+        assert!(oblique.speed_cap > direct.speed_cap);
+    }
+}
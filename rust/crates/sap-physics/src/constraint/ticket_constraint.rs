@@ -0,0 +1,271 @@
+//! TicketConstraint - 티켓 유효성 및 VTS 준수 검사기
+//!
+//! 이동 명령의 티켓이 유효한지, 다음 예측 구간의 위치가
+//! 티켓이 허용한 복셀-시간 슬롯 안에 머무는지 검사한다.
+//! 슬롯 경계의 시각 오차는 grace 허용치로 흡수한다.
+//!
+//! PPR 매핑: AI_process_TicketValidity, AI_process_VTSCompliance
+
+use crate::command::MotionCommand;
+use sap_core::{
+    ticket::{TicketStore, TransitTicket, VoxelGrid},
+    types::Position,
+    validation::ValidationReason,
+};
+
+/// VTS 위반 시 격자 밖 위치를 나타내는 복셀 ID
+pub const OUT_OF_GRID_VOXEL: u64 = u64::MAX;
+
+/// 티켓 제약 설정
+#[derive(Debug, Clone)]
+pub struct TicketConstraintConfig {
+    /// 슬롯/유효기간 경계 허용 오차 (나노초)
+    pub grace_ns: u64,
+
+    /// 위치 예측 구간 (나노초)
+    pub prediction_interval_ns: u64,
+
+    /// 예측 구간 샘플 수 (시작점 제외)
+    pub prediction_samples: u32,
+}
+
+impl Default for TicketConstraintConfig {
+    fn default() -> Self {
+        Self {
+            grace_ns: 20_000_000,                // 20ms
+            prediction_interval_ns: 100_000_000, // 100ms (1틱)
+            prediction_samples: 4,
+        }
+    }
+}
+
+/// 티켓 제약 검사 결과
+#[derive(Debug, Clone)]
+pub struct TicketCheckResult {
+    /// 티켓 유효 여부
+    pub ticket_ok: bool,
+
+    /// VTS 준수 여부 (티켓이 무효면 검사하지 않음: None)
+    pub vts_ok: Option<bool>,
+
+    /// 위반 이유
+    pub reason: Option<ValidationReason>,
+}
+
+impl TicketCheckResult {
+    fn pass() -> Self {
+        Self {
+            ticket_ok: true,
+            vts_ok: Some(true),
+            reason: None,
+        }
+    }
+
+    /// 모든 검사 통과 여부
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.reason.is_none()
+    }
+}
+
+/// 티켓/VTS 제약 검사기
+///
+/// PPR: AI_process_TicketValidity(cmd, ticket_store) -> TicketCheckResult
+#[derive(Debug, Clone)]
+pub struct TicketConstraint {
+    config: TicketConstraintConfig,
+    grid: VoxelGrid,
+}
+
+impl TicketConstraint {
+    /// 새 TicketConstraint 생성
+    pub fn new(grid: VoxelGrid, config: TicketConstraintConfig) -> Self {
+        Self { config, grid }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config(grid: VoxelGrid) -> Self {
+        Self::new(grid, TicketConstraintConfig::default())
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &TicketConstraintConfig {
+        &self.config
+    }
+
+    /// 명령 검사
+    ///
+    /// 정지 명령은 티켓 없이도 허용한다.
+    pub fn check(
+        &self,
+        cmd: &MotionCommand,
+        store: &dyn TicketStore,
+        timestamp_ns: u64,
+    ) -> TicketCheckResult {
+        if cmd.is_stop_command() {
+            return TicketCheckResult::pass();
+        }
+
+        let ticket = match store.ticket(cmd.ticket_id) {
+            Some(ticket) if self.ticket_valid(ticket, cmd.robot_id, timestamp_ns) => ticket,
+            _ => {
+                return TicketCheckResult {
+                    ticket_ok: false,
+                    vts_ok: None,
+                    reason: Some(ValidationReason::InvalidTicket {
+                        ticket_id: cmd.ticket_id,
+                    }),
+                }
+            }
+        };
+
+        let samples = self.config.prediction_samples.max(1) as u64;
+        for step in 0..=samples {
+            let dt_ns = self.config.prediction_interval_ns * step / samples;
+            let dt = dt_ns as f32 / 1_000_000_000.0;
+            let position = Position::new(
+                cmd.current_position.x + cmd.target_velocity.vx * dt,
+                cmd.current_position.y + cmd.target_velocity.vy * dt,
+                cmd.current_position.z + cmd.target_velocity.vz * dt,
+            );
+            if let Some(vts_id) = self.uncovered_voxel(ticket, &position, timestamp_ns + dt_ns) {
+                return TicketCheckResult {
+                    ticket_ok: true,
+                    vts_ok: Some(false),
+                    reason: Some(ValidationReason::VTSViolation { vts_id }),
+                };
+            }
+        }
+
+        TicketCheckResult::pass()
+    }
+
+    fn ticket_valid(&self, ticket: &TransitTicket, robot_id: u64, timestamp_ns: u64) -> bool {
+        let grace = self.config.grace_ns;
+        ticket.robot_id == robot_id
+            && timestamp_ns.saturating_add(grace) >= ticket.valid_from_ns
+            && timestamp_ns < ticket.valid_to_ns.saturating_add(grace)
+    }
+
+    /// 위치가 슬롯으로 덮이지 않으면 해당 복셀 ID 반환
+    fn uncovered_voxel(
+        &self,
+        ticket: &TransitTicket,
+        position: &Position,
+        timestamp_ns: u64,
+    ) -> Option<u64> {
+        let Some(voxel_id) = self.grid.voxel_id(position) else {
+            return Some(OUT_OF_GRID_VOXEL);
+        };
+        let grace = self.config.grace_ns;
+        let covered = ticket.vts_list.iter().any(|vts| {
+            vts.voxel_id == voxel_id
+                && timestamp_ns.saturating_add(grace) >= vts.t_start_ns
+                && timestamp_ns < vts.t_end_ns.saturating_add(grace)
+        });
+        (!covered).then_some(voxel_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::{ticket::VoxelTimeSlot, types::Velocity};
+    use std::collections::HashMap;
+
+    const MS: u64 = 1_000_000;
+
+    fn setup() -> (TicketConstraint, HashMap<u128, TransitTicket>) {
+        // 1m 복셀 4개를 x축으로 배치, 각 복셀 1초씩 예약
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [4, 1, 1]);
+        let ticket = TransitTicket::new(7, 1, 1).with_vts(vec![
+            VoxelTimeSlot::new(0, 0, 1_000 * MS),
+            VoxelTimeSlot::new(1, 1_000 * MS, 2_000 * MS),
+        ]);
+        let store = HashMap::from([(ticket.ticket_id, ticket)]);
+        (TicketConstraint::with_default_config(grid), store)
+    }
+
+    fn moving(x: f32, ticket_id: u128) -> MotionCommand {
+        MotionCommand::new(1)
+            .with_position(Position::new(x, 0.5, 0.5))
+            .with_velocity(Velocity::new(1.0, 0.0, 0.0))
+            .with_ticket(ticket_id)
+    }
+
+    #[test]
+    fn test_ticket_constraint_pass() {
+        let (constraint, store) = setup();
+        let result = constraint.check(&moving(0.2, 7), &store, 200 * MS);
+        assert!(result.is_ok());
+        assert_eq!(result.vts_ok, Some(true));
+    }
+
+    #[test]
+    fn test_ticket_constraint_invalid_ticket() {
+        let (constraint, store) = setup();
+
+        let unknown = constraint.check(&moving(0.2, 99), &store, 200 * MS);
+        assert!(matches!(
+            unknown.reason,
+            Some(ValidationReason::InvalidTicket { ticket_id: 99 })
+        ));
+        assert_eq!(unknown.vts_ok, None);
+
+        // 다른 로봇의 티켓
+        let mut other = moving(0.2, 7);
+        other.robot_id = 2;
+        assert!(!constraint.check(&other, &store, 200 * MS).ticket_ok);
+
+        // 만료 (grace 초과)
+        assert!(
+            !constraint
+                .check(&moving(1.5, 7), &store, 2_100 * MS)
+                .ticket_ok
+        );
+
+        // 정지 명령은 티켓 불필요
+        assert!(constraint
+            .check(&MotionCommand::new(1), &store, 5_000 * MS)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_ticket_constraint_vts_violation() {
+        let (constraint, store) = setup();
+
+        // 예측 구간 안에 예약되지 않은 복셀 2로 진입
+        let result = constraint.check(&moving(1.95, 7), &store, 1_500 * MS);
+        assert!(result.ticket_ok);
+        assert_eq!(result.vts_ok, Some(false));
+        assert!(matches!(
+            result.reason,
+            Some(ValidationReason::VTSViolation { vts_id: 2 })
+        ));
+
+        // 복셀 1에 너무 일찍 진입 (grace 초과)
+        let early = constraint.check(&moving(0.95, 7), &store, 500 * MS);
+        assert!(matches!(
+            early.reason,
+            Some(ValidationReason::VTSViolation { vts_id: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_ticket_constraint_boundary_grace() {
+        let (constraint, store) = setup();
+
+        // 슬롯 시작 10ms 전에 복셀 1 진입: grace(20ms) 내 허용
+        let result = constraint.check(&moving(0.95, 7), &store, 940 * MS);
+        assert!(result.is_ok());
+
+        let strict = TicketConstraint::new(
+            constraint.grid,
+            TicketConstraintConfig {
+                grace_ns: 0,
+                ..Default::default()
+            },
+        );
+        assert!(!strict.check(&moving(0.95, 7), &store, 940 * MS).is_ok());
+    }
+}
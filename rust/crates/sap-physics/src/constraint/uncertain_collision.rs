@@ -0,0 +1,287 @@
+//! UncertainCollisionChecker - 위치 불확실성 기반 충돌 확률 예측
+//!
+//! 로봇/장애물의 위치·속도 공분산을 최근접 접근 시점까지 전파하고,
+//! 최근접 거리 방향의 1차원 정규 분포로 안전 거리 침범 확률을 계산한다.
+//! 공분산이 없으면 정확한 값으로 간주하므로 확률은 0 또는 1이 된다.
+//!
+//! PPR 매핑: AI_process_UncertainCollision
+
+use super::CollisionPredictor;
+use sap_core::types::{Covariance3, DynamicObstacle, RobotState, Velocity};
+
+/// 불확실성 기반 충돌 판정 설정
+#[derive(Debug, Clone)]
+pub struct UncertaintyConfig {
+    /// REJECT 충돌 확률 임계값 (0.0~1.0)
+    pub reject_probability: f32,
+
+    /// 감속을 시작하는 위치 표준편차 (m)
+    pub high_uncertainty_std_m: f32,
+
+    /// 최소 속도 배율 (0.0~1.0)
+    pub min_speed_scale: f32,
+}
+
+impl Default for UncertaintyConfig {
+    fn default() -> Self {
+        Self {
+            reject_probability: 0.01,    // 1%
+            high_uncertainty_std_m: 0.2, // 20cm
+            min_speed_scale: 0.3,
+        }
+    }
+}
+
+/// 불확실성 기반 충돌 예측 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct UncertainCollisionResult {
+    /// 최대 충돌 확률 (0.0~1.0)
+    pub collision_probability: f32,
+
+    /// 충돌 확률이 가장 높은 장애물 인덱스
+    pub obstacle_index: Option<usize>,
+
+    /// 해당 장애물 최근접 접근 시간 (초)
+    pub time_to_closest_approach: f32,
+
+    /// 해당 장애물 최근접 거리 기대값 (m)
+    pub miss_distance: f32,
+
+    /// 감속 판단에 사용한 위치 표준편차 (m) - 로봇 자체 오차는 지면(XY) 성분만
+    pub position_std_dev: f32,
+
+    /// 명령 속도 배율 (0.0~1.0, 1.0이면 감속 없음)
+    pub speed_scale: f32,
+
+    /// REJECT 임계값 초과 여부
+    pub exceeds_threshold: bool,
+}
+
+impl UncertainCollisionResult {
+    /// 감속 필요 여부
+    #[inline]
+    pub fn requires_slowdown(&self) -> bool {
+        self.speed_scale < 1.0
+    }
+}
+
+/// 불확실성 기반 충돌 검사기
+///
+/// PPR: AI_process_UncertainCollision(robot, obstacles) -> UncertainCollisionResult
+#[derive(Debug, Clone)]
+pub struct UncertainCollisionChecker {
+    config: UncertaintyConfig,
+}
+
+impl UncertainCollisionChecker {
+    /// 새 UncertainCollisionChecker 생성
+    pub fn new(config: UncertaintyConfig) -> Self {
+        Self { config }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(UncertaintyConfig::default())
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &UncertaintyConfig {
+        &self.config
+    }
+
+    /// 명령 속도로 이동할 때의 충돌 확률 평가
+    ///
+    /// 안전 거리와 예측 지평은 `predictor` 설정을 따르며,
+    /// 장애물 반경만큼 안전 거리를 늘려 판정한다.
+    pub fn evaluate(
+        &self,
+        predictor: &CollisionPredictor,
+        state: &RobotState,
+        velocity: &Velocity,
+        obstacles: &[DynamicObstacle],
+    ) -> UncertainCollisionResult {
+        let robot_position = state.position_covariance.unwrap_or_default();
+        let robot_velocity = state.velocity_covariance.unwrap_or_default();
+        let horizon = predictor.effective_horizon(velocity.magnitude());
+
+        let mut result = UncertainCollisionResult {
+            collision_probability: 0.0,
+            obstacle_index: None,
+            time_to_closest_approach: 0.0,
+            miss_distance: f32::MAX,
+            position_std_dev: robot_position.planar_std_dev_bound(),
+            speed_scale: 1.0,
+            exceeds_threshold: false,
+        };
+        let mut worst_std_dev = 0.0f32;
+
+        for (index, obstacle) in obstacles.iter().enumerate() {
+            // 상대 위치/속도 (로봇 기준)
+            let (px, py, pz) = (
+                obstacle.position.x - state.position.x,
+                obstacle.position.y - state.position.y,
+                obstacle.position.z - state.position.z,
+            );
+            let (vx, vy, vz) = (
+                obstacle.velocity.vx - velocity.vx,
+                obstacle.velocity.vy - velocity.vy,
+                obstacle.velocity.vz - velocity.vz,
+            );
+
+            let rel_speed_sq = vx * vx + vy * vy + vz * vz;
+            let tca = if rel_speed_sq > f32::EPSILON {
+                (-(px * vx + py * vy + pz * vz) / rel_speed_sq).clamp(0.0, horizon)
+            } else {
+                0.0
+            };
+            let (mx, my, mz) = (px + vx * tca, py + vy * tca, pz + vz * tca);
+            let miss = (mx * mx + my * my + mz * mz).sqrt();
+
+            // 공분산 전파: Σ = Σp_r + Σp_o + t²(Σv_r + Σv_o)
+            let velocity_cov = robot_velocity + obstacle.velocity_covariance.unwrap_or_default();
+            let combined: Covariance3 = robot_position
+                + obstacle.position_covariance.unwrap_or_default()
+                + velocity_cov.scale(tca * tca);
+            let std_dev = if miss > f32::EPSILON {
+                combined.variance_along(mx, my, mz)
+            } else {
+                combined.variance_along(px, py, pz)
+            }
+            .sqrt();
+
+            let radius = predictor.safety_distance() + obstacle.radius;
+            let probability = Self::overlap_probability(miss, radius, std_dev);
+            if probability > result.collision_probability || result.obstacle_index.is_none() {
+                result.collision_probability = probability;
+                result.obstacle_index = Some(index);
+                result.time_to_closest_approach = tca;
+                result.miss_distance = miss;
+                worst_std_dev = std_dev;
+            }
+        }
+
+        result.position_std_dev = result.position_std_dev.max(worst_std_dev);
+        result.exceeds_threshold = result.collision_probability > self.config.reject_probability;
+        if result.position_std_dev > self.config.high_uncertainty_std_m {
+            result.speed_scale = (self.config.high_uncertainty_std_m / result.position_std_dev)
+                .clamp(self.config.min_speed_scale, 1.0);
+        }
+        result
+    }
+
+    /// 최근접 거리 ~ N(miss, σ²) 가 반경 안에 들어올 확률
+    fn overlap_probability(miss: f32, radius: f32, std_dev: f32) -> f32 {
+        if std_dev < 1e-6 {
+            return if miss < radius { 1.0 } else { 0.0 };
+        }
+        let upper = normal_cdf((radius - miss) / std_dev);
+        let lower = normal_cdf((-radius - miss) / std_dev);
+        (upper - lower).clamp(0.0, 1.0)
+    }
+}
+
+/// 표준 정규 누적분포 Φ(x)
+fn normal_cdf(x: f32) -> f32 {
+    0.5 * (1.0 + erf(x / std::f32::consts::SQRT_2))
+}
+
+/// 오차 함수 근사 (Abramowitz & Stegun 7.1.26, 최대 오차 1.5e-7)
+fn erf(x: f32) -> f32 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_6
+            + t * (-0.284_496_74 + t * (1.421_413_7 + t * (-1.453_152_1 + t * 1.061_405_4))));
+    sign * (1.0 - poly * (-x * x).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::Position;
+
+    fn robot(position_std: Option<f32>) -> RobotState {
+        let state = RobotState::new(1);
+        match position_std {
+            Some(std) => state.with_covariance(Covariance3::isotropic(std), Covariance3::ZERO),
+            None => state,
+        }
+    }
+
+    #[test]
+    fn test_exact_positions_are_binary() {
+        let checker = UncertainCollisionChecker::with_default_config();
+        let predictor = CollisionPredictor::new(0.5, 2.0);
+        let obstacle = DynamicObstacle::new(9, Position::new(1.0, 0.8, 0.0), Velocity::ZERO, 0.1);
+
+        // 최근접 거리 0.8 > 0.6 - 확률 0
+        let result = checker.evaluate(
+            &predictor,
+            &robot(None),
+            &Velocity::new(1.0, 0.0, 0.0),
+            &[obstacle],
+        );
+        assert_eq!(result.collision_probability, 0.0);
+        assert!((result.time_to_closest_approach - 1.0).abs() < 1e-5);
+        assert!(!result.exceeds_threshold);
+        assert!(!result.requires_slowdown());
+    }
+
+    #[test]
+    fn test_uncertainty_raises_probability() {
+        let checker = UncertainCollisionChecker::with_default_config();
+        let predictor = CollisionPredictor::new(0.5, 2.0);
+        let obstacle = DynamicObstacle::new(9, Position::new(1.0, 0.8, 0.0), Velocity::ZERO, 0.1)
+            .with_covariance(Covariance3::isotropic(0.05), Covariance3::ZERO);
+
+        // 같은 기하에서 위치 오차가 클수록 확률 증가 → 임계값 초과
+        let velocity = Velocity::new(1.0, 0.0, 0.0);
+        let obstacles = [obstacle];
+        let precise = checker.evaluate(&predictor, &robot(Some(0.02)), &velocity, &obstacles);
+        let noisy = checker.evaluate(&predictor, &robot(Some(0.3)), &velocity, &obstacles);
+
+        assert!(precise.collision_probability < noisy.collision_probability);
+        assert!(!precise.exceeds_threshold);
+        assert!(noisy.exceeds_threshold);
+        assert_eq!(noisy.obstacle_index, Some(0));
+    }
+
+    #[test]
+    fn test_high_uncertainty_slows_down() {
+        let checker = UncertainCollisionChecker::with_default_config();
+        let predictor = CollisionPredictor::new(0.5, 2.0);
+        let velocity = Velocity::new(1.0, 0.0, 0.0);
+
+        let precise = checker.evaluate(&predictor, &robot(Some(0.05)), &velocity, &[]);
+        assert!(!precise.requires_slowdown());
+
+        let noisy = checker.evaluate(&predictor, &robot(Some(0.5)), &velocity, &[]);
+        assert!(noisy.requires_slowdown());
+        assert!(noisy.speed_scale >= checker.config().min_speed_scale);
+        assert_eq!(noisy.collision_probability, 0.0);
+    }
+
+    #[test]
+    fn test_vertical_uncertainty_does_not_slow_down() {
+        let checker = UncertainCollisionChecker::with_default_config();
+        let predictor = CollisionPredictor::new(0.5, 2.0);
+        let velocity = Velocity::new(1.0, 0.0, 0.0);
+
+        // 고도 추정 오차만 큰 경우 지면 여유 거리에는 영향 없음
+        let state = RobotState::new(1).with_covariance(
+            Covariance3::from_std_devs(0.05, 0.05, 1.0),
+            Covariance3::ZERO,
+        );
+        let result = checker.evaluate(&predictor, &state, &velocity, &[]);
+        assert!(!result.requires_slowdown());
+        assert!(result.position_std_dev < checker.config().high_uncertainty_std_m);
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-3);
+        assert!((normal_cdf(-1.96) - 0.025).abs() < 1e-3);
+    }
+}
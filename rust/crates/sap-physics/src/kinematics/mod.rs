@@ -5,5 +5,7 @@
 //! PPR 매핑: AI_make_VehicleProfile
 
 mod profile;
+mod scurve;
 
 pub use profile::{KinematicsParams, VehicleProfile, VehicleType};
+pub use scurve::{LegTiming, PathState, PlannedMotion, SCurveGenerator, SCurveProfile};
@@ -56,6 +56,7 @@ impl VehicleType {
 
 /// 운동학 파라미터
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct KinematicsParams {
     /// 최대 선속도 (m/s)
     pub max_velocity: f32,
@@ -109,6 +110,7 @@ impl KinematicsParams {
 
 /// 로봇 프로파일 (유형 + 파라미터)
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct VehicleProfile {
     /// 로봇 유형
     pub vehicle_type: VehicleType,
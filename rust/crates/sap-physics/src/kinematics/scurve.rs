@@ -0,0 +1,505 @@
+//! S-커브 모션 프로파일 생성기
+//!
+//! 저크 제한 7구간 S-커브 (정지 → 정지)
+//!
+//! | 구간 | 저크 | 설명 |
+//! |------|------|------|
+//! | 1 | +J | 가속도 증가 |
+//! | 2 | 0 | 최대 가속 유지 |
+//! | 3 | -J | 가속도 감소 |
+//! | 4 | 0 | 등속 순항 |
+//! | 5 | -J | 감속도 증가 |
+//! | 6 | 0 | 최대 감속 유지 |
+//! | 7 | +J | 감속도 감소 |
+//!
+//! 거리가 짧아 최대 속도에 도달하지 못하면 피크 속도를 이분 탐색으로 낮추고,
+//! 최대 가속에 도달하지 못하면 2·6구간이 사라진다.
+//!
+//! PPR 매핑: AI_make_SCurveProfile
+
+use super::KinematicsParams;
+use sap_core::types::{Acceleration, Position, Trajectory, TrajectoryPoint, Velocity};
+
+/// 1차원 운동 상태 (경로 길이 기준)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PathState {
+    /// 경로 위치 (m)
+    pub position: f32,
+    /// 속력 (m/s)
+    pub velocity: f32,
+    /// 가속도 (m/s²)
+    pub acceleration: f32,
+}
+
+/// 일정 저크 구간
+#[derive(Debug, Clone, Copy, Default)]
+struct JerkSegment {
+    /// 구간 시간 (초)
+    duration: f32,
+    /// 저크 (m/s³)
+    jerk: f32,
+    /// 구간 시작 상태
+    start: PathState,
+}
+
+impl JerkSegment {
+    fn state_at(&self, tau: f32) -> PathState {
+        let s = &self.start;
+        let j = self.jerk;
+        PathState {
+            position: s.position
+                + s.velocity * tau
+                + 0.5 * s.acceleration * tau * tau
+                + j * tau * tau * tau / 6.0,
+            velocity: s.velocity + s.acceleration * tau + 0.5 * j * tau * tau,
+            acceleration: s.acceleration + j * tau,
+        }
+    }
+}
+
+/// 7구간 S-커브 프로파일
+#[derive(Debug, Clone)]
+pub struct SCurveProfile {
+    segments: [JerkSegment; 7],
+    distance: f32,
+    peak_velocity: f32,
+}
+
+impl SCurveProfile {
+    /// 피크 속도 탐색 반복 횟수
+    const BISECTION_ITERATIONS: u32 = 48;
+
+    /// 정지 → 정지 프로파일 계획
+    ///
+    /// 파라미터의 속도/가속/감속/저크 중 하나라도 0 이하면 None
+    pub fn plan(distance: f32, params: &KinematicsParams) -> Option<Self> {
+        let v_max = params.max_velocity;
+        let a_max = params.max_acceleration;
+        let d_max = params.max_deceleration;
+        let j_max = params.max_jerk;
+        if v_max <= 0.0 || a_max <= 0.0 || d_max <= 0.0 || j_max <= 0.0 || distance < 0.0 {
+            return None;
+        }
+
+        let ramp_distance =
+            |v: f32| ramp_time(v, a_max, j_max) * v * 0.5 + ramp_time(v, d_max, j_max) * v * 0.5;
+
+        let peak = if ramp_distance(v_max) <= distance {
+            v_max
+        } else {
+            let (mut lo, mut hi) = (0.0f32, v_max);
+            for _ in 0..Self::BISECTION_ITERATIONS {
+                let mid = 0.5 * (lo + hi);
+                if ramp_distance(mid) <= distance {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        };
+
+        let cruise_time = if peak > 0.0 {
+            ((distance - ramp_distance(peak)) / peak).max(0.0)
+        } else {
+            0.0
+        };
+
+        let (acc_jerk_time, acc_const_time) = ramp_phases(peak, a_max, j_max);
+        let (dec_jerk_time, dec_const_time) = ramp_phases(peak, d_max, j_max);
+
+        let plan = [
+            (acc_jerk_time, j_max),
+            (acc_const_time, 0.0),
+            (acc_jerk_time, -j_max),
+            (cruise_time, 0.0),
+            (dec_jerk_time, -j_max),
+            (dec_const_time, 0.0),
+            (dec_jerk_time, j_max),
+        ];
+
+        let mut segments = [JerkSegment::default(); 7];
+        let mut state = PathState::default();
+        for (segment, &(duration, jerk)) in segments.iter_mut().zip(plan.iter()) {
+            *segment = JerkSegment {
+                duration,
+                jerk,
+                start: state,
+            };
+            state = segment.state_at(duration);
+        }
+
+        Some(Self {
+            segments,
+            distance,
+            peak_velocity: peak,
+        })
+    }
+
+    /// 총 소요 시간 (초)
+    pub fn duration(&self) -> f32 {
+        self.segments.iter().map(|s| s.duration).sum()
+    }
+
+    /// 이동 거리 (m)
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    /// 피크 속력 (m/s)
+    pub fn peak_velocity(&self) -> f32 {
+        self.peak_velocity
+    }
+
+    /// 특정 시각(초)의 경로 상태
+    ///
+    /// 종료 이후는 목표 지점 정지 상태
+    pub fn state_at(&self, t: f32) -> PathState {
+        let mut elapsed = 0.0;
+        for segment in &self.segments {
+            if t < elapsed + segment.duration {
+                let mut state = segment.state_at((t - elapsed).max(0.0));
+                state.position = state.position.clamp(0.0, self.distance);
+                return state;
+            }
+            elapsed += segment.duration;
+        }
+        PathState {
+            position: self.distance,
+            velocity: 0.0,
+            acceleration: 0.0,
+        }
+    }
+}
+
+/// 0 → v 램프 시간 (대칭 저크 제한)
+fn ramp_time(v: f32, a: f32, j: f32) -> f32 {
+    let (jerk_time, const_time) = ramp_phases(v, a, j);
+    2.0 * jerk_time + const_time
+}
+
+/// 램프 구간 시간 분해: (저크 구간 시간, 등가속 구간 시간)
+fn ramp_phases(v: f32, a: f32, j: f32) -> (f32, f32) {
+    if v * j >= a * a {
+        // 최대 가속 도달
+        let jerk_time = a / j;
+        (jerk_time, v / a - jerk_time)
+    } else {
+        ((v / j).sqrt(), 0.0)
+    }
+}
+
+/// 구간 소요 시각
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegTiming {
+    /// 출발 지점
+    pub from: Position,
+    /// 도착 지점
+    pub to: Position,
+    /// 출발 시각 (나노초)
+    pub start_ns: u64,
+    /// 도착 시각 (나노초)
+    pub arrival_ns: u64,
+}
+
+/// 계획된 이동
+#[derive(Debug, Clone)]
+pub struct PlannedMotion {
+    /// 샘플링된 궤적
+    pub trajectory: Trajectory,
+    /// 최종 도착 시각 (나노초)
+    pub arrival_ns: u64,
+    /// 구간별 시각 (직선 구간 단위, 각 구간 끝에서 정지)
+    pub legs: Vec<LegTiming>,
+}
+
+/// S-커브 궤적 생성기
+///
+/// PPR: AI_make_SCurveProfile(waypoints, params) -> PlannedMotion
+#[derive(Debug, Clone)]
+pub struct SCurveGenerator {
+    params: KinematicsParams,
+    sample_interval_ns: u64,
+    collinear_tolerance_rad: f32,
+}
+
+impl SCurveGenerator {
+    /// 새 SCurveGenerator 생성 (기본 샘플 간격 50ms)
+    pub fn new(params: KinematicsParams) -> Self {
+        Self {
+            params,
+            sample_interval_ns: 50_000_000,
+            collinear_tolerance_rad: 0.01,
+        }
+    }
+
+    /// 샘플 간격 설정 (나노초)
+    pub fn with_sample_interval(mut self, interval_ns: u64) -> Self {
+        self.sample_interval_ns = interval_ns.max(1);
+        self
+    }
+
+    /// 직선으로 간주할 최대 방향 변화 설정 (라디안)
+    pub fn with_collinear_tolerance(mut self, tolerance_rad: f32) -> Self {
+        self.collinear_tolerance_rad = tolerance_rad;
+        self
+    }
+
+    /// 운동학 파라미터 조회
+    pub fn params(&self) -> &KinematicsParams {
+        &self.params
+    }
+
+    /// 지점 간 이동 계획
+    pub fn point_to_point(
+        &self,
+        robot_id: u64,
+        start: Position,
+        goal: Position,
+        start_ns: u64,
+    ) -> Option<PlannedMotion> {
+        self.through_waypoints(robot_id, &[start, goal], start_ns)
+    }
+
+    /// 다중 경유지 이동 계획
+    ///
+    /// 일직선상의 연속 경유지는 하나의 구간으로 합치고,
+    /// 방향이 바뀌는 경유지에서는 정지 후 다음 구간을 시작한다.
+    pub fn through_waypoints(
+        &self,
+        robot_id: u64,
+        waypoints: &[Position],
+        start_ns: u64,
+    ) -> Option<PlannedMotion> {
+        let first = *waypoints.first()?;
+        let corners = self.merge_collinear(waypoints);
+
+        let mut trajectory = Trajectory::new(robot_id);
+        trajectory.push(TrajectoryPoint::new(start_ns, first));
+        let mut legs = Vec::with_capacity(corners.len().saturating_sub(1));
+        let mut leg_start_ns = start_ns;
+
+        for pair in corners.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            let delta = to - from;
+            let length = delta.magnitude();
+            let profile = SCurveProfile::plan(length, &self.params)?;
+            let direction = delta.scale(1.0 / length);
+            let theta = direction.y.atan2(direction.x);
+            let duration_ns = (profile.duration() as f64 * 1e9).ceil() as u64;
+
+            let mut offset_ns = self.sample_interval_ns;
+            while offset_ns < duration_ns {
+                let state = profile.state_at(offset_ns as f32 / 1e9);
+                trajectory.push(path_sample(
+                    leg_start_ns + offset_ns,
+                    from,
+                    direction,
+                    theta,
+                    &state,
+                ));
+                offset_ns += self.sample_interval_ns;
+            }
+
+            let arrival_ns = leg_start_ns + duration_ns.max(1);
+            trajectory.push(TrajectoryPoint::new(arrival_ns, to).with_theta(theta));
+            legs.push(LegTiming {
+                from,
+                to,
+                start_ns: leg_start_ns,
+                arrival_ns,
+            });
+            leg_start_ns = arrival_ns;
+        }
+
+        Some(PlannedMotion {
+            trajectory,
+            arrival_ns: leg_start_ns,
+            legs,
+        })
+    }
+
+    /// 중복 지점 제거 및 일직선 경유지 병합
+    fn merge_collinear(&self, waypoints: &[Position]) -> Vec<Position> {
+        const MIN_LEG: f32 = 1e-4;
+        let mut corners: Vec<Position> = Vec::with_capacity(waypoints.len());
+        for &point in waypoints {
+            if let Some(last) = corners.last() {
+                if point.distance(last) < MIN_LEG {
+                    continue;
+                }
+            }
+            if corners.len() >= 2 {
+                let a = corners[corners.len() - 2];
+                let b = corners[corners.len() - 1];
+                let (d1, d2) = (b - a, point - b);
+                let cos =
+                    (d1.x * d2.x + d1.y * d2.y + d1.z * d2.z) / (d1.magnitude() * d2.magnitude());
+                if cos.clamp(-1.0, 1.0).acos() <= self.collinear_tolerance_rad {
+                    corners.pop();
+                }
+            }
+            corners.push(point);
+        }
+        corners
+    }
+}
+
+fn path_sample(
+    timestamp_ns: u64,
+    from: Position,
+    direction: Position,
+    theta: f32,
+    state: &PathState,
+) -> TrajectoryPoint {
+    TrajectoryPoint::new(timestamp_ns, from + direction.scale(state.position))
+        .with_theta(theta)
+        .with_velocity(Velocity::new(
+            direction.x * state.velocity,
+            direction.y * state.velocity,
+            direction.z * state.velocity,
+        ))
+        .with_acceleration(Acceleration::new(
+            direction.x * state.acceleration,
+            direction.y * state.acceleration,
+            direction.z * state.acceleration,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> KinematicsParams {
+        KinematicsParams::default() // v=2, a=1.5, d=3, j=10
+    }
+
+    #[test]
+    fn test_scurve_long_move_reaches_max_velocity() {
+        let profile = SCurveProfile::plan(20.0, &params()).unwrap();
+        assert!((profile.peak_velocity() - 2.0).abs() < 1e-4);
+
+        let end = profile.state_at(profile.duration());
+        assert!((end.position - 20.0).abs() < 1e-3);
+        assert_eq!(end.velocity, 0.0);
+
+        // 순항 구간 중간
+        let mid = profile.state_at(profile.duration() * 0.5);
+        assert!((mid.velocity - 2.0).abs() < 1e-3);
+        assert!(mid.acceleration.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_scurve_respects_limits() {
+        let p = params();
+        let profile = SCurveProfile::plan(5.0, &p).unwrap();
+        let steps = 1000;
+        let dt = profile.duration() / steps as f32;
+        let mut prev = profile.state_at(0.0);
+        for i in 1..=steps {
+            let state = profile.state_at(i as f32 * dt);
+            assert!(state.velocity <= p.max_velocity + 1e-3);
+            assert!(state.acceleration <= p.max_acceleration + 1e-3);
+            assert!(state.acceleration >= -p.max_deceleration - 1e-3);
+            let jerk = (state.acceleration - prev.acceleration).abs() / dt;
+            assert!(jerk <= p.max_jerk * 1.01 + 1e-2, "jerk {} at {}", jerk, i);
+            assert!(state.position + 1e-4 >= prev.position);
+            prev = state;
+        }
+        assert!((prev.position - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_scurve_short_move_reduces_peak() {
+        let profile = SCurveProfile::plan(0.2, &params()).unwrap();
+        assert!(profile.peak_velocity() < 2.0);
+        let end = profile.state_at(profile.duration() + 1.0);
+        assert!((end.position - 0.2).abs() < 1e-6);
+
+        assert!(SCurveProfile::plan(
+            1.0,
+            &KinematicsParams {
+                max_jerk: 0.0,
+                ..params()
+            }
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_point_to_point_trajectory() {
+        let generator = SCurveGenerator::new(params());
+        let goal = Position::new(10.0, 0.0, 0.0);
+        let motion = generator
+            .point_to_point(3, Position::ORIGIN, goal, 1_000_000_000)
+            .unwrap();
+
+        let profile = SCurveProfile::plan(10.0, &params()).unwrap();
+        let expected_ns = 1_000_000_000 + (profile.duration() as f64 * 1e9).ceil() as u64;
+        assert_eq!(motion.arrival_ns, expected_ns);
+        assert_eq!(motion.trajectory.end_ns(), Some(expected_ns));
+        assert_eq!(motion.trajectory.robot_id, 3);
+        assert_eq!(motion.trajectory.points().last().unwrap().position, goal);
+        assert_eq!(motion.legs.len(), 1);
+    }
+
+    #[test]
+    fn test_waypoints_merge_collinear_and_stop_at_corners() {
+        let generator = SCurveGenerator::new(params());
+        let waypoints = [
+            Position::ORIGIN,
+            Position::new(2.0, 0.0, 0.0),
+            Position::new(4.0, 0.0, 0.0), // 일직선 → 병합
+            Position::new(4.0, 3.0, 0.0), // 코너
+        ];
+        let motion = generator.through_waypoints(1, &waypoints, 0).unwrap();
+
+        assert_eq!(motion.legs.len(), 2);
+        assert_eq!(motion.legs[0].to, Position::new(4.0, 0.0, 0.0));
+        assert_eq!(motion.legs[1].start_ns, motion.legs[0].arrival_ns);
+        assert_eq!(motion.arrival_ns, motion.legs[1].arrival_ns);
+
+        // 코너 도착 시점에 정지
+        let corner = motion
+            .trajectory
+            .points()
+            .iter()
+            .find(|p| p.timestamp_ns == motion.legs[0].arrival_ns)
+            .unwrap();
+        assert_eq!(corner.velocity, Velocity::ZERO);
+    }
+
+    #[test]
+    fn test_planned_motion_fits_occupancy_windows() {
+        use crate::validator::{TrajectoryContext, TrajectoryValidator, TrajectoryValidatorConfig};
+        use sap_core::ticket::{TransitTicket, VoxelGrid};
+
+        let generator = SCurveGenerator::new(params());
+        let waypoints = [
+            Position::new(0.5, 0.5, 0.5),
+            Position::new(6.5, 0.5, 0.5),
+            Position::new(6.5, 4.5, 0.5),
+        ];
+        let motion = generator.through_waypoints(1, &waypoints, 0).unwrap();
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [10, 10, 1]);
+        let ticket =
+            TransitTicket::new(1, 1, 1).with_vts(grid.occupancy_windows(&motion.trajectory, 0));
+
+        let validator = TrajectoryValidator::new(TrajectoryValidatorConfig {
+            limits: crate::validator::PhysicsValidatorConfig {
+                max_velocity: params().max_velocity,
+                max_acceleration: params().max_deceleration,
+                // 한계값 그대로 계획하므로 부동소수점 여유 1%
+                max_jerk: params().max_jerk * 1.01,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_voxel_grid(grid);
+        let result = validator.validate(
+            &motion.trajectory,
+            &TrajectoryContext::new().with_ticket(&ticket),
+        );
+        assert!(result.is_valid(), "{:?}", result.first_violation);
+    }
+}
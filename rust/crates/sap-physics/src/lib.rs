@@ -13,10 +13,20 @@
 //! ## PPR 매핑
 //!
 //! - `AI_make_PhysicsValidator` → `PhysicsValidator::validate()`
+//! - `AI_make_BatchValidation` → `PhysicsValidator::validate_batch()`
 //! - `AI_process_KinematicsCheck` → `KinematicsChecker::check()`
 //! - `AI_process_CollisionPrediction` → `CollisionPredictor::predict()`
+//! - `AI_process_SpatialIndex` → `SpatialIndex::query_radius()`
+//! - `AI_process_SpeedSeparation` → `SpeedSeparationMonitor::check()`
+//! - `AI_process_UncertainCollision` → `UncertainCollisionChecker::evaluate()`
+//! - `AI_process_SafetyFilter` → `SafetyFilter::filter()`
+//! - `AI_process_TicketValidity` → `TicketConstraint::check()`
+//! - `AI_process_TrajectoryValidation` → `TrajectoryValidator::validate()`
+//! - `AI_process_ProofAccumulation` → `ProofAccumulator::push()`
 //! - `AI_response_PhysicalRecovery` → `RecoveryCommand`
+//! - `AI_process_RecoveryExecution` → `RecoveryExecutor::tick()`
 //! - `AI_make_VehicleProfile` → `VehicleProfile`
+//! - `AI_make_SCurveProfile` → `SCurveGenerator`
 
 pub mod command;
 pub mod constraint;
@@ -25,8 +35,17 @@ pub mod recovery;
 pub mod validator;
 
 // 주요 타입 re-export
-pub use command::{CommandGate, MotionCommand};
-pub use constraint::{CollisionPredictor, KinematicsChecker};
-pub use kinematics::{KinematicsParams, VehicleProfile, VehicleType};
-pub use recovery::{RecoveryCommand, RecoveryLevel, RecoveryResult};
-pub use validator::{PhysicsValidator, PhysicsValidatorConfig};
+pub use command::{CommandGate, CommandPolicy, GateResult, MotionCommand, PolicyContext};
+pub use constraint::{
+    CollisionPredictor, FilterState, Geofence, GeofenceKind, KinematicsChecker, SafetyFilter,
+    SafetyFilterConfig, SeparationParty, SpatialIndex, SpeedSeparationMonitor, TicketConstraint,
+    UncertainCollisionChecker,
+};
+pub use kinematics::{
+    KinematicsParams, PlannedMotion, SCurveGenerator, SCurveProfile, VehicleProfile, VehicleType,
+};
+pub use recovery::{RecoveryCommand, RecoveryExecutor, RecoveryLevel, RecoveryResult};
+pub use validator::{
+    AppliedLimits, BatchResults, CommandBatch, PhysicsValidator, PhysicsValidatorConfig,
+    ProofAccumulator, TrajectoryValidator, ValidationReport,
+};
@@ -34,6 +34,16 @@ impl RecoveryLevel {
     pub fn is_emergency(self) -> bool {
         matches!(self, Self::EmergencyStop)
     }
+
+    /// 한 단계 더 긴급한 수준 (EmergencyStop이면 None)
+    pub fn escalate(self) -> Option<Self> {
+        match self {
+            Self::PathReplanning => Some(Self::SafeHold),
+            Self::SafeHold => Some(Self::SafeDeceleration),
+            Self::SafeDeceleration => Some(Self::EmergencyStop),
+            Self::EmergencyStop => None,
+        }
+    }
 }
 
 /// 물리적 복구 명령
@@ -169,6 +179,12 @@ pub struct RecoveryResult {
 
     /// 에러 메시지 (실패 시)
     pub error_message: Option<String>,
+
+    /// 종료 시점의 복구 수준 (실행기가 채움)
+    pub final_level: Option<RecoveryLevel>,
+
+    /// 상향(escalation) 횟수
+    pub escalation_count: u32,
 }
 
 impl RecoveryResult {
@@ -180,6 +196,8 @@ impl RecoveryResult {
             final_position,
             duration_ns,
             error_message: None,
+            final_level: None,
+            escalation_count: 0,
         }
     }
 
@@ -191,8 +209,23 @@ impl RecoveryResult {
             final_position: position,
             duration_ns: 0,
             error_message: Some(error.into()),
+            final_level: None,
+            escalation_count: 0,
         }
     }
+
+    /// 소요 시간 설정
+    pub fn with_duration(mut self, duration_ns: u64) -> Self {
+        self.duration_ns = duration_ns;
+        self
+    }
+
+    /// 종료 수준 및 상향 횟수 설정
+    pub fn with_level(mut self, level: RecoveryLevel, escalation_count: u32) -> Self {
+        self.final_level = Some(level);
+        self.escalation_count = escalation_count;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -236,6 +269,19 @@ mod tests {
         assert_eq!(RecoveryLevel::PathReplanning.priority(), 3);
     }
 
+    #[test]
+    fn test_recovery_level_escalate() {
+        assert_eq!(
+            RecoveryLevel::PathReplanning.escalate(),
+            Some(RecoveryLevel::SafeHold)
+        );
+        assert_eq!(
+            RecoveryLevel::SafeDeceleration.escalate(),
+            Some(RecoveryLevel::EmergencyStop)
+        );
+        assert_eq!(RecoveryLevel::EmergencyStop.escalate(), None);
+    }
+
     #[test]
     fn test_recovery_result_success() {
         let result = RecoveryResult::success(42, Position::new(1.0, 2.0, 0.0), 500_000_000);
@@ -0,0 +1,513 @@
+//! 복구 실행기 - 복구 명령의 시간축 실행 및 상향
+//!
+//! 매 틱마다 감속 설정값을 생성하고 로봇이 보고한 상태를 감시한다.
+//! 로봇이 예상대로 감속하지 못하면 더 긴급한 수준으로 상향한다.
+//!
+//! ```text
+//! PathReplanning → SafeHold → SafeDeceleration → EmergencyStop → 실패
+//! ```
+//!
+//! PPR 매핑: AI_process_RecoveryExecution
+
+use super::{RecoveryCommand, RecoveryLevel, RecoveryResult};
+use sap_core::types::{Position, RobotState, Velocity};
+
+/// 복구 실행기 설정
+#[derive(Debug, Clone)]
+pub struct RecoveryExecutorConfig {
+    /// 제어 틱 주기 (나노초) - 설정값은 다음 틱 도달 목표
+    pub tick_interval_ns: u64,
+
+    /// 예상 속력 대비 허용 초과량 (m/s)
+    pub speed_tolerance: f32,
+
+    /// 상향 전 허용 연속 위반 틱 수
+    pub escalation_grace_ticks: u32,
+
+    /// 정지로 간주하는 속력 (m/s)
+    pub stop_speed_threshold: f32,
+
+    /// 완료 판정에 필요한 연속 정지 틱 수
+    pub settle_ticks: u32,
+
+    /// SafeHold 위치 허용 오차 (m)
+    pub hold_position_tolerance: f32,
+
+    /// SafeHold 상향 시 최소 감속도 (m/s²)
+    pub hold_deceleration: f32,
+
+    /// SafeDeceleration 상향 시 최소 감속도 (m/s²)
+    pub safe_deceleration: f32,
+
+    /// EmergencyStop 상향 시 최소 감속도 (m/s²)
+    pub emergency_deceleration: f32,
+}
+
+impl Default for RecoveryExecutorConfig {
+    fn default() -> Self {
+        Self {
+            tick_interval_ns: 100_000_000, // 10Hz
+            speed_tolerance: 0.2,
+            escalation_grace_ticks: 3,
+            stop_speed_threshold: 0.05,
+            settle_ticks: 3,
+            hold_position_tolerance: 0.2,
+            hold_deceleration: 2.0,
+            safe_deceleration: 3.0,
+            emergency_deceleration: 6.0,
+        }
+    }
+}
+
+/// 실행기 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutorPhase {
+    /// 대기 (복구 없음)
+    Idle,
+    /// 복구 진행 중
+    Running,
+    /// 정지 완료 (재개 대기)
+    Completed,
+    /// 비상 정지 실패
+    Failed,
+}
+
+/// 틱별 감속 설정값
+#[derive(Debug, Clone)]
+pub struct RecoverySetpoint {
+    /// 대상 로봇 ID
+    pub robot_id: u64,
+
+    /// 현재 복구 수준
+    pub level: RecoveryLevel,
+
+    /// 목표 속도
+    pub target_velocity: Velocity,
+
+    /// 적용 감속도 (m/s²)
+    pub deceleration: f32,
+
+    /// 유지 위치 (SafeHold)
+    pub hold_position: Option<Position>,
+
+    /// 타임스탬프 (나노초)
+    pub timestamp_ns: u64,
+}
+
+/// 틱 처리 결과
+#[derive(Debug, Clone)]
+pub enum RecoveryStep {
+    /// 실행 중인 복구 없음
+    Idle,
+    /// 로봇에 전달할 설정값
+    Setpoint(RecoverySetpoint),
+    /// 복구 종료 (이번 틱에서 한 번만 반환)
+    Finished(RecoveryResult),
+}
+
+/// 복구 실행기
+///
+/// PPR: AI_process_RecoveryExecution(cmd, robot_state) -> RecoveryStep
+#[derive(Debug, Clone)]
+pub struct RecoveryExecutor {
+    config: RecoveryExecutorConfig,
+    phase: ExecutorPhase,
+    command: Option<RecoveryCommand>,
+    level: RecoveryLevel,
+    deceleration: f32,
+    target_speed: f32,
+    direction: Velocity,
+    hold_position: Option<Position>,
+    allow_resume: bool,
+    started_ns: u64,
+    level_started_ns: u64,
+    level_start_speed: f32,
+    violation_ticks: u32,
+    settled_ticks: u32,
+    escalation_count: u32,
+    last_position: Position,
+}
+
+impl RecoveryExecutor {
+    /// 새 RecoveryExecutor 생성
+    pub fn new(config: RecoveryExecutorConfig) -> Self {
+        Self {
+            config,
+            phase: ExecutorPhase::Idle,
+            command: None,
+            level: RecoveryLevel::SafeDeceleration,
+            deceleration: 0.0,
+            target_speed: 0.0,
+            direction: Velocity::ZERO,
+            hold_position: None,
+            allow_resume: true,
+            started_ns: 0,
+            level_started_ns: 0,
+            level_start_speed: 0.0,
+            violation_ticks: 0,
+            settled_ticks: 0,
+            escalation_count: 0,
+            last_position: Position::ORIGIN,
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(RecoveryExecutorConfig::default())
+    }
+
+    /// 복구 시작 (진행 중인 복구는 대체됨)
+    pub fn start(&mut self, command: RecoveryCommand, state: &RobotState) {
+        self.level = command.level;
+        self.deceleration = command.max_deceleration;
+        self.target_speed = if command.level == RecoveryLevel::PathReplanning {
+            command.target_velocity.magnitude()
+        } else {
+            0.0
+        };
+        self.hold_position = match command.level {
+            RecoveryLevel::SafeHold => command.target_position,
+            _ => None,
+        };
+        self.allow_resume = command.allow_resume;
+        self.started_ns = state.timestamp_ns;
+        self.escalation_count = 0;
+        self.command = Some(command);
+        self.phase = ExecutorPhase::Running;
+        self.begin_level(state);
+    }
+
+    /// 현재 단계
+    pub fn phase(&self) -> ExecutorPhase {
+        self.phase
+    }
+
+    /// 현재 복구 수준
+    pub fn level(&self) -> RecoveryLevel {
+        self.level
+    }
+
+    /// 재개 허용 여부 (비상 정지로 상향되면 false)
+    pub fn allow_resume(&self) -> bool {
+        self.allow_resume
+    }
+
+    /// 틱 처리
+    ///
+    /// 로봇이 보고한 상태로 감속 준수 여부를 판정하고 다음 설정값을 반환한다.
+    /// 완료/실패 이후에는 재개 또는 리셋 전까지 정지 유지 설정값을 반환한다.
+    pub fn tick(&mut self, state: &RobotState) -> RecoveryStep {
+        match self.phase {
+            ExecutorPhase::Idle => return RecoveryStep::Idle,
+            ExecutorPhase::Completed | ExecutorPhase::Failed => {
+                return RecoveryStep::Setpoint(self.hold_setpoint(state));
+            }
+            ExecutorPhase::Running => {}
+        }
+
+        self.last_position = state.position;
+        let now = state.timestamp_ns;
+        let speed = state.velocity.magnitude();
+        let expected = self.expected_speed(now);
+
+        let hold_drift = match self.hold_position {
+            Some(hold) if speed <= self.config.stop_speed_threshold => {
+                state.position.distance(&hold) > self.config.hold_position_tolerance
+            }
+            _ => false,
+        };
+
+        if speed > expected + self.config.speed_tolerance || hold_drift {
+            self.violation_ticks += 1;
+            if self.violation_ticks > self.config.escalation_grace_ticks {
+                match self.level.escalate() {
+                    Some(next) => self.escalate(next, state),
+                    None => return self.finish_failure(state, "Emergency stop not achieved"),
+                }
+            }
+        } else {
+            self.violation_ticks = 0;
+        }
+
+        if self.level == RecoveryLevel::PathReplanning {
+            if speed <= self.target_speed + self.config.speed_tolerance {
+                return self.finish_success(state);
+            }
+        } else if speed <= self.config.stop_speed_threshold && !hold_drift {
+            self.settled_ticks += 1;
+            if self.settled_ticks >= self.config.settle_ticks {
+                return self.finish_success(state);
+            }
+        } else {
+            self.settled_ticks = 0;
+        }
+
+        let setpoint_speed = self.expected_speed(now + self.config.tick_interval_ns);
+        RecoveryStep::Setpoint(RecoverySetpoint {
+            robot_id: state.robot_id,
+            level: self.level,
+            target_velocity: self.direction.scale(setpoint_speed),
+            deceleration: self.deceleration,
+            hold_position: self.hold_position,
+            timestamp_ns: now,
+        })
+    }
+
+    /// 재개 (완료 상태 + 재개 허용 시에만)
+    pub fn resume(&mut self) -> bool {
+        if self.phase == ExecutorPhase::Completed && self.allow_resume {
+            self.reset();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 강제 리셋 (운영자 개입)
+    pub fn reset(&mut self) {
+        self.phase = ExecutorPhase::Idle;
+        self.command = None;
+        self.violation_ticks = 0;
+        self.settled_ticks = 0;
+    }
+
+    fn expected_speed(&self, now_ns: u64) -> f32 {
+        let elapsed = now_ns.saturating_sub(self.level_started_ns) as f32 / 1_000_000_000.0;
+        (self.level_start_speed - self.deceleration * elapsed).max(self.target_speed)
+    }
+
+    fn begin_level(&mut self, state: &RobotState) {
+        let speed = state.velocity.magnitude();
+        self.level_started_ns = state.timestamp_ns;
+        self.level_start_speed = speed;
+        self.direction = if speed > f32::EPSILON {
+            state.velocity.scale(1.0 / speed)
+        } else {
+            Velocity::ZERO
+        };
+        self.violation_ticks = 0;
+        self.settled_ticks = 0;
+    }
+
+    fn escalate(&mut self, next: RecoveryLevel, state: &RobotState) {
+        let level_decel = match next {
+            RecoveryLevel::SafeHold => self.config.hold_deceleration,
+            RecoveryLevel::SafeDeceleration => self.config.safe_deceleration,
+            RecoveryLevel::EmergencyStop => self.config.emergency_deceleration,
+            RecoveryLevel::PathReplanning => self.deceleration,
+        };
+        self.level = next;
+        self.deceleration = self.deceleration.max(level_decel);
+        self.target_speed = 0.0;
+        // 원래 유지 위치는 이미 실패 - 해제하고 완료 후에는 실제 정지 지점에서 유지
+        self.hold_position = None;
+        if next.is_emergency() {
+            self.allow_resume = false;
+        }
+        self.escalation_count += 1;
+        self.begin_level(state);
+    }
+
+    fn finish_success(&mut self, state: &RobotState) -> RecoveryStep {
+        self.phase = ExecutorPhase::Completed;
+        RecoveryStep::Finished(
+            RecoveryResult::success(
+                state.robot_id,
+                state.position,
+                state.timestamp_ns.saturating_sub(self.started_ns),
+            )
+            .with_level(self.level, self.escalation_count),
+        )
+    }
+
+    fn finish_failure(&mut self, state: &RobotState, message: &str) -> RecoveryStep {
+        self.phase = ExecutorPhase::Failed;
+        self.allow_resume = false;
+        RecoveryStep::Finished(
+            RecoveryResult::failure(state.robot_id, state.position, message)
+                .with_duration(state.timestamp_ns.saturating_sub(self.started_ns))
+                .with_level(self.level, self.escalation_count),
+        )
+    }
+
+    fn hold_setpoint(&self, state: &RobotState) -> RecoverySetpoint {
+        RecoverySetpoint {
+            robot_id: state.robot_id,
+            level: self.level,
+            target_velocity: Velocity::ZERO,
+            deceleration: self.deceleration,
+            hold_position: Some(self.hold_position.unwrap_or(self.last_position)),
+            timestamp_ns: state.timestamp_ns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TICK_NS: u64 = 100_000_000; // 10Hz
+
+    fn moving_state(speed: f32, timestamp_ns: u64) -> RobotState {
+        let mut state = RobotState::new(1);
+        state.velocity = Velocity::new(speed, 0.0, 0.0);
+        state.timestamp_ns = timestamp_ns;
+        state
+    }
+
+    /// 설정값을 그대로 따르는 로봇으로 실행
+    fn run_compliant(executor: &mut RecoveryExecutor, mut state: RobotState) -> RecoveryResult {
+        for _ in 0..200 {
+            match executor.tick(&state) {
+                RecoveryStep::Finished(result) => return result,
+                RecoveryStep::Setpoint(sp) => {
+                    let dt = TICK_NS as f32 / 1e9;
+                    state.position = state.position
+                        + Position::new(
+                            sp.target_velocity.vx * dt,
+                            sp.target_velocity.vy * dt,
+                            0.0,
+                        );
+                    state.velocity = sp.target_velocity;
+                    state.timestamp_ns += TICK_NS;
+                }
+                RecoveryStep::Idle => panic!("executor idle"),
+            }
+        }
+        panic!("recovery did not finish");
+    }
+
+    #[test]
+    fn test_compliant_deceleration_completes() {
+        let mut executor = RecoveryExecutor::with_default_config();
+        let state = moving_state(2.0, 1_000_000_000);
+        executor.start(
+            RecoveryCommand::safe_deceleration(1, 2.0, 1_000_000_000),
+            &state,
+        );
+
+        let result = run_compliant(&mut executor, state);
+        assert!(result.success);
+        assert_eq!(result.escalation_count, 0);
+        assert_eq!(result.final_level, Some(RecoveryLevel::SafeDeceleration));
+        // 2 m/s → 0, 2 m/s² → 약 1초 + 정지 확인 틱
+        assert!(result.duration_ns >= 1_000_000_000);
+        assert!(result.duration_ns <= 1_500_000_000);
+        // 정지 거리 ≈ v²/2a = 1m
+        assert!((result.final_position.x - 1.0).abs() < 0.2);
+        assert_eq!(executor.phase(), ExecutorPhase::Completed);
+    }
+
+    #[test]
+    fn test_non_compliant_robot_escalates_to_failure() {
+        let mut executor = RecoveryExecutor::with_default_config();
+        let mut state = moving_state(1.5, 0);
+        executor.start(
+            RecoveryCommand::path_replanning(1, Position::new(5.0, 0.0, 0.0), 0),
+            &state,
+        );
+
+        let mut levels = vec![executor.level()];
+        let result = loop {
+            state.timestamp_ns += TICK_NS;
+            match executor.tick(&state) {
+                RecoveryStep::Finished(result) => break result,
+                RecoveryStep::Setpoint(sp) => {
+                    if levels.last() != Some(&sp.level) {
+                        levels.push(sp.level);
+                    }
+                }
+                RecoveryStep::Idle => unreachable!(),
+            }
+        };
+
+        assert_eq!(
+            levels,
+            vec![
+                RecoveryLevel::PathReplanning,
+                RecoveryLevel::SafeHold,
+                RecoveryLevel::SafeDeceleration,
+                RecoveryLevel::EmergencyStop,
+            ]
+        );
+        assert!(!result.success);
+        assert_eq!(result.escalation_count, 3);
+        assert_eq!(result.final_level, Some(RecoveryLevel::EmergencyStop));
+        assert!(result.duration_ns > 0);
+        assert_eq!(executor.phase(), ExecutorPhase::Failed);
+        assert!(!executor.resume());
+    }
+
+    #[test]
+    fn test_escalation_to_emergency_disables_resume() {
+        let config = RecoveryExecutorConfig {
+            escalation_grace_ticks: 0,
+            ..Default::default()
+        };
+        let mut executor = RecoveryExecutor::new(config);
+        let mut state = moving_state(2.0, 0);
+        executor.start(RecoveryCommand::safe_deceleration(1, 2.0, 0), &state);
+
+        // 한 틱 불이행 → EmergencyStop
+        state.timestamp_ns += 5 * TICK_NS;
+        executor.tick(&state);
+        assert_eq!(executor.level(), RecoveryLevel::EmergencyStop);
+        assert!(!executor.allow_resume());
+
+        // 이후 정상 정지
+        let result = run_compliant(&mut executor, state);
+        assert!(result.success);
+        assert!(!executor.resume());
+        assert_eq!(executor.phase(), ExecutorPhase::Completed);
+
+        // 완료 후에도 정지 유지
+        match executor.tick(&moving_state(0.0, 10 * TICK_NS)) {
+            RecoveryStep::Setpoint(sp) => assert_eq!(sp.target_velocity, Velocity::ZERO),
+            other => panic!("expected hold setpoint, got {:?}", other),
+        }
+        executor.reset();
+        assert_eq!(executor.phase(), ExecutorPhase::Idle);
+    }
+
+    #[test]
+    fn test_resume_honored() {
+        let mut executor = RecoveryExecutor::with_default_config();
+        let state = moving_state(0.5, 0);
+        executor.start(RecoveryCommand::safe_deceleration(1, 2.0, 0), &state);
+        assert!(!executor.resume()); // 진행 중에는 재개 불가
+
+        run_compliant(&mut executor, state);
+        assert!(executor.resume());
+        assert_eq!(executor.phase(), ExecutorPhase::Idle);
+        assert!(matches!(
+            executor.tick(&moving_state(0.0, 0)),
+            RecoveryStep::Idle
+        ));
+    }
+
+    #[test]
+    fn test_safe_hold_drift_escalates() {
+        let mut executor = RecoveryExecutor::with_default_config();
+        let mut state = moving_state(0.0, 0);
+        executor.start(RecoveryCommand::safe_hold(1, Position::ORIGIN, 0), &state);
+
+        // 정지했지만 유지 위치에서 밀려남
+        state.position = Position::new(0.5, 0.0, 0.0);
+        for _ in 0..4 {
+            state.timestamp_ns += TICK_NS;
+            executor.tick(&state);
+        }
+        assert_eq!(executor.level(), RecoveryLevel::SafeDeceleration);
+
+        // 상향 후에는 밀려난 지점에서 정지 완료 → 그 지점을 유지
+        for _ in 0..10 {
+            state.timestamp_ns += TICK_NS;
+            executor.tick(&state);
+        }
+        assert_eq!(executor.phase(), ExecutorPhase::Completed);
+        let RecoveryStep::Setpoint(setpoint) = executor.tick(&state) else {
+            panic!("expected hold setpoint");
+        };
+        assert_eq!(setpoint.hold_position, Some(state.position));
+    }
+}
@@ -13,8 +13,15 @@
 //! | L2 | SafeHold | 현재 위치 유지 |
 //! | L3 | PathReplanning | 새 경로로 재계획 |
 //!
-//! PPR 매핑: AI_response_PhysicalRecovery
+//! `RecoveryExecutor`는 명령을 틱별 감속 설정값으로 실행하고,
+//! 로봇이 따르지 못하면 L3 → L0 방향으로 상향합니다.
+//!
+//! PPR 매핑: AI_response_PhysicalRecovery, AI_process_RecoveryExecution
 
 mod command;
+mod executor;
 
 pub use command::{RecoveryCommand, RecoveryLevel, RecoveryResult};
+pub use executor::{
+    ExecutorPhase, RecoveryExecutor, RecoveryExecutorConfig, RecoverySetpoint, RecoveryStep,
+};
@@ -0,0 +1,425 @@
+//! 배치 검증 - 틱 단위 다수 명령의 SoA(structure-of-arrays) 검증 커널
+//!
+//! 한 틱의 모든 명령과 장애물을 성분별 연속 배열로 보관하고,
+//! 동역학/이격 거리 검사를 분기 없는 단순 루프로 수행해 자동 벡터화를 유도한다.
+//! 버퍼는 틱마다 `clear` 후 재사용하므로 워밍업 이후 할당이 없다.
+//!
+//! PPR 매핑: AI_make_BatchValidation
+
+use super::physics_validator::{CollisionResult, KinematicsResult};
+use super::AppliedLimits;
+use crate::command::MotionCommand;
+use crate::constraint::CollisionPredictor;
+use sap_core::{
+    types::{Acceleration, Position, Velocity},
+    validation::{constraint_ids, ValidationResult},
+};
+
+/// 배치 커널이 검사하는 제약조건 비트
+pub const BATCH_CONSTRAINTS_MASK: u64 = (1 << constraint_ids::VELOCITY_LIMIT)
+    | (1 << constraint_ids::ACCELERATION_LIMIT)
+    | (1 << constraint_ids::JERK_LIMIT)
+    | (1 << constraint_ids::COLLISION_PREDICTION)
+    | (1 << constraint_ids::ANGULAR_VELOCITY_LIMIT)
+    | (1 << constraint_ids::ANGULAR_ACCELERATION_LIMIT);
+
+/// 동역학 제약조건 비트 (위반 시 ADJUST)
+const KINEMATICS_MASK: u64 = (1 << constraint_ids::VELOCITY_LIMIT)
+    | (1 << constraint_ids::ACCELERATION_LIMIT)
+    | (1 << constraint_ids::ANGULAR_VELOCITY_LIMIT)
+    | (1 << constraint_ids::ANGULAR_ACCELERATION_LIMIT);
+
+/// 틱 단위 명령/장애물 배치 (SoA)
+#[derive(Debug, Clone, Default)]
+pub struct CommandBatch {
+    robot_ids: Vec<u64>,
+    ticket_ids: Vec<u128>,
+    px: Vec<f32>,
+    py: Vec<f32>,
+    pz: Vec<f32>,
+    vx: Vec<f32>,
+    vy: Vec<f32>,
+    vz: Vec<f32>,
+    ax: Vec<f32>,
+    ay: Vec<f32>,
+    az: Vec<f32>,
+    angular_velocity: Vec<f32>,
+    angular_acceleration: Vec<f32>,
+
+    ox: Vec<f32>,
+    oy: Vec<f32>,
+    oz: Vec<f32>,
+}
+
+impl CommandBatch {
+    /// 새 CommandBatch 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 명령 수 기준 용량 예약
+    pub fn with_capacity(commands: usize) -> Self {
+        let mut batch = Self::new();
+        batch.reserve(commands);
+        batch
+    }
+
+    /// 명령 용량 예약
+    pub fn reserve(&mut self, additional: usize) {
+        self.robot_ids.reserve(additional);
+        self.ticket_ids.reserve(additional);
+        for column in [
+            &mut self.px,
+            &mut self.py,
+            &mut self.pz,
+            &mut self.vx,
+            &mut self.vy,
+            &mut self.vz,
+            &mut self.ax,
+            &mut self.ay,
+            &mut self.az,
+            &mut self.angular_velocity,
+            &mut self.angular_acceleration,
+        ] {
+            column.reserve(additional);
+        }
+    }
+
+    /// 명령 슬라이스로부터 생성
+    pub fn from_commands(commands: &[MotionCommand]) -> Self {
+        let mut batch = Self::with_capacity(commands.len());
+        for cmd in commands {
+            batch.push(cmd);
+        }
+        batch
+    }
+
+    /// 명령/장애물 비우기 (용량 유지)
+    pub fn clear(&mut self) {
+        self.robot_ids.clear();
+        self.ticket_ids.clear();
+        for column in [
+            &mut self.px,
+            &mut self.py,
+            &mut self.pz,
+            &mut self.vx,
+            &mut self.vy,
+            &mut self.vz,
+            &mut self.ax,
+            &mut self.ay,
+            &mut self.az,
+            &mut self.angular_velocity,
+            &mut self.angular_acceleration,
+        ] {
+            column.clear();
+        }
+        self.clear_obstacles();
+    }
+
+    /// 명령 추가
+    pub fn push(&mut self, cmd: &MotionCommand) {
+        self.robot_ids.push(cmd.robot_id);
+        self.ticket_ids.push(cmd.ticket_id);
+        self.px.push(cmd.current_position.x);
+        self.py.push(cmd.current_position.y);
+        self.pz.push(cmd.current_position.z);
+        self.vx.push(cmd.target_velocity.vx);
+        self.vy.push(cmd.target_velocity.vy);
+        self.vz.push(cmd.target_velocity.vz);
+        self.ax.push(cmd.target_acceleration.ax);
+        self.ay.push(cmd.target_acceleration.ay);
+        self.az.push(cmd.target_acceleration.az);
+        self.angular_velocity.push(cmd.target_angular_velocity);
+        self.angular_acceleration
+            .push(cmd.target_angular_acceleration);
+    }
+
+    /// 동적 장애물 설정 (이전 장애물 교체)
+    pub fn set_obstacles(&mut self, obstacles: &[Position]) {
+        self.clear_obstacles();
+        for obstacle in obstacles {
+            self.ox.push(obstacle.x);
+            self.oy.push(obstacle.y);
+            self.oz.push(obstacle.z);
+        }
+    }
+
+    fn clear_obstacles(&mut self) {
+        self.ox.clear();
+        self.oy.clear();
+        self.oz.clear();
+    }
+
+    /// 명령 수
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.robot_ids.len()
+    }
+
+    /// 비어있는지
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.robot_ids.is_empty()
+    }
+
+    /// 장애물 수
+    #[inline]
+    pub fn obstacle_count(&self) -> usize {
+        self.ox.len()
+    }
+
+    /// 로봇 ID 목록
+    #[inline]
+    pub fn robot_ids(&self) -> &[u64] {
+        &self.robot_ids
+    }
+
+    /// i번째 명령 복원
+    pub fn command(&self, i: usize) -> MotionCommand {
+        MotionCommand {
+            robot_id: self.robot_ids[i],
+            current_position: self.position(i),
+            target_velocity: self.velocity(i),
+            target_acceleration: Acceleration::new(self.ax[i], self.ay[i], self.az[i]),
+            target_angular_velocity: self.angular_velocity[i],
+            target_angular_acceleration: self.angular_acceleration[i],
+            ticket_id: self.ticket_ids[i],
+        }
+    }
+
+    /// i번째 명령 위치
+    #[inline]
+    pub fn position(&self, i: usize) -> Position {
+        Position::new(self.px[i], self.py[i], self.pz[i])
+    }
+
+    /// i번째 명령 목표 속도
+    #[inline]
+    pub fn velocity(&self, i: usize) -> Velocity {
+        Velocity::new(self.vx[i], self.vy[i], self.vz[i])
+    }
+
+    /// 장애물 목록 복원
+    pub fn obstacles(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.obstacle_count()).map(|j| Position::new(self.ox[j], self.oy[j], self.oz[j]))
+    }
+}
+
+/// 배치 검증 결과 (명령 인덱스 기준 SoA)
+#[derive(Debug, Clone, Default)]
+pub struct BatchResults {
+    results: Vec<ValidationResult>,
+    failed_bitmaps: Vec<u64>,
+    time_to_collision: Vec<f32>,
+    nearest_distance: Vec<f32>,
+    /// 정적 맵 질의 후보 버퍼 (재사용)
+    pub(super) candidates: Vec<usize>,
+}
+
+impl BatchResults {
+    /// 새 BatchResults 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 결과 수
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// 비어있는지
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    /// 명령별 검증 결과
+    #[inline]
+    pub fn results(&self) -> &[ValidationResult] {
+        &self.results
+    }
+
+    /// i번째 명령 결과
+    #[inline]
+    pub fn result(&self, i: usize) -> ValidationResult {
+        self.results[i]
+    }
+
+    /// i번째 명령 실패 제약조건 비트맵 (`constraint_ids` 기준)
+    #[inline]
+    pub fn failed_bitmap(&self, i: usize) -> u64 {
+        self.failed_bitmaps[i]
+    }
+
+    /// i번째 명령 통과 제약조건 비트맵
+    #[inline]
+    pub fn passed_bitmap(&self, i: usize) -> u64 {
+        BATCH_CONSTRAINTS_MASK & !self.failed_bitmaps[i]
+    }
+
+    /// i번째 명령 충돌 예상 시간 (초, 충돌 예측이 없으면 None)
+    #[inline]
+    pub fn time_to_collision(&self, i: usize) -> Option<f32> {
+        let ttc = self.time_to_collision[i];
+        ttc.is_finite().then_some(ttc)
+    }
+
+    /// i번째 명령 최근접 장애물 거리 (m, 장애물이 없으면 `f32::MAX`)
+    #[inline]
+    pub fn nearest_distance(&self, i: usize) -> f32 {
+        self.nearest_distance[i]
+    }
+
+    /// 결과 수 집계 (OK, ADJUST, REJECT)
+    pub fn counts(&self) -> (usize, usize, usize) {
+        self.results
+            .iter()
+            .fold((0, 0, 0), |(ok, adjust, reject), result| match result {
+                ValidationResult::OK => (ok + 1, adjust, reject),
+                ValidationResult::ADJUST => (ok, adjust + 1, reject),
+                ValidationResult::REJECT => (ok, adjust, reject + 1),
+            })
+    }
+
+    /// 명령 수에 맞게 초기화 (용량 유지)
+    pub(super) fn reset(&mut self, len: usize) {
+        self.results.clear();
+        self.results.resize(len, ValidationResult::OK);
+        self.failed_bitmaps.clear();
+        self.failed_bitmaps.resize(len, 0);
+        self.time_to_collision.clear();
+        self.time_to_collision.resize(len, f32::INFINITY);
+        self.nearest_distance.clear();
+        self.nearest_distance.resize(len, f32::MAX);
+    }
+
+    /// i번째 결과를 스칼라 경로 결과로 덮어쓰기
+    pub(super) fn set(
+        &mut self,
+        i: usize,
+        result: ValidationResult,
+        failed_bitmap: u64,
+        time_to_collision: Option<f32>,
+        nearest_distance: f32,
+    ) {
+        self.results[i] = result;
+        self.failed_bitmaps[i] = failed_bitmap & BATCH_CONSTRAINTS_MASK;
+        self.time_to_collision[i] = time_to_collision.unwrap_or(f32::INFINITY);
+        self.nearest_distance[i] = nearest_distance;
+    }
+
+    /// 충돌 결과 병합 (더 이른 충돌 우선, 최근접 거리는 최소값)
+    pub(super) fn merge_collision(&mut self, i: usize, collision: &CollisionResult) {
+        if collision.will_collide {
+            self.failed_bitmaps[i] |= 1 << constraint_ids::COLLISION_PREDICTION;
+            let ttc = collision.time_to_collision.unwrap_or(0.0);
+            self.time_to_collision[i] = self.time_to_collision[i].min(ttc);
+        }
+        self.nearest_distance[i] =
+            self.nearest_distance[i].min(collision.nearest_obstacle_distance);
+    }
+}
+
+/// 스칼라 동역학 결과의 실패 비트맵
+pub(super) fn kinematics_failed_bitmap(kinematics: &KinematicsResult) -> u64 {
+    (!kinematics.velocity_ok as u64) << constraint_ids::VELOCITY_LIMIT
+        | (!kinematics.acceleration_ok as u64) << constraint_ids::ACCELERATION_LIMIT
+        | (!kinematics.jerk_ok as u64) << constraint_ids::JERK_LIMIT
+        | (!kinematics.angular_velocity_ok as u64) << constraint_ids::ANGULAR_VELOCITY_LIMIT
+        | (!kinematics.angular_acceleration_ok as u64) << constraint_ids::ANGULAR_ACCELERATION_LIMIT
+}
+
+/// 동역학 검사 패스 - 제곱 크기 비교로 분기 없이 실패 비트 누적
+pub(super) fn kinematics_pass(
+    batch: &CommandBatch,
+    limits: &AppliedLimits,
+    out: &mut BatchResults,
+) {
+    let max_v2 = limits.max_velocity * limits.max_velocity;
+    let max_a2 = limits.max_acceleration * limits.max_acceleration;
+    let max_w = limits.max_angular_velocity;
+    let max_alpha = limits.max_angular_acceleration;
+
+    let n = batch.len();
+    let (vx, vy, vz) = (&batch.vx[..n], &batch.vy[..n], &batch.vz[..n]);
+    let (ax, ay, az) = (&batch.ax[..n], &batch.ay[..n], &batch.az[..n]);
+    let (w, alpha) = (
+        &batch.angular_velocity[..n],
+        &batch.angular_acceleration[..n],
+    );
+    let failed = &mut out.failed_bitmaps[..n];
+
+    for i in 0..n {
+        let v2 = vx[i] * vx[i] + vy[i] * vy[i] + vz[i] * vz[i];
+        let a2 = ax[i] * ax[i] + ay[i] * ay[i] + az[i] * az[i];
+        failed[i] = ((v2 > max_v2) as u64) << constraint_ids::VELOCITY_LIMIT
+            | ((a2 > max_a2) as u64) << constraint_ids::ACCELERATION_LIMIT
+            | ((w[i].abs() > max_w) as u64) << constraint_ids::ANGULAR_VELOCITY_LIMIT
+            | ((alpha[i].abs() > max_alpha) as u64) << constraint_ids::ANGULAR_ACCELERATION_LIMIT;
+    }
+}
+
+/// 이격 거리 검사 패스 - `CollisionPredictor::predict`와 같은 판정
+///
+/// 장애물별 도달 시간을 최소값으로 접어 분기 없이 계산한다.
+pub(super) fn clearance_pass(
+    batch: &CommandBatch,
+    predictor: &CollisionPredictor,
+    out: &mut BatchResults,
+) {
+    let m = batch.obstacle_count();
+    if m == 0 {
+        return;
+    }
+    let safety = predictor.safety_distance();
+    let safety2 = safety * safety;
+    let (ox, oy, oz) = (&batch.ox[..m], &batch.oy[..m], &batch.oz[..m]);
+
+    for i in 0..batch.len() {
+        let (px, py, pz) = (batch.px[i], batch.py[i], batch.pz[i]);
+        let (vx, vy, vz) = (batch.vx[i], batch.vy[i], batch.vz[i]);
+        let speed = (vx * vx + vy * vy + vz * vz).sqrt();
+        let moving = speed > 0.001;
+        let inv_speed = if moving { 1.0 / speed } else { 0.0 };
+
+        let mut nearest2 = f32::MAX;
+        let mut min_ttc = f32::INFINITY;
+        for j in 0..m {
+            let (dx, dy, dz) = (ox[j] - px, oy[j] - py, oz[j] - pz);
+            let d2 = dx * dx + dy * dy + dz * dz;
+            nearest2 = nearest2.min(d2);
+
+            let toward = moving & (vx * dx + vy * dy + vz * dz > 0.0);
+            let reach = (d2.sqrt() - safety) * inv_speed;
+            let ttc = if d2 < safety2 {
+                0.0
+            } else if toward {
+                reach
+            } else {
+                f32::INFINITY
+            };
+            min_ttc = min_ttc.min(ttc);
+        }
+
+        let horizon = predictor.effective_horizon(speed);
+        if min_ttc < horizon {
+            out.failed_bitmaps[i] |= 1 << constraint_ids::COLLISION_PREDICTION;
+            out.time_to_collision[i] = min_ttc.max(0.0);
+        }
+        out.nearest_distance[i] = nearest2.sqrt();
+    }
+}
+
+/// 실패 비트맵으로부터 결과 결정 (충돌은 REJECT, 동역학 위반은 ADJUST)
+pub(super) fn resolve_results(out: &mut BatchResults) {
+    for (result, &failed) in out.results.iter_mut().zip(out.failed_bitmaps.iter()) {
+        *result = if failed & (1 << constraint_ids::COLLISION_PREDICTION) != 0 {
+            ValidationResult::REJECT
+        } else if failed & KINEMATICS_MASK != 0 {
+            ValidationResult::ADJUST
+        } else {
+            ValidationResult::OK
+        };
+    }
+}
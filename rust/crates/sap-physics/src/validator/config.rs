@@ -1,9 +1,12 @@
 //! PhysicsValidator 설정
 
+use crate::kinematics::{KinematicsParams, VehicleProfile, VehicleType};
 use sap_core::types::Acceleration;
+use serde::{Deserialize, Serialize};
 
 /// PhysicsValidator 설정
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct PhysicsValidatorConfig {
     /// 최대 속도 (m/s)
     pub max_velocity: f32,
@@ -14,6 +17,12 @@ pub struct PhysicsValidatorConfig {
     /// 최대 저크 (m/s³)
     pub max_jerk: f32,
 
+    /// 최대 각속도 (rad/s)
+    pub max_angular_velocity: f32,
+
+    /// 최대 각가속도 (rad/s²)
+    pub max_angular_acceleration: f32,
+
     /// 충돌 안전 거리 (m)
     pub collision_safety_distance: f32,
 
@@ -22,17 +31,23 @@ pub struct PhysicsValidatorConfig {
 
     /// 롤백 델타 임계값 (m)
     pub rollback_delta_threshold: f32,
+
+    /// 제어 주기 (초) - 명령 하나가 적용되는 시간
+    pub control_period_secs: f32,
 }
 
 impl Default for PhysicsValidatorConfig {
     fn default() -> Self {
         Self {
-            max_velocity: 5.0,                       // 5 m/s
-            max_acceleration: Acceleration::GRAVITY, // 9.8 m/s²
-            max_jerk: 50.0,                          // 50 m/s³
-            collision_safety_distance: 1.0,          // 1m
-            collision_horizon_secs: 1.0,             // 1초
-            rollback_delta_threshold: 0.1,           // 10cm
+            max_velocity: 5.0,                               // 5 m/s
+            max_acceleration: Acceleration::GRAVITY,         // 9.8 m/s²
+            max_jerk: 50.0,                                  // 50 m/s³
+            max_angular_velocity: std::f32::consts::PI,      // 180°/s
+            max_angular_acceleration: std::f32::consts::TAU, // 360°/s²
+            collision_safety_distance: 1.0,                  // 1m
+            collision_horizon_secs: 1.0,                     // 1초
+            rollback_delta_threshold: 0.1,                   // 10cm
+            control_period_secs: 0.1,                        // 10Hz
         }
     }
 }
@@ -44,9 +59,12 @@ impl PhysicsValidatorConfig {
             max_velocity: 3.0,
             max_acceleration: 5.0,
             max_jerk: 30.0,
+            max_angular_velocity: 1.57,
+            max_angular_acceleration: std::f32::consts::PI,
             collision_safety_distance: 1.5,
             collision_horizon_secs: 1.5,
             rollback_delta_threshold: 0.05,
+            control_period_secs: 0.1,
         }
     }
 
@@ -56,9 +74,95 @@ impl PhysicsValidatorConfig {
             max_velocity: 8.0,
             max_acceleration: 8.0,
             max_jerk: 60.0,
+            max_angular_velocity: std::f32::consts::PI,
+            max_angular_acceleration: std::f32::consts::TAU,
             collision_safety_distance: 2.0,
             collision_horizon_secs: 2.0,
             rollback_delta_threshold: 0.15,
+            control_period_secs: 0.05,
+        }
+    }
+}
+
+/// 검증에 실제 적용된 한계값
+///
+/// Zone 전역 설정과 차량 프로파일 중 더 엄격한 값
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedLimits {
+    /// 최대 속도 (m/s)
+    pub max_velocity: f32,
+
+    /// 최대 가속도 (m/s²)
+    pub max_acceleration: f32,
+
+    /// 최대 저크 (m/s³)
+    pub max_jerk: f32,
+
+    /// 최대 각속도 (rad/s)
+    pub max_angular_velocity: f32,
+
+    /// 최대 각가속도 (rad/s²)
+    pub max_angular_acceleration: f32,
+
+    /// 충돌 안전 거리 (m) - 차량이 있으면 Zone 안전 거리 + 차량 바운딩 반경
+    pub collision_safety_distance: f32,
+
+    /// 적용된 차량 유형 (None이면 Zone 설정만 적용)
+    pub vehicle_type: Option<VehicleType>,
+}
+
+impl AppliedLimits {
+    /// Zone 설정만 적용
+    pub fn from_config(config: &PhysicsValidatorConfig) -> Self {
+        Self {
+            max_velocity: config.max_velocity,
+            max_acceleration: config.max_acceleration,
+            max_jerk: config.max_jerk,
+            max_angular_velocity: config.max_angular_velocity,
+            max_angular_acceleration: config.max_angular_acceleration,
+            collision_safety_distance: config.collision_safety_distance,
+            vehicle_type: None,
+        }
+    }
+
+    /// Zone 설정과 차량 프로파일 중 더 엄격한 값
+    ///
+    /// 장애물까지의 거리는 로봇 중심 기준이므로 안전 거리는 차량 반경만큼 늘린다.
+    pub fn stricter(config: &PhysicsValidatorConfig, profile: &VehicleProfile) -> Self {
+        let kinematics = &profile.kinematics;
+        Self {
+            max_velocity: config.max_velocity.min(kinematics.max_velocity),
+            max_acceleration: config.max_acceleration.min(kinematics.max_acceleration),
+            max_jerk: config.max_jerk.min(kinematics.max_jerk),
+            max_angular_velocity: config
+                .max_angular_velocity
+                .min(kinematics.max_angular_velocity),
+            max_angular_acceleration: config
+                .max_angular_acceleration
+                .min(kinematics.max_angular_acceleration),
+            collision_safety_distance: config.collision_safety_distance + profile.bounding_radius(),
+            vehicle_type: Some(profile.vehicle_type),
+        }
+    }
+
+    /// 차량 프로파일이 반영되었는지
+    #[inline]
+    pub fn is_vehicle_bound(&self) -> bool {
+        self.vehicle_type.is_some()
+    }
+
+    /// 한계값 기반 운동학 파라미터 (차량 프로파일 없는 로봇의 정지 거리 계산용)
+    ///
+    /// Zone 설정에는 감속도가 없으므로 최대 가속도를 감속도로 사용한다.
+    pub fn kinematics(&self) -> KinematicsParams {
+        KinematicsParams {
+            max_velocity: self.max_velocity,
+            max_acceleration: self.max_acceleration,
+            max_deceleration: self.max_acceleration,
+            max_angular_velocity: self.max_angular_velocity,
+            max_angular_acceleration: self.max_angular_acceleration,
+            max_jerk: self.max_jerk,
+            ..Default::default()
         }
     }
 }
@@ -67,6 +171,37 @@ impl PhysicsValidatorConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_applied_limits_stricter() {
+        let zone = PhysicsValidatorConfig::warehouse();
+        let limits = AppliedLimits::stricter(&zone, &VehicleProfile::agv());
+
+        assert_eq!(limits.max_velocity, 1.5); // 차량이 더 엄격
+        assert_eq!(limits.max_acceleration, 1.0);
+        assert_eq!(limits.max_angular_velocity, 0.5);
+        // Zone 안전 거리 1.5m + AGV 바운딩 반경
+        let radius = VehicleProfile::agv().bounding_radius();
+        assert_eq!(limits.collision_safety_distance, 1.5 + radius);
+        assert_eq!(limits.vehicle_type, Some(VehicleType::Ackermann));
+        assert!(!AppliedLimits::from_config(&zone).is_vehicle_bound());
+
+        let kinematics = AppliedLimits::from_config(&zone).kinematics();
+        assert_eq!(kinematics.max_velocity, zone.max_velocity);
+        assert_eq!(kinematics.max_deceleration, zone.max_acceleration);
+    }
+
+    #[test]
+    fn test_safety_distance_adds_large_vehicle_radius() {
+        let zone = PhysicsValidatorConfig::default();
+        let agv = VehicleProfile::agv();
+        // AGV 반경(≈1.32m)이 Zone 안전 거리(1m)보다 커도 여유 거리가 사라지지 않음
+        assert!(agv.bounding_radius() > zone.collision_safety_distance);
+
+        let limits = AppliedLimits::stricter(&zone, &agv);
+        let clearance = limits.collision_safety_distance - agv.bounding_radius();
+        assert!((clearance - zone.collision_safety_distance).abs() < 1e-6);
+    }
+
     #[test]
     fn test_default_config() {
         let config = PhysicsValidatorConfig::default();
@@ -1,7 +1,18 @@
 //! validator 모듈 - 물리 검증기
 
+mod batch;
 mod config;
 pub mod physics_validator;
+mod proof_accumulator;
+mod trajectory_validator;
 
-pub use config::PhysicsValidatorConfig;
-pub use physics_validator::PhysicsValidator;
+pub use batch::{BatchResults, CommandBatch, BATCH_CONSTRAINTS_MASK};
+pub use config::{AppliedLimits, PhysicsValidatorConfig};
+pub use physics_validator::{PhysicsValidator, ValidationReport};
+pub use proof_accumulator::{
+    AccumulateError, InclusionProof, ProofAccumulator, ProofAccumulatorConfig,
+};
+pub use trajectory_validator::{
+    TrajectoryCheckResult, TrajectoryContext, TrajectoryValidator, TrajectoryValidatorConfig,
+    TrajectoryViolation,
+};
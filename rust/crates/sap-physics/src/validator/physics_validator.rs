@@ -2,13 +2,25 @@
 //!
 //! PPR 매핑: AI_make_PhysicsValidator
 
-use super::PhysicsValidatorConfig;
+use super::batch::{self, BatchResults, CommandBatch};
+use super::{AppliedLimits, PhysicsValidatorConfig};
 use crate::command::MotionCommand;
-use crate::constraint::{CollisionPredictor, KinematicsChecker};
+use crate::constraint::{
+    CollisionPredictor, DynamicHorizonConfig, FilterState, Geofence, KinematicsChecker,
+    SafetyFilter, SafetyFilterConfig, SeparationParty, SpatialIndex, SpeedSeparationMonitor,
+    TicketConstraint, UncertainCollisionChecker,
+};
+use crate::kinematics::VehicleProfile;
 use sap_core::{
-    types::Position,
-    validation::{constraint_ids, ValidationFrame, ValidationResult},
+    ticket::TicketStore,
+    types::{DynamicObstacle, Position, RobotState},
+    validation::{
+        constraint_ids, AdjustedCommand, ValidationFrame, ValidationReason, ValidationResult,
+        ValidationResultDetail,
+    },
 };
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 /// PhysicsValidator - L2 TrustOS 물리 검증기
 ///
@@ -23,6 +35,24 @@ pub struct PhysicsValidator {
     /// 충돌 예측기
     collision_predictor: CollisionPredictor,
 
+    /// 최소 편차 안전 필터
+    safety_filter: SafetyFilter,
+
+    /// 로봇별 차량 프로파일 (robot_id → 프로파일)
+    vehicle_profiles: HashMap<u64, VehicleProfile>,
+
+    /// 정적 맵 장애물 인덱스 (맵 버전별 1회 생성)
+    static_index: Option<SpatialIndex>,
+
+    /// 티켓/VTS 제약 (설정 시에만 검사)
+    ticket_constraint: Option<TicketConstraint>,
+
+    /// 속도-분리 감시 (설정 시에만 검사)
+    speed_separation: Option<SpeedSeparationMonitor>,
+
+    /// 불확실성 기반 충돌 검사 (설정 시에만 검사)
+    uncertainty: Option<UncertainCollisionChecker>,
+
     /// 검증 로그 (최근 N개)
     validation_log: Vec<ValidationLogEntry>,
 
@@ -30,6 +60,43 @@ pub struct PhysicsValidator {
     log_capacity: usize,
 }
 
+/// 상세 검증 보고서
+///
+/// 실패한 제약조건 목록과 ADJUST 시 실제로 실행 가능한 명령을 포함
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    /// 상세 결과 (위반 목록, 통과/실패 비트맵)
+    pub detail: ValidationResultDetail,
+
+    /// 조정된 명령 (ADJUST 시에만 Some)
+    pub adjusted_command: Option<MotionCommand>,
+
+    /// 적용된 한계값 (Zone/차량 중 엄격한 값)
+    pub applied_limits: AppliedLimits,
+}
+
+impl ValidationReport {
+    /// 기본 결과
+    #[inline]
+    pub fn result(&self) -> ValidationResult {
+        self.detail.result
+    }
+
+    /// 로봇이 실행해야 할 명령
+    ///
+    /// OK면 원본, ADJUST면 조정된 명령, REJECT면 None
+    pub fn command_to_execute<'a>(
+        &'a self,
+        original: &'a MotionCommand,
+    ) -> Option<&'a MotionCommand> {
+        match self.detail.result {
+            ValidationResult::OK => Some(original),
+            ValidationResult::ADJUST => self.adjusted_command.as_ref(),
+            ValidationResult::REJECT => None,
+        }
+    }
+}
+
 /// 검증 로그 항목
 #[derive(Debug, Clone)]
 pub struct ValidationLogEntry {
@@ -42,8 +109,24 @@ pub struct ValidationLogEntry {
     /// 타임스탬프 (나노초)
     pub timestamp_ns: u64,
 
-    /// 상세 정보
-    pub details: String,
+    /// 속도 제한 통과
+    pub velocity_ok: bool,
+
+    /// 가속도 제한 통과
+    pub acceleration_ok: bool,
+
+    /// 충돌 예측 여부
+    pub will_collide: bool,
+}
+
+impl ValidationLogEntry {
+    /// 사람이 읽을 수 있는 상세 정보 (조회 시에만 포맷)
+    pub fn details(&self) -> String {
+        format!(
+            "vel_ok={}, accel_ok={}, collision={}",
+            self.velocity_ok, self.acceleration_ok, self.will_collide
+        )
+    }
 }
 
 impl PhysicsValidator {
@@ -53,17 +136,26 @@ impl PhysicsValidator {
             config.max_velocity,
             config.max_acceleration,
             config.max_jerk,
-        );
+        )
+        .with_angular_limits(config.max_angular_velocity, config.max_angular_acceleration);
 
         let collision_predictor = CollisionPredictor::new(
             config.collision_safety_distance,
             config.collision_horizon_secs,
         );
 
+        let safety_filter = SafetyFilter::new(SafetyFilterConfig::from_validator_config(&config));
+
         Self {
             config,
             kinematics_checker,
             collision_predictor,
+            safety_filter,
+            vehicle_profiles: HashMap::new(),
+            static_index: None,
+            ticket_constraint: None,
+            speed_separation: None,
+            uncertainty: None,
             validation_log: Vec::new(),
             log_capacity: 1000,
         }
@@ -74,6 +166,80 @@ impl PhysicsValidator {
         Self::new(PhysicsValidatorConfig::default())
     }
 
+    /// 안전 필터 교체
+    pub fn with_safety_filter(mut self, filter: SafetyFilter) -> Self {
+        self.safety_filter = filter;
+        self
+    }
+
+    /// 속력 기반 동적 충돌 예측 지평 활성화
+    pub fn with_dynamic_horizon(mut self, config: DynamicHorizonConfig) -> Self {
+        self.collision_predictor = self.collision_predictor.with_dynamic_horizon(config);
+        self
+    }
+
+    /// 티켓/VTS 제약 설정
+    pub fn with_ticket_constraint(mut self, constraint: TicketConstraint) -> Self {
+        self.ticket_constraint = Some(constraint);
+        self
+    }
+
+    /// 속도-분리 감시 설정
+    pub fn with_speed_separation(mut self, monitor: SpeedSeparationMonitor) -> Self {
+        self.speed_separation = Some(monitor);
+        self
+    }
+
+    /// 불확실성 기반 충돌 검사 설정
+    pub fn with_uncertainty(mut self, checker: UncertainCollisionChecker) -> Self {
+        self.uncertainty = Some(checker);
+        self
+    }
+
+    /// 로봇 차량 프로파일 등록 (기존 프로파일은 교체)
+    pub fn register_vehicle(&mut self, robot_id: u64, profile: VehicleProfile) {
+        self.vehicle_profiles.insert(robot_id, profile);
+    }
+
+    /// 로봇 차량 프로파일 해제
+    pub fn unregister_vehicle(&mut self, robot_id: u64) -> Option<VehicleProfile> {
+        self.vehicle_profiles.remove(&robot_id)
+    }
+
+    /// 로봇 차량 프로파일 조회
+    pub fn vehicle_profile(&self, robot_id: u64) -> Option<&VehicleProfile> {
+        self.vehicle_profiles.get(&robot_id)
+    }
+
+    /// 로봇에 적용할 한계값 (프로파일 없으면 Zone 설정)
+    pub fn limits_for(&self, robot_id: u64) -> AppliedLimits {
+        match self.vehicle_profiles.get(&robot_id) {
+            Some(profile) => AppliedLimits::stricter(&self.config, profile),
+            None => AppliedLimits::from_config(&self.config),
+        }
+    }
+
+    /// 정적 맵 갱신
+    ///
+    /// 맵 버전이 바뀐 경우에만 공간 인덱스를 다시 생성하고 true 반환.
+    /// 이후 검증은 인자로 받은 동적 장애물과 정적 맵을 함께 검사한다.
+    pub fn update_static_map(&mut self, map_version: u64, obstacles: &[Position]) -> bool {
+        if self.static_map_version() == Some(map_version) {
+            return false;
+        }
+        self.static_index = Some(SpatialIndex::build(
+            obstacles,
+            self.config.collision_safety_distance,
+            map_version,
+        ));
+        true
+    }
+
+    /// 현재 정적 맵 버전
+    pub fn static_map_version(&self) -> Option<u64> {
+        self.static_index.as_ref().map(SpatialIndex::map_version)
+    }
+
     /// 명령 검증 (PPR: AI_make_PhysicsValidator)
     pub fn validate(
         &mut self,
@@ -81,27 +247,468 @@ impl PhysicsValidator {
         obstacles: &[Position],
         timestamp_ns: u64,
     ) -> ValidationResult {
-        let kinematics_result = self
-            .kinematics_checker
-            .check(&cmd.target_velocity, &cmd.target_acceleration);
+        self.validate_detailed(cmd, obstacles, timestamp_ns)
+            .result()
+    }
 
-        let collision_result = self.collision_predictor.predict(
-            &cmd.current_position,
+    /// 상세 명령 검증
+    ///
+    /// 실패한 제약조건과 비트맵, ADJUST 시 조정된 명령을 반환
+    pub fn validate_detailed(
+        &mut self,
+        cmd: &MotionCommand,
+        obstacles: &[Position],
+        timestamp_ns: u64,
+    ) -> ValidationReport {
+        let limits = self.limits_for(cmd.robot_id);
+        let (kinematics_checker, collision_predictor) = self.checkers_for(&limits);
+
+        let kinematics_result = kinematics_checker.check_motion(
             &cmd.target_velocity,
-            obstacles,
+            &cmd.target_acceleration,
+            cmd.target_angular_velocity,
+            cmd.target_angular_acceleration,
         );
 
-        let result = self.determine_result(&kinematics_result, &collision_result);
+        let collision_result = self.predict_collision(&collision_predictor, cmd, obstacles);
+
+        let report = self.build_report(cmd, limits, &kinematics_result, &collision_result);
 
         self.log_validation(
             cmd.robot_id,
-            result,
+            report.result(),
             timestamp_ns,
             &kinematics_result,
             &collision_result,
         );
 
-        result
+        report
+    }
+
+    /// 배치 명령 검증 (SoA 커널)
+    ///
+    /// 한 틱의 명령을 일괄 검사해 명령별 결과를 `out`에 기록한다.
+    /// 결과는 명령마다 `validate`를 호출한 것과 같으며, `out`을 틱마다 재사용하면
+    /// 차량 프로파일이 없는 로봇 경로에서는 할당이 없다.
+    /// 차량 프로파일이 등록된 로봇은 한계값이 달라 스칼라 경로로 검증하고,
+    /// ADJUST 명령의 조정값은 `adjust_command`로 얻는다.
+    pub fn validate_batch(
+        &mut self,
+        batch: &CommandBatch,
+        timestamp_ns: u64,
+        out: &mut BatchResults,
+    ) {
+        out.reset(batch.len());
+        let limits = AppliedLimits::from_config(&self.config);
+        batch::kinematics_pass(batch, &limits, out);
+        batch::clearance_pass(batch, &self.collision_predictor, out);
+        if let Some(index) = &self.static_index {
+            let mut candidates = std::mem::take(&mut out.candidates);
+            for i in 0..batch.len() {
+                let collision = self.collision_predictor.predict_indexed_with(
+                    &batch.position(i),
+                    &batch.velocity(i),
+                    index,
+                    &mut candidates,
+                );
+                out.merge_collision(i, &collision);
+            }
+            out.candidates = candidates;
+        }
+        batch::resolve_results(out);
+
+        // 차량 프로파일 로봇은 스칼라 경로로 덮어쓰기
+        let obstacles: Vec<Position> = if batch
+            .robot_ids()
+            .iter()
+            .any(|id| self.vehicle_profiles.contains_key(id))
+        {
+            batch.obstacles().collect()
+        } else {
+            Vec::new()
+        };
+
+        for (i, &robot_id) in batch.robot_ids().iter().enumerate() {
+            if self.vehicle_profiles.contains_key(&robot_id) {
+                let cmd = batch.command(i);
+                let limits = self.limits_for(robot_id);
+                let (checker, predictor) = self.checkers_for(&limits);
+                let kinematics = checker.check_motion(
+                    &cmd.target_velocity,
+                    &cmd.target_acceleration,
+                    cmd.target_angular_velocity,
+                    cmd.target_angular_acceleration,
+                );
+                let collision = self.predict_collision(&predictor, &cmd, &obstacles);
+                let result = self.determine_result(&kinematics, &collision);
+                let failed = batch::kinematics_failed_bitmap(&kinematics)
+                    | (collision.will_collide as u64) << constraint_ids::COLLISION_PREDICTION;
+                out.set(
+                    i,
+                    result,
+                    failed,
+                    collision
+                        .time_to_collision
+                        .filter(|_| collision.will_collide),
+                    collision.nearest_obstacle_distance,
+                );
+                self.log_validation(robot_id, result, timestamp_ns, &kinematics, &collision);
+                continue;
+            }
+
+            let failed = out.failed_bitmap(i);
+            self.push_log(ValidationLogEntry {
+                robot_id,
+                result: out.result(i),
+                timestamp_ns,
+                velocity_ok: failed & (1 << constraint_ids::VELOCITY_LIMIT) == 0,
+                acceleration_ok: failed & (1 << constraint_ids::ACCELERATION_LIMIT) == 0,
+                will_collide: failed & (1 << constraint_ids::COLLISION_PREDICTION) != 0,
+            });
+        }
+    }
+
+    /// 티켓 저장소 기반 검증
+    ///
+    /// 물리 제약에 더해 티켓 유효성과 VTS 준수를 검사한다.
+    /// 티켓/VTS 위반은 조정으로 해결할 수 없으므로 REJECT.
+    /// ADJUST면 실제 실행될 조정된 명령으로 VTS를 검사한다.
+    /// 티켓 제약이 설정되지 않았으면 `validate_detailed`와 동일.
+    pub fn validate_with_tickets(
+        &mut self,
+        cmd: &MotionCommand,
+        obstacles: &[Position],
+        tickets: &dyn TicketStore,
+        timestamp_ns: u64,
+    ) -> ValidationReport {
+        let mut report = self.validate_detailed(cmd, obstacles, timestamp_ns);
+        let Some(constraint) = &self.ticket_constraint else {
+            return report;
+        };
+
+        let executed = report.adjusted_command.as_ref().unwrap_or(cmd);
+        let check = constraint.check(executed, tickets, timestamp_ns);
+        let detail = &mut report.detail;
+
+        if check.ticket_ok {
+            detail.record_pass(constraint_ids::TICKET_VALIDITY);
+        }
+        if check.vts_ok == Some(true) {
+            detail.record_pass(constraint_ids::VTS_COMPLIANCE);
+        }
+
+        if let Some(reason) = check.reason {
+            let constraint_id = if check.ticket_ok {
+                constraint_ids::VTS_COMPLIANCE
+            } else {
+                constraint_ids::TICKET_VALIDITY
+            };
+            detail.record_violation(constraint_id, reason.clone());
+            detail.constraint_ok = false;
+            detail.result = ValidationResult::REJECT;
+            detail.adjusted_command = None;
+            // 충돌 예측이 있으면 그쪽이 대표 이유
+            if detail.collision_ok {
+                detail.reason = Some(reason);
+            }
+            report.adjusted_command = None;
+        }
+
+        report
+    }
+
+    /// 속도-분리 감시 포함 검증
+    ///
+    /// 최근접 대상(로봇/사람/장애물)까지의 거리로 속력 상한을 계산하고,
+    /// 명령이 상한을 넘으면 감속한 명령으로 ADJUST한다. 감속한 명령이
+    /// 충돌 예측을 통과하면 충돌 예측 명령도 REJECT 대신 ADJUST된다.
+    /// 차량 프로파일이 없는 로봇은 Zone 한계값으로 정지 거리를 계산한다.
+    /// 감시가 설정되지 않았으면 `validate_detailed`와 동일.
+    pub fn validate_with_separation(
+        &mut self,
+        cmd: &MotionCommand,
+        obstacles: &[Position],
+        parties: &[SeparationParty],
+        timestamp_ns: u64,
+    ) -> ValidationReport {
+        let mut report = self.validate_detailed(cmd, obstacles, timestamp_ns);
+        let Some(monitor) = &self.speed_separation else {
+            return report;
+        };
+
+        let base = report
+            .adjusted_command
+            .clone()
+            .unwrap_or_else(|| cmd.clone());
+        let profile = self.vehicle_profiles.get(&cmd.robot_id);
+        let kinematics = profile
+            .map(|profile| profile.kinematics.clone())
+            .unwrap_or_else(|| report.applied_limits.kinematics());
+        let robot_radius = profile.map_or(0.0, VehicleProfile::bounding_radius);
+        let all_parties: Vec<SeparationParty> = parties
+            .iter()
+            .copied()
+            .chain(obstacles.iter().map(|&p| SeparationParty::obstacle(p)))
+            .collect();
+        let separation = monitor.check(
+            &base.current_position,
+            &base.target_velocity,
+            &kinematics,
+            robot_radius,
+            &all_parties,
+        );
+
+        if !separation.is_limited() {
+            report.detail.record_pass(constraint_ids::SPEED_SEPARATION);
+            return report;
+        }
+
+        let reason = ValidationReason::SeparationSpeedExceeded {
+            actual: base.target_speed(),
+            limit: separation.speed_cap,
+            separation: separation.separation,
+        };
+        report
+            .detail
+            .record_violation(constraint_ids::SPEED_SEPARATION, reason.clone());
+        if report.detail.reason.is_none() {
+            report.detail.reason = Some(reason);
+        }
+
+        let slowed = MotionCommand {
+            target_velocity: base.target_velocity.scale(separation.scale),
+            ..base
+        };
+        self.settle_adjustment(
+            cmd,
+            &mut report,
+            &slowed,
+            obstacles,
+            "Speed limited by separation",
+        );
+        report
+    }
+
+    /// 위치 불확실성 포함 검증
+    ///
+    /// 로봇/장애물 공분산으로 충돌 확률을 계산해 임계값을 넘으면 REJECT,
+    /// 위치 추정 오차가 크면 감속한 명령을 한계값 클램핑과 충돌 재예측을 거쳐 ADJUST한다.
+    /// 검사기가 설정되지 않았으면 장애물 위치만으로 `validate_detailed`와 동일.
+    pub fn validate_with_uncertainty(
+        &mut self,
+        cmd: &MotionCommand,
+        state: &RobotState,
+        obstacles: &[DynamicObstacle],
+        timestamp_ns: u64,
+    ) -> ValidationReport {
+        let positions: Vec<Position> = obstacles.iter().map(|o| o.position).collect();
+        let mut report = self.validate_detailed(cmd, &positions, timestamp_ns);
+        let Some(checker) = &self.uncertainty else {
+            return report;
+        };
+
+        let base = report
+            .adjusted_command
+            .clone()
+            .unwrap_or_else(|| cmd.clone());
+        let (_, collision_predictor) = self.checkers_for(&report.applied_limits);
+        let evaluation = checker.evaluate(
+            &collision_predictor,
+            state,
+            &base.target_velocity,
+            obstacles,
+        );
+
+        if evaluation.exceeds_threshold {
+            let reason = ValidationReason::CollisionProbabilityExceeded {
+                probability: evaluation.collision_probability,
+                threshold: checker.config().reject_probability,
+                obstacle_id: evaluation
+                    .obstacle_index
+                    .map_or(u64::MAX, |i| obstacles[i].id),
+            };
+            let detail = &mut report.detail;
+            detail.record_violation(constraint_ids::COLLISION_PREDICTION, reason.clone());
+            detail.collision_ok = false;
+            detail.result = ValidationResult::REJECT;
+            detail.reason = Some(reason);
+            detail.adjusted_command = None;
+            report.adjusted_command = None;
+            return report;
+        }
+
+        if !evaluation.requires_slowdown() || report.detail.result == ValidationResult::REJECT {
+            return report;
+        }
+
+        let reason = ValidationReason::PositionUncertaintyHigh {
+            std_dev: evaluation.position_std_dev,
+            limit: checker.config().high_uncertainty_std_m,
+        };
+        let slowed = MotionCommand {
+            target_velocity: base.target_velocity.scale(evaluation.speed_scale),
+            ..base
+        };
+        let detail = &mut report.detail;
+        detail.record_violation(constraint_ids::POSITION_UNCERTAINTY, reason.clone());
+        if detail.reason.is_none() {
+            detail.reason = Some(reason);
+        }
+        self.settle_adjustment(
+            cmd,
+            &mut report,
+            &slowed,
+            &positions,
+            "Slowed for position uncertainty",
+        );
+        report
+    }
+
+    /// 로봇 상태 기반 검증 (안전 필터 적용)
+    ///
+    /// 단순 스케일링 대신 모든 제약을 만족하는 최근접 명령으로 ADJUST한다.
+    /// 필터 결과는 한계값으로 클램핑한 뒤 정적 맵을 포함해 충돌 예측을 다시 거치며,
+    /// 해가 없거나 예측 범위 안에 충돌이 남으면 REJECT.
+    pub fn validate_with_state(
+        &mut self,
+        cmd: &MotionCommand,
+        state: &FilterState,
+        obstacles: &[Position],
+        geofences: &[Geofence],
+        timestamp_ns: u64,
+    ) -> ValidationReport {
+        let mut report = self.validate_detailed(cmd, obstacles, timestamp_ns);
+        let (_, collision_predictor) = self.checkers_for(&report.applied_limits);
+        let filter_obstacles =
+            self.with_static_obstacles(&collision_predictor, cmd, state, obstacles);
+        let filtered = self
+            .safety_filter
+            .filter(cmd, state, &filter_obstacles, geofences);
+        let detail = &mut report.detail;
+
+        match filtered.active_geofence {
+            Some(geofence_id) => {
+                let reason = ValidationReason::GeofenceViolation { geofence_id };
+                detail.record_violation(constraint_ids::GEOFENCE, reason.clone());
+                detail.constraint_ok = false;
+                if detail.reason.is_none() {
+                    detail.reason = Some(reason);
+                }
+            }
+            None => detail.record_pass(constraint_ids::GEOFENCE),
+        }
+
+        if !filtered.feasible {
+            detail.result = ValidationResult::REJECT;
+            detail.adjusted_command = None;
+            report.adjusted_command = None;
+            return report;
+        }
+
+        if !filtered.is_modified() && detail.result == ValidationResult::OK {
+            return report;
+        }
+
+        // 필터는 속도만 투영하므로 가속도/각 한계까지 클램핑하고,
+        // 조정된 명령도 예측 범위 전체에서 충돌이 없어야 ADJUST
+        self.settle_adjustment(
+            cmd,
+            &mut report,
+            &filtered.command,
+            obstacles,
+            "Projected into safe set",
+        );
+        report
+    }
+
+    /// 명령을 동역학 제한 내로 조정
+    ///
+    /// 속도와 가속도를 방향은 유지한 채 로봇에 적용되는 최대값으로 클램핑
+    /// (각속도/각가속도는 부호 유지)
+    pub fn adjust_command(&self, cmd: &MotionCommand) -> MotionCommand {
+        let limits = self.limits_for(cmd.robot_id);
+        let (checker, _) = self.checkers_for(&limits);
+        MotionCommand {
+            target_velocity: checker.clamp_velocity(&cmd.target_velocity),
+            target_acceleration: checker.clamp_acceleration(&cmd.target_acceleration),
+            target_angular_velocity: checker.clamp_angular_velocity(cmd.target_angular_velocity),
+            target_angular_acceleration: checker
+                .clamp_angular_acceleration(cmd.target_angular_acceleration),
+            ..cmd.clone()
+        }
+    }
+
+    /// 조정 후보를 한계값으로 클램프하고 동역학/충돌을 재검사해 ADJUST로 확정
+    ///
+    /// 최종 명령이 여전히 제약을 어기면 REJECT로 두고 false를 반환한다.
+    /// 통과하면 원 명령의 충돌 예측 위반과 대표 이유를 최종 명령 기준으로 갱신한다.
+    fn settle_adjustment(
+        &self,
+        cmd: &MotionCommand,
+        report: &mut ValidationReport,
+        candidate: &MotionCommand,
+        obstacles: &[Position],
+        note: &str,
+    ) -> bool {
+        let adjusted = self.adjust_command(candidate);
+        let (kinematics_checker, collision_predictor) = self.checkers_for(&report.applied_limits);
+        let kinematics = kinematics_checker.check_motion(
+            &adjusted.target_velocity,
+            &adjusted.target_acceleration,
+            adjusted.target_angular_velocity,
+            adjusted.target_angular_acceleration,
+        );
+        let collision = self.predict_collision(&collision_predictor, &adjusted, obstacles);
+
+        let detail = &mut report.detail;
+        if !kinematics.within_limits() || collision.will_collide {
+            detail.result = ValidationResult::REJECT;
+            detail.adjusted_command = None;
+            report.adjusted_command = None;
+            return false;
+        }
+
+        let is_collision = |reason: &ValidationReason| {
+            matches!(reason, ValidationReason::CollisionPredicted { .. })
+        };
+        detail.record_pass(constraint_ids::COLLISION_PREDICTION);
+        detail.collision_ok = true;
+        detail.violations.retain(|reason| !is_collision(reason));
+        if detail.reason.as_ref().is_none_or(is_collision) {
+            detail.reason = detail.violations.first().cloned();
+        }
+        detail.result = ValidationResult::ADJUST;
+        detail.adjusted_command = Some(Self::adjustment(cmd, &adjusted, note));
+        report.adjusted_command = Some(adjusted);
+        true
+    }
+
+    /// 한계값에 맞는 검사기 (Zone 설정과 같으면 기존 검사기 재사용)
+    fn checkers_for(
+        &self,
+        limits: &AppliedLimits,
+    ) -> (Cow<'_, KinematicsChecker>, Cow<'_, CollisionPredictor>) {
+        if !limits.is_vehicle_bound() {
+            return (
+                Cow::Borrowed(&self.kinematics_checker),
+                Cow::Borrowed(&self.collision_predictor),
+            );
+        }
+        (
+            Cow::Owned(
+                KinematicsChecker::new(
+                    limits.max_velocity,
+                    limits.max_acceleration,
+                    limits.max_jerk,
+                )
+                .with_angular_limits(limits.max_angular_velocity, limits.max_angular_acceleration),
+            ),
+            Cow::Owned(
+                self.collision_predictor
+                    .clone()
+                    .with_safety_distance(limits.collision_safety_distance),
+            ),
+        )
     }
 
     /// 검증 프레임 생성
@@ -127,6 +734,203 @@ impl PhysicsValidator {
         frame
     }
 
+    /// 상세 결과로부터 검증 프레임 생성 (실패 비트맵 포함)
+    pub fn create_detailed_frame(
+        &self,
+        cmd: &MotionCommand,
+        detail: &ValidationResultDetail,
+        tick: u64,
+        zone_id: u32,
+    ) -> ValidationFrame {
+        ValidationFrame::new(tick, cmd.robot_id, zone_id)
+            .with_cmd_hash(sap_core::util::compute_hash(cmd))
+            .with_bitmaps(
+                detail.constraints_passed_bitmap,
+                detail.constraints_failed_bitmap,
+            )
+    }
+
+    /// 동적 장애물과 정적 맵을 함께 충돌 예측
+    fn predict_collision(
+        &self,
+        predictor: &CollisionPredictor,
+        cmd: &MotionCommand,
+        obstacles: &[Position],
+    ) -> CollisionResult {
+        let result = predictor.predict(&cmd.current_position, &cmd.target_velocity, obstacles);
+        match &self.static_index {
+            Some(index) => result.merge(predictor.predict_indexed(
+                &cmd.current_position,
+                &cmd.target_velocity,
+                index,
+            )),
+            None => result,
+        }
+    }
+
+    /// 동적 장애물에 예측 범위 내 정적 맵 장애물을 더한 목록 (안전 필터 입력용)
+    fn with_static_obstacles<'a>(
+        &self,
+        predictor: &CollisionPredictor,
+        cmd: &MotionCommand,
+        state: &FilterState,
+        obstacles: &'a [Position],
+    ) -> Cow<'a, [Position]> {
+        let Some(index) = &self.static_index else {
+            return Cow::Borrowed(obstacles);
+        };
+        let speed = cmd
+            .target_velocity
+            .magnitude()
+            .max(state.velocity.magnitude());
+        let mut nearby = Vec::new();
+        index.query_radius(&cmd.current_position, predictor.reach(speed), &mut nearby);
+        if nearby.is_empty() {
+            return Cow::Borrowed(obstacles);
+        }
+        let points = index.points();
+        Cow::Owned(
+            obstacles
+                .iter()
+                .copied()
+                .chain(nearby.into_iter().map(|i| points[i]))
+                .collect(),
+        )
+    }
+
+    /// 원본 대비 조정 내역
+    fn adjustment(
+        original: &MotionCommand,
+        adjusted: &MotionCommand,
+        note: &str,
+    ) -> AdjustedCommand {
+        let original_speed = original.target_speed();
+        let adjusted_speed = adjusted.target_speed();
+        AdjustedCommand {
+            adjusted_velocity: adjusted_speed,
+            adjusted_angular_velocity: adjusted.target_angular_velocity,
+            adjusted_acceleration: adjusted.target_acceleration.magnitude(),
+            scale_factor: if original_speed > 0.0 {
+                adjusted_speed / original_speed
+            } else {
+                1.0
+            },
+            adjustment_note: Some(note.to_string()),
+        }
+    }
+
+    fn build_report(
+        &self,
+        cmd: &MotionCommand,
+        limits: AppliedLimits,
+        kinematics: &KinematicsResult,
+        collision: &CollisionResult,
+    ) -> ValidationReport {
+        let mut detail = ValidationResultDetail::ok();
+        detail.result = self.determine_result(kinematics, collision);
+
+        if kinematics.velocity_ok {
+            detail.record_pass(constraint_ids::VELOCITY_LIMIT);
+        } else {
+            detail.record_violation(
+                constraint_ids::VELOCITY_LIMIT,
+                ValidationReason::VelocityExceeded {
+                    actual: kinematics.actual_velocity,
+                    limit: limits.max_velocity,
+                },
+            );
+        }
+
+        if kinematics.acceleration_ok {
+            detail.record_pass(constraint_ids::ACCELERATION_LIMIT);
+        } else {
+            detail.record_violation(
+                constraint_ids::ACCELERATION_LIMIT,
+                ValidationReason::AccelerationExceeded {
+                    actual: kinematics.actual_acceleration,
+                    limit: limits.max_acceleration,
+                },
+            );
+        }
+
+        if kinematics.jerk_ok {
+            detail.record_pass(constraint_ids::JERK_LIMIT);
+        } else {
+            detail.record_violation(
+                constraint_ids::JERK_LIMIT,
+                // 단일 명령 검사에서는 저크 실측값이 없음
+                ValidationReason::JerkExceeded {
+                    actual: 0.0,
+                    limit: limits.max_jerk,
+                },
+            );
+        }
+
+        if kinematics.angular_velocity_ok {
+            detail.record_pass(constraint_ids::ANGULAR_VELOCITY_LIMIT);
+        } else {
+            detail.record_violation(
+                constraint_ids::ANGULAR_VELOCITY_LIMIT,
+                ValidationReason::AngularVelocityExceeded {
+                    actual: kinematics.actual_angular_velocity,
+                    limit: limits.max_angular_velocity,
+                },
+            );
+        }
+
+        if kinematics.angular_acceleration_ok {
+            detail.record_pass(constraint_ids::ANGULAR_ACCELERATION_LIMIT);
+        } else {
+            detail.record_violation(
+                constraint_ids::ANGULAR_ACCELERATION_LIMIT,
+                ValidationReason::AngularAccelerationExceeded {
+                    actual: kinematics.actual_angular_acceleration,
+                    limit: limits.max_angular_acceleration,
+                },
+            );
+        }
+
+        let collision_reason =
+            collision
+                .will_collide
+                .then(|| ValidationReason::CollisionPredicted {
+                    time_to_collision: collision.time_to_collision.unwrap_or(0.0),
+                    obstacle_id: collision.obstacle_index.map_or(u64::MAX, |i| i as u64),
+                });
+
+        match &collision_reason {
+            Some(reason) => {
+                detail.record_violation(constraint_ids::COLLISION_PREDICTION, reason.clone())
+            }
+            None => detail.record_pass(constraint_ids::COLLISION_PREDICTION),
+        }
+
+        detail.kinematics_ok = kinematics.within_limits();
+        detail.collision_ok = !collision.will_collide;
+        detail.constraint_ok = true;
+
+        // 대표 이유: 충돌이 가장 심각, 그 외에는 첫 위반
+        detail.reason = collision_reason.or_else(|| detail.violations.first().cloned());
+
+        let adjusted_command = if detail.result == ValidationResult::ADJUST {
+            let adjusted = self.adjust_command(cmd);
+            detail.adjusted_command = Some(Self::adjustment(
+                cmd,
+                &adjusted,
+                "Clamped to physical limits",
+            ));
+            Some(adjusted)
+        } else {
+            None
+        };
+
+        ValidationReport {
+            detail,
+            adjusted_command,
+            applied_limits: limits,
+        }
+    }
+
     fn determine_result(
         &self,
         kinematics: &KinematicsResult,
@@ -136,7 +940,7 @@ impl PhysicsValidator {
             return ValidationResult::REJECT;
         }
 
-        if !kinematics.velocity_ok || !kinematics.acceleration_ok || !kinematics.jerk_ok {
+        if !kinematics.within_limits() {
             return ValidationResult::ADJUST;
         }
 
@@ -151,30 +955,39 @@ impl PhysicsValidator {
         kinematics: &KinematicsResult,
         collision: &CollisionResult,
     ) {
-        let details = format!(
-            "vel_ok={}, accel_ok={}, collision={}",
-            kinematics.velocity_ok, kinematics.acceleration_ok, collision.will_collide
-        );
-
-        let entry = ValidationLogEntry {
+        self.push_log(ValidationLogEntry {
             robot_id,
             result,
             timestamp_ns,
-            details,
-        };
+            velocity_ok: kinematics.velocity_ok,
+            acceleration_ok: kinematics.acceleration_ok,
+            will_collide: collision.will_collide,
+        });
+    }
 
-        if self.validation_log.len() >= self.log_capacity {
-            self.validation_log.remove(0);
+    /// 로그 추가 - 용량의 2배까지 쌓은 뒤 앞쪽 절반을 한 번에 제거 (상각 O(1))
+    ///
+    /// 버퍼에는 용량보다 많이 남을 수 있으므로 조회는 `retained_logs`를 거친다.
+    fn push_log(&mut self, entry: ValidationLogEntry) {
+        if self.validation_log.len() >= self.log_capacity * 2 {
+            let excess = self.validation_log.len() - self.log_capacity;
+            self.validation_log.drain(..excess);
         }
         self.validation_log.push(entry);
     }
 
-    /// 최근 검증 로그 조회
-    pub fn recent_logs(&self, count: usize) -> &[ValidationLogEntry] {
-        let start = self.validation_log.len().saturating_sub(count);
+    /// 보관 로그 (최근 로그 용량만큼)
+    fn retained_logs(&self) -> &[ValidationLogEntry] {
+        let start = self.validation_log.len().saturating_sub(self.log_capacity);
         &self.validation_log[start..]
     }
 
+    /// 최근 검증 로그 조회 (최대 로그 용량까지)
+    pub fn recent_logs(&self, count: usize) -> &[ValidationLogEntry] {
+        let logs = self.retained_logs();
+        &logs[logs.len().saturating_sub(count)..]
+    }
+
     /// 설정 조회
     pub fn config(&self) -> &PhysicsValidatorConfig {
         &self.config
@@ -187,8 +1000,26 @@ pub struct KinematicsResult {
     pub velocity_ok: bool,
     pub acceleration_ok: bool,
     pub jerk_ok: bool,
+    pub angular_velocity_ok: bool,
+    pub angular_acceleration_ok: bool,
     pub actual_velocity: f32,
     pub actual_acceleration: f32,
+    /// 각속도 크기 (rad/s)
+    pub actual_angular_velocity: f32,
+    /// 각가속도 크기 (rad/s²)
+    pub actual_angular_acceleration: f32,
+}
+
+impl KinematicsResult {
+    /// 직선/회전 동역학 제한 모두 통과
+    #[inline]
+    pub fn within_limits(&self) -> bool {
+        self.velocity_ok
+            && self.acceleration_ok
+            && self.jerk_ok
+            && self.angular_velocity_ok
+            && self.angular_acceleration_ok
+    }
 }
 
 /// 충돌 예측 결과
@@ -197,12 +1028,37 @@ pub struct CollisionResult {
     pub will_collide: bool,
     pub time_to_collision: Option<f32>,
     pub nearest_obstacle_distance: f32,
+    /// 충돌 대상 장애물 인덱스 (obstacles 슬라이스 또는 정적 맵 기준)
+    pub obstacle_index: Option<usize>,
+}
+
+impl CollisionResult {
+    /// 두 결과 병합 (더 이른 충돌 우선, 최근접 거리는 최소값)
+    pub fn merge(self, other: CollisionResult) -> CollisionResult {
+        let nearest = self
+            .nearest_obstacle_distance
+            .min(other.nearest_obstacle_distance);
+        let mut chosen = match (self.will_collide, other.will_collide) {
+            (true, true) => {
+                let ttc = |r: &CollisionResult| r.time_to_collision.unwrap_or(0.0);
+                if ttc(&other) < ttc(&self) {
+                    other
+                } else {
+                    self
+                }
+            }
+            (false, true) => other,
+            _ => self,
+        };
+        chosen.nearest_obstacle_distance = nearest;
+        chosen
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use sap_core::types::{Acceleration, Velocity};
+    use sap_core::types::{Acceleration, Covariance3, Velocity};
 
     fn create_test_command(vel_magnitude: f32) -> MotionCommand {
         MotionCommand {
@@ -210,6 +1066,8 @@ mod tests {
             current_position: Position::ORIGIN,
             target_velocity: Velocity::new(vel_magnitude, 0.0, 0.0),
             target_acceleration: Acceleration::new(1.0, 0.0, 0.0),
+            target_angular_velocity: 0.0,
+            target_angular_acceleration: 0.0,
             ticket_id: 1,
         }
     }
@@ -242,6 +1100,510 @@ mod tests {
         assert_eq!(result, ValidationResult::REJECT);
     }
 
+    #[test]
+    fn test_validate_detailed_adjust_command() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let mut cmd = create_test_command(10.0);
+        cmd.target_acceleration = Acceleration::new(0.0, 20.0, 0.0);
+
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+
+        let detail = &report.detail;
+        assert!(detail.is_failed(constraint_ids::VELOCITY_LIMIT));
+        assert!(detail.is_failed(constraint_ids::ACCELERATION_LIMIT));
+        assert!(detail.is_passed(constraint_ids::COLLISION_PREDICTION));
+        assert_eq!(detail.violations.len(), 2);
+        assert!(matches!(
+            detail.reason,
+            Some(ValidationReason::VelocityExceeded { .. })
+        ));
+
+        let adjusted = report.adjusted_command.as_ref().unwrap();
+        assert!((adjusted.target_speed() - 5.0).abs() < 0.01);
+        assert!((adjusted.target_acceleration.magnitude() - 9.8).abs() < 0.01);
+        assert!(adjusted.target_velocity.vx > 0.0); // 방향 유지
+
+        let summary = detail.adjusted_command.as_ref().unwrap();
+        assert!((summary.scale_factor - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_validate_detailed_reject_has_no_command() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(10.0);
+        let obstacles = vec![Position::new(0.5, 0.0, 0.0)];
+
+        let report = validator.validate_detailed(&cmd, &obstacles, 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.command_to_execute(&cmd).is_none());
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::CollisionPredicted { obstacle_id: 0, .. })
+        ));
+        // 충돌과 속도 위반 모두 기록
+        assert!(report
+            .detail
+            .is_failed(constraint_ids::COLLISION_PREDICTION));
+        assert!(report.detail.is_failed(constraint_ids::VELOCITY_LIMIT));
+    }
+
+    #[test]
+    fn test_validate_with_state_rejects_unverified_detour() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = MotionCommand::new(1).with_velocity(Velocity::new(1.5, 1.5, 0.0));
+        let state = FilterState::new(Velocity::new(1.5, 1.5, 0.0), Acceleration::ZERO);
+        let obstacles = vec![Position::new(1.5, 0.0, 0.0)];
+
+        // 상태 없는 검증은 충돌 예측으로 REJECT
+        let plain = validator.validate_detailed(&cmd, &obstacles, 0);
+        assert_eq!(plain.result(), ValidationResult::REJECT);
+
+        // 필터 우회 명령도 예측 범위 안에서 장애물에 접근하므로 REJECT 유지
+        let report = validator.validate_with_state(&cmd, &state, &obstacles, &[], 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.adjusted_command.is_none());
+    }
+
+    #[test]
+    fn test_validate_with_state_adjusts_verified_command() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(5.0);
+        let obstacles = vec![Position::new(2.5, 0.0, 0.0)];
+
+        // 5 m/s로는 예측 범위 안에 안전 거리 침범
+        let plain = validator.validate_detailed(&cmd, &obstacles, 0);
+        assert_eq!(plain.result(), ValidationResult::REJECT);
+
+        // 정지 상태에서는 제어 주기 동안 도달 가능한 속도로 제한되어 충돌 없음
+        let report =
+            validator.validate_with_state(&cmd, &FilterState::at_rest(), &obstacles, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        assert!(report
+            .detail
+            .is_passed(constraint_ids::COLLISION_PREDICTION));
+        assert!(report.detail.collision_ok);
+        let adjusted = report.adjusted_command.unwrap();
+        assert!(adjusted.target_speed() < 1.5);
+        assert!(adjusted.target_velocity.vx > 0.0);
+    }
+
+    #[test]
+    fn test_validate_with_state_rejects_geofence_breach() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(1.0);
+        let fence = Geofence::keep_out(
+            9,
+            Position::new(-1.0, -1.0, -1.0),
+            Position::new(1.0, 1.0, 1.0),
+        );
+
+        let report = validator.validate_with_state(&cmd, &FilterState::at_rest(), &[], &[fence], 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.detail.is_failed(constraint_ids::GEOFENCE));
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::GeofenceViolation { geofence_id: 9 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_with_state_keeps_reject_for_static_map() {
+        let mut validator = PhysicsValidator::with_default_config();
+        validator.update_static_map(1, &[Position::new(0.5, 0.0, 0.0)]);
+        let cmd = create_test_command(1.0);
+        let state = FilterState::new(Velocity::new(1.0, 0.0, 0.0), Acceleration::ZERO);
+
+        assert_eq!(
+            validator.validate_detailed(&cmd, &[], 0).result(),
+            ValidationResult::REJECT
+        );
+
+        // 정적 맵 장애물이 안전 거리 내 - 필터가 충돌을 해소하지 못하면 REJECT 유지
+        let report = validator.validate_with_state(&cmd, &state, &[], &[], 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.adjusted_command.is_none());
+        assert!(report.detail.adjusted_command.is_none());
+    }
+
+    #[test]
+    fn test_validate_with_state_clamps_without_vehicle_profile() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(1.0)
+            .with_acceleration(Acceleration::new(50.0, 0.0, 0.0))
+            .with_angular(-10.0, 20.0);
+        let state = FilterState::new(Velocity::new(1.0, 0.0, 0.0), Acceleration::ZERO);
+
+        let report = validator.validate_with_state(&cmd, &state, &[], &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        let adjusted = report.adjusted_command.unwrap();
+        assert!(adjusted.target_acceleration.magnitude() <= Acceleration::GRAVITY + 1e-4);
+        assert_eq!(adjusted.target_angular_velocity, -std::f32::consts::PI);
+        assert_eq!(adjusted.target_angular_acceleration, std::f32::consts::TAU);
+    }
+
+    #[test]
+    fn test_detailed_frame_records_failures() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(10.0);
+
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        let frame = validator.create_detailed_frame(&cmd, &report.detail, 7, 1);
+
+        assert_eq!(frame.tick, 7);
+        assert!(frame.check_failed(constraint_ids::VELOCITY_LIMIT));
+        assert!(frame.check_constraint(constraint_ids::ACCELERATION_LIMIT));
+        assert!(!frame.check_constraint(constraint_ids::GEOFENCE));
+        assert!(!frame.check_failed(constraint_ids::GEOFENCE));
+    }
+
+    #[test]
+    fn test_validate_with_tickets() {
+        use crate::constraint::TicketConstraint;
+        use sap_core::ticket::{TransitTicket, VoxelGrid, VoxelTimeSlot};
+        use std::collections::HashMap;
+
+        let grid = VoxelGrid::new(Position::ORIGIN, 10.0, [2, 1, 1]);
+        let mut validator = PhysicsValidator::with_default_config()
+            .with_ticket_constraint(TicketConstraint::with_default_config(grid));
+        let ticket =
+            TransitTicket::new(1, 1, 1).with_vts(vec![VoxelTimeSlot::new(0, 0, 1_000_000_000)]);
+        let store = HashMap::from([(1, ticket)]);
+
+        let cmd = create_test_command(2.0).with_position(Position::new(1.0, 1.0, 1.0));
+        let report = validator.validate_with_tickets(&cmd, &[], &store, 0);
+        assert_eq!(report.result(), ValidationResult::OK);
+        assert!(report.detail.is_passed(constraint_ids::TICKET_VALIDITY));
+        assert!(report.detail.is_passed(constraint_ids::VTS_COMPLIANCE));
+
+        // 속도 초과(ADJUST)라도 티켓이 없으면 REJECT
+        let no_ticket = create_test_command(10.0)
+            .with_position(Position::new(1.0, 1.0, 1.0))
+            .with_ticket(2);
+        let report = validator.validate_with_tickets(&no_ticket, &[], &store, 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.adjusted_command.is_none());
+        assert!(report.detail.is_failed(constraint_ids::TICKET_VALIDITY));
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::InvalidTicket { ticket_id: 2 })
+        ));
+
+        // 예약 안 된 복셀 1로 진입
+        let outside = create_test_command(2.0).with_position(Position::new(9.9, 1.0, 1.0));
+        let report = validator.validate_with_tickets(&outside, &[], &store, 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report.detail.is_passed(constraint_ids::TICKET_VALIDITY));
+        assert!(report.detail.is_failed(constraint_ids::VTS_COMPLIANCE));
+
+        // 원 명령은 복셀 1로 넘어가지만 감속된 명령은 복셀 0에 머묾
+        let fast = create_test_command(10.0).with_position(Position::new(9.3, 1.0, 1.0));
+        let report = validator.validate_with_tickets(&fast, &[], &store, 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        assert!(report.detail.is_passed(constraint_ids::VTS_COMPLIANCE));
+    }
+
+    #[test]
+    fn test_static_map_index() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let map: Vec<Position> = (0..1_000)
+            .map(|i| Position::new(10.0 + (i % 50) as f32, (i / 50) as f32, 0.0))
+            .collect();
+
+        assert!(validator.update_static_map(1, &map));
+        assert!(!validator.update_static_map(1, &map)); // 동일 버전은 재생성 안 함
+        assert_eq!(validator.static_map_version(), Some(1));
+
+        // 정적 맵 장애물 방향으로 이동 (x=10 벽까지 8.5m, 5 m/s)
+        let mut cmd = create_test_command(4.0);
+        cmd.current_position = Position::new(8.5, 0.0, 0.0);
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+        assert!(report
+            .detail
+            .is_failed(constraint_ids::COLLISION_PREDICTION));
+
+        // 맵 교체 후에는 통과
+        assert!(validator.update_static_map(2, &[]));
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        assert_eq!(report.result(), ValidationResult::OK);
+    }
+
+    #[test]
+    fn test_vehicle_profile_limits() {
+        use crate::kinematics::VehicleProfile;
+
+        let mut validator = PhysicsValidator::with_default_config();
+        validator.register_vehicle(2, VehicleProfile::mecanum());
+
+        // 로봇 1: Zone 한계(5 m/s) 적용
+        let report = validator.validate_detailed(&create_test_command(2.0), &[], 0);
+        assert_eq!(report.result(), ValidationResult::OK);
+        assert!(!report.applied_limits.is_vehicle_bound());
+
+        // 로봇 2: 메카넘 한계(1.2 m/s) 적용
+        let mut cmd = create_test_command(2.0);
+        cmd.robot_id = 2;
+        cmd.target_acceleration = Acceleration::new(0.5, 0.0, 0.0);
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        assert_eq!(report.applied_limits.max_velocity, 1.2);
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::VelocityExceeded { limit, .. }) if limit == 1.2
+        ));
+        let adjusted = report.adjusted_command.unwrap();
+        assert!((adjusted.target_speed() - 1.2).abs() < 1e-5);
+
+        // 안전 필터 경로도 차량 한계를 넘지 않음
+        let report = validator.validate_with_state(&cmd, &FilterState::at_rest(), &[], &[], 0);
+        assert!(report.adjusted_command.unwrap().target_speed() <= 1.2 + 1e-5);
+
+        assert!(validator.unregister_vehicle(2).is_some());
+        assert!(!validator.limits_for(2).is_vehicle_bound());
+    }
+
+    #[test]
+    fn test_speed_separation_slows_near_human() {
+        // 차량 프로파일 없음 - Zone 가속도 한계로 정지 거리 계산
+        let config = PhysicsValidatorConfig {
+            max_acceleration: 3.0,
+            ..Default::default()
+        };
+        let mut validator = PhysicsValidator::new(config)
+            .with_speed_separation(SpeedSeparationMonitor::with_default_config());
+        let cmd = create_test_command(2.0);
+
+        // 멀리 있는 사람: 제한 없음
+        let far = [SeparationParty::human(
+            Position::new(6.0, 0.0, 0.0),
+            Velocity::ZERO,
+        )];
+        let report = validator.validate_with_separation(&cmd, &[], &far, 0);
+        assert_eq!(report.result(), ValidationResult::OK);
+        assert!(report.detail.is_passed(constraint_ids::SPEED_SEPARATION));
+
+        // 가까운 사람: 거부 대신 감속
+        let near = [SeparationParty::human(
+            Position::new(2.0, 0.0, 0.0),
+            Velocity::ZERO,
+        )];
+        let report = validator.validate_with_separation(&cmd, &[], &near, 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        assert!(report.detail.is_failed(constraint_ids::SPEED_SEPARATION));
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::SeparationSpeedExceeded { separation, .. }) if separation == 2.0
+        ));
+        let slowed = report.adjusted_command.unwrap().target_speed();
+        assert!(slowed > 0.0 && slowed < 2.0);
+
+        // 더 가까우면 더 느리게
+        let closer = [SeparationParty::human(
+            Position::new(1.5, 0.0, 0.0),
+            Velocity::ZERO,
+        )];
+        let report = validator.validate_with_separation(&cmd, &[], &closer, 0);
+        assert!(report.adjusted_command.unwrap().target_speed() < slowed);
+
+        // Zone 감속 능력이 크면 같은 거리에서도 제한 없음
+        let mut agile = PhysicsValidator::with_default_config()
+            .with_speed_separation(SpeedSeparationMonitor::with_default_config());
+        let report = agile.validate_with_separation(&cmd, &[], &near, 0);
+        assert_eq!(report.result(), ValidationResult::OK);
+    }
+
+    #[test]
+    fn test_speed_separation_turns_reject_into_adjust() {
+        let config = PhysicsValidatorConfig {
+            max_acceleration: 3.0,
+            collision_safety_distance: 0.5,
+            collision_horizon_secs: 0.5,
+            ..Default::default()
+        };
+        let monitor = SpeedSeparationMonitor::new(crate::constraint::SpeedSeparationConfig {
+            protective_separation: 0.5,
+            ..Default::default()
+        });
+        let mut validator = PhysicsValidator::new(config).with_speed_separation(monitor);
+        let cmd = create_test_command(4.0);
+        let obstacles = [Position::new(2.0, 0.0, 0.0)];
+
+        let report = validator.validate_detailed(&cmd, &obstacles, 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+
+        let report = validator.validate_with_separation(&cmd, &obstacles, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        // 최종 명령 기준으로 충돌 예측 위반 해제
+        assert!(report.detail.collision_ok);
+        assert!(report
+            .detail
+            .is_passed(constraint_ids::COLLISION_PREDICTION));
+        assert!(!matches!(
+            report.detail.reason,
+            Some(ValidationReason::CollisionPredicted { .. })
+        ));
+        let adjusted = report.adjusted_command.unwrap();
+        assert!(adjusted.target_speed() < 4.0);
+        assert!(report.detail.adjusted_command.unwrap().scale_factor < 1.0);
+
+        // 원 명령이 REJECT여도 조정 명령은 가속도/각속도 한계로 클램프
+        let aggressive = MotionCommand {
+            target_acceleration: Acceleration::new(20.0, 0.0, 0.0),
+            ..cmd.clone()
+        }
+        .with_angular(10.0, 0.0);
+        let report = validator.validate_with_separation(&aggressive, &obstacles, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        let adjusted = report.adjusted_command.unwrap();
+        assert!(adjusted.target_acceleration.magnitude() <= 3.0 + 1e-4);
+        assert!(adjusted.target_angular_velocity <= validator.config().max_angular_velocity);
+
+        // 감속해도 충돌이면 REJECT 유지
+        let touching = [Position::new(0.3, 0.0, 0.0)];
+        let report = validator.validate_with_separation(&cmd, &touching, &[], 0);
+        assert_eq!(report.result(), ValidationResult::REJECT);
+    }
+
+    #[test]
+    fn test_uncertainty_rejects_and_slows() {
+        let mut validator = PhysicsValidator::with_default_config()
+            .with_uncertainty(UncertainCollisionChecker::with_default_config());
+        let cmd = create_test_command(1.0);
+        // 진행 경로 옆 2.5m - 정확한 위치 기준으로는 안전
+        let obstacles = [DynamicObstacle::new(
+            42,
+            Position::new(3.0, 2.5, 0.0),
+            Velocity::ZERO,
+            0.3,
+        )];
+
+        let precise = RobotState::new(1);
+        let report = validator.validate_with_uncertainty(&cmd, &precise, &obstacles, 0);
+        assert_eq!(report.detail.result, ValidationResult::OK);
+
+        // 위치 오차 1m - 충돌 확률 임계값 초과
+        let lost =
+            RobotState::new(1).with_covariance(Covariance3::isotropic(1.0), Covariance3::ZERO);
+        let report = validator.validate_with_uncertainty(&cmd, &lost, &obstacles, 0);
+        assert_eq!(report.detail.result, ValidationResult::REJECT);
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::CollisionProbabilityExceeded {
+                obstacle_id: 42,
+                ..
+            })
+        ));
+
+        // 장애물 없이 오차 0.4m - 감속 ADJUST
+        let drifting =
+            RobotState::new(1).with_covariance(Covariance3::isotropic(0.4), Covariance3::ZERO);
+        let report = validator.validate_with_uncertainty(&cmd, &drifting, &[], 0);
+        assert_eq!(report.detail.result, ValidationResult::ADJUST);
+        assert!(report
+            .detail
+            .is_failed(constraint_ids::POSITION_UNCERTAINTY));
+        let slowed = report.adjusted_command.unwrap();
+        assert!(slowed.target_speed() < cmd.target_speed());
+    }
+
+    #[test]
+    fn test_uncertainty_slowdown_is_clamped_and_verified() {
+        let mut validator = PhysicsValidator::with_default_config()
+            .with_uncertainty(UncertainCollisionChecker::with_default_config());
+        let cmd = create_test_command(8.0)
+            .with_acceleration(Acceleration::new(20.0, 0.0, 0.0))
+            .with_angular(10.0, 0.0);
+        let drifting =
+            RobotState::new(1).with_covariance(Covariance3::isotropic(0.4), Covariance3::ZERO);
+
+        let report = validator.validate_with_uncertainty(&cmd, &drifting, &[], 0);
+        assert_eq!(report.result(), ValidationResult::ADJUST);
+        assert!(report
+            .detail
+            .is_failed(constraint_ids::POSITION_UNCERTAINTY));
+        assert!(report
+            .detail
+            .is_passed(constraint_ids::COLLISION_PREDICTION));
+        let slowed = report.adjusted_command.unwrap();
+        assert!(slowed.target_speed() < 5.0);
+        assert!(slowed.target_acceleration.magnitude() <= Acceleration::GRAVITY + 1e-4);
+        assert_eq!(slowed.target_angular_velocity, std::f32::consts::PI);
+    }
+
+    #[test]
+    fn test_angular_limits_clamped_on_adjust() {
+        let mut validator = PhysicsValidator::with_default_config();
+        validator.register_vehicle(1, VehicleProfile::agv());
+        let cmd = create_test_command(1.0).with_angular(-0.8, 0.5);
+
+        let report = validator.validate_detailed(&cmd, &[], 0);
+        assert_eq!(report.detail.result, ValidationResult::ADJUST);
+        assert!(!report
+            .detail
+            .is_passed(constraint_ids::ANGULAR_VELOCITY_LIMIT));
+        assert!(report
+            .detail
+            .is_passed(constraint_ids::ANGULAR_ACCELERATION_LIMIT));
+        assert!(matches!(
+            report.detail.reason,
+            Some(ValidationReason::AngularVelocityExceeded { limit, .. }) if limit == 0.5
+        ));
+
+        // 부호 유지한 채 AGV 한계로 클램핑
+        let adjusted = report.adjusted_command.unwrap();
+        assert_eq!(adjusted.target_angular_velocity, -0.5);
+        let detail = report.detail.adjusted_command.unwrap();
+        assert_eq!(detail.adjusted_angular_velocity, -0.5);
+    }
+
+    #[test]
+    fn test_validate_batch_matches_scalar() {
+        let mut scalar = PhysicsValidator::with_default_config();
+        let mut batched = PhysicsValidator::with_default_config();
+        for validator in [&mut scalar, &mut batched] {
+            validator.update_static_map(1, &[Position::new(-3.0, 0.0, 0.0)]);
+            validator.register_vehicle(7, VehicleProfile::agv());
+        }
+
+        let obstacles = [Position::new(3.0, 0.0, 0.0), Position::new(0.0, 0.5, 0.0)];
+        let commands: Vec<MotionCommand> = [
+            (1, Position::new(0.0, -10.0, 0.0), 2.0), // OK
+            (2, Position::new(0.0, -10.0, 0.0), 6.0), // 속도 초과 → ADJUST
+            (3, Position::new(1.0, 0.0, 0.0), 2.0),   // 장애물 방향 → REJECT
+            (4, Position::new(-1.0, 0.0, 0.0), -4.0), // 정적 맵 방향 → REJECT
+            (7, Position::new(0.0, -10.0, 0.0), 2.0), // AGV 한계 초과 → ADJUST
+        ]
+        .into_iter()
+        .map(|(id, position, speed)| {
+            MotionCommand::new(id)
+                .with_position(position)
+                .with_velocity(Velocity::new(speed, 0.0, 0.0))
+                .with_angular(if id == 2 { 5.0 } else { 0.0 }, 0.0)
+        })
+        .collect();
+
+        let mut batch = CommandBatch::from_commands(&commands);
+        batch.set_obstacles(&obstacles);
+        let mut out = BatchResults::new();
+        batched.validate_batch(&batch, 0, &mut out);
+
+        assert_eq!(out.len(), commands.len());
+        for (i, cmd) in commands.iter().enumerate() {
+            let report = scalar.validate_detailed(cmd, &obstacles, 0);
+            assert_eq!(out.result(i), report.result(), "command {i}");
+            assert_eq!(
+                out.failed_bitmap(i),
+                report.detail.constraints_failed_bitmap & batch::BATCH_CONSTRAINTS_MASK,
+                "command {i}"
+            );
+        }
+        assert_eq!(out.counts(), (1, 2, 2));
+        assert!(out.time_to_collision(0).is_none());
+        assert_eq!(out.time_to_collision(3), Some(0.25)); // 정적 맵: (2m - 1m) / 4m/s
+        assert_eq!(batched.recent_logs(10).len(), commands.len());
+    }
+
     #[test]
     fn test_validation_frame_creation() {
         let validator = PhysicsValidator::with_default_config();
@@ -266,5 +1628,32 @@ mod tests {
         let logs = validator.recent_logs(2);
         assert_eq!(logs.len(), 2);
         assert_eq!(logs[1].timestamp_ns, 3000);
+        assert_eq!(
+            logs[1].details(),
+            "vel_ok=true, accel_ok=true, collision=false"
+        );
+    }
+
+    #[test]
+    fn test_validation_log_capacity() {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = create_test_command(2.0);
+
+        // 버퍼가 용량을 넘었어도 최근 용량만큼만 노출
+        for tick in 0..1_500u64 {
+            validator.validate(&cmd, &[], tick);
+        }
+        let logs = validator.recent_logs(usize::MAX);
+        assert_eq!(logs.len(), 1000);
+        assert_eq!(logs[0].timestamp_ns, 500);
+
+        for tick in 1_500..2_500u64 {
+            validator.validate(&cmd, &[], tick);
+        }
+
+        let logs = validator.recent_logs(usize::MAX);
+        assert_eq!(logs.len(), 1000);
+        assert_eq!(logs[0].timestamp_ns, 1500);
+        assert_eq!(logs[999].timestamp_ns, 2499);
     }
 }
@@ -0,0 +1,392 @@
+//! ProofAccumulator - 검증 프레임 → 서명된 ProofDigest 집계기
+//!
+//! Zone의 검증 프레임을 틱 윈도우 단위로 모아, 윈도우가 끝나면
+//! Merkle Root를 계산하고 Edge 키로 서명한 `ProofDigest`를 발행한다.
+//! 봉인된 윈도우의 프레임은 보관되어 개별 포함 증명을 제공할 수 있다.
+//!
+//! PPR 매핑: AI_process_ProofAccumulation
+
+use sap_core::{
+    crypto::{PublicKey, Signer},
+    util::{compute_merkle_proof, compute_merkle_root, verify_merkle_proof},
+    validation::{ProofDigest, ValidationFrame},
+};
+use std::collections::VecDeque;
+
+/// 증명 집계 설정
+#[derive(Debug, Clone)]
+pub struct ProofAccumulatorConfig {
+    /// 다이제스트 윈도우 크기 (틱)
+    pub window_ticks: u64,
+
+    /// 보관할 봉인 윈도우 수 (초과 시 오래된 것부터 삭제)
+    pub retained_windows: usize,
+}
+
+impl Default for ProofAccumulatorConfig {
+    fn default() -> Self {
+        Self {
+            window_ticks: 100,     // 10Hz 기준 10초
+            retained_windows: 360, // 1시간
+        }
+    }
+}
+
+/// 프레임 집계 에러
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccumulateError {
+    /// 다른 Zone의 프레임
+    ZoneMismatch { expected: u32, actual: u32 },
+    /// 이미 지나간 윈도우의 프레임 (window_start: 허용되는 가장 이른 윈도우)
+    LateFrame { tick: u64, window_start: u64 },
+}
+
+/// 단일 프레임 포함 증명
+#[derive(Debug, Clone)]
+pub struct InclusionProof {
+    /// 대상 프레임
+    pub frame: ValidationFrame,
+
+    /// 윈도우 내 잎 인덱스
+    pub leaf_index: usize,
+
+    /// 잎에서 루트까지의 형제 해시
+    pub siblings: Vec<[u8; 32]>,
+
+    /// 프레임이 포함된 서명 다이제스트
+    pub digest: ProofDigest,
+}
+
+impl InclusionProof {
+    /// 프레임이 다이제스트의 Merkle Root에 포함되는지 검증
+    ///
+    /// 다이제스트 서명 검증은 공개키를 가진 검증자가 별도로 수행한다.
+    pub fn verify(&self) -> bool {
+        self.frame.zone_id == self.digest.zone_id
+            && self.frame.tick >= self.digest.from_tick
+            && self.frame.tick < self.digest.to_tick
+            && verify_merkle_proof(
+                &self.frame.leaf_hash(),
+                self.leaf_index,
+                &self.siblings,
+                &self.digest.merkle_root,
+            )
+    }
+}
+
+/// 봉인된 윈도우
+#[derive(Debug, Clone)]
+struct SealedWindow {
+    digest: ProofDigest,
+    frames: Vec<ValidationFrame>,
+    leaves: Vec<[u8; 32]>,
+}
+
+/// 검증 프레임 집계기
+///
+/// 윈도우는 `window_ticks`의 배수로 정렬되며, 다이제스트의 `to_tick`은
+/// 윈도우 끝(미포함)이다. 프레임이 없는 윈도우는 다이제스트를 만들지 않는다.
+///
+/// PPR: AI_process_ProofAccumulation(frames, edge_key) -> ProofDigest
+pub struct ProofAccumulator<S: Signer> {
+    zone_id: u32,
+    config: ProofAccumulatorConfig,
+    signer: S,
+    /// 열린 윈도우 시작 틱 (프레임이 없으면 None)
+    window_start: Option<u64>,
+    pending: Vec<ValidationFrame>,
+    sealed: VecDeque<SealedWindow>,
+}
+
+impl<S: Signer> ProofAccumulator<S> {
+    /// 새 ProofAccumulator 생성
+    pub fn new(zone_id: u32, signer: S, config: ProofAccumulatorConfig) -> Self {
+        Self {
+            zone_id,
+            config: ProofAccumulatorConfig {
+                window_ticks: config.window_ticks.max(1),
+                ..config
+            },
+            signer,
+            window_start: None,
+            pending: Vec::new(),
+            sealed: VecDeque::new(),
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config(zone_id: u32, signer: S) -> Self {
+        Self::new(zone_id, signer, ProofAccumulatorConfig::default())
+    }
+
+    /// Zone ID
+    pub fn zone_id(&self) -> u32 {
+        self.zone_id
+    }
+
+    /// 서명 공개키
+    pub fn public_key(&self) -> PublicKey {
+        self.signer.public_key()
+    }
+
+    /// 열린 윈도우의 프레임 수
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// 보관 중인 다이제스트 (오래된 순)
+    pub fn digests(&self) -> impl Iterator<Item = &ProofDigest> {
+        self.sealed.iter().map(|window| &window.digest)
+    }
+
+    /// 프레임 추가
+    ///
+    /// 프레임이 다음 윈도우에 속하면 열린 윈도우를 먼저 봉인하고
+    /// 그 다이제스트를 반환한다.
+    pub fn push(
+        &mut self,
+        frame: ValidationFrame,
+        now_ns: u64,
+    ) -> Result<Option<ProofDigest>, AccumulateError> {
+        if frame.zone_id != self.zone_id {
+            return Err(AccumulateError::ZoneMismatch {
+                expected: self.zone_id,
+                actual: frame.zone_id,
+            });
+        }
+
+        let start = self.window_of(frame.tick);
+        let earliest = self
+            .window_start
+            .or_else(|| self.sealed.back().map(|window| window.digest.to_tick));
+        if let Some(window_start) = earliest.filter(|&earliest| start < earliest) {
+            return Err(AccumulateError::LateFrame {
+                tick: frame.tick,
+                window_start,
+            });
+        }
+
+        let digest = match self.window_start {
+            Some(current) if start > current => self.seal(now_ns),
+            _ => None,
+        };
+        self.window_start = Some(start);
+        self.pending.push(frame);
+        Ok(digest)
+    }
+
+    /// 틱 진행 - 열린 윈도우가 끝났으면 봉인
+    pub fn advance(&mut self, tick: u64, now_ns: u64) -> Option<ProofDigest> {
+        match self.window_start {
+            Some(start) if tick >= start + self.config.window_ticks => self.seal(now_ns),
+            _ => None,
+        }
+    }
+
+    /// 열린 윈도우 강제 봉인 (종료 시)
+    pub fn flush(&mut self, now_ns: u64) -> Option<ProofDigest> {
+        self.seal(now_ns)
+    }
+
+    /// 프레임 포함 증명 조회
+    ///
+    /// 봉인되어 보관 중인 윈도우에서 (tick, robot_id) 프레임을 찾는다.
+    pub fn inclusion_proof(&self, tick: u64, robot_id: u64) -> Option<InclusionProof> {
+        let start = self.window_of(tick);
+        let window = self
+            .sealed
+            .iter()
+            .find(|window| window.digest.from_tick == start)?;
+        let leaf_index = window
+            .frames
+            .iter()
+            .position(|frame| frame.tick == tick && frame.robot_id == robot_id)?;
+
+        Some(InclusionProof {
+            frame: window.frames[leaf_index].clone(),
+            leaf_index,
+            siblings: compute_merkle_proof(&window.leaves, leaf_index)?,
+            digest: window.digest.clone(),
+        })
+    }
+
+    fn window_of(&self, tick: u64) -> u64 {
+        tick - tick % self.config.window_ticks
+    }
+
+    fn seal(&mut self, now_ns: u64) -> Option<ProofDigest> {
+        let start = self.window_start.take()?;
+        let frames = std::mem::take(&mut self.pending);
+        let leaves: Vec<[u8; 32]> = frames.iter().map(ValidationFrame::leaf_hash).collect();
+
+        let mut digest = ProofDigest::new(start, start + self.config.window_ticks, self.zone_id)
+            .with_merkle_root(compute_merkle_root(&leaves))
+            .with_frame_count(frames.len() as u32);
+        digest.created_at_ns = now_ns;
+        let signature = self.signer.sign(&digest.signing_bytes());
+        let digest = digest.with_signature(signature.as_bytes());
+
+        self.sealed.push_back(SealedWindow {
+            digest: digest.clone(),
+            frames,
+            leaves,
+        });
+        while self.sealed.len() > self.config.retained_windows {
+            self.sealed.pop_front();
+        }
+
+        Some(digest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::MotionCommand;
+    use crate::validator::PhysicsValidator;
+    use sap_core::crypto::Signature;
+    use sap_core::types::Velocity;
+    use sap_core::util::compute_hash_bytes;
+
+    /// 테스트용 서명자 (키 + 메시지 해시)
+    struct TestSigner(u8);
+
+    impl Signer for TestSigner {
+        fn public_key(&self) -> PublicKey {
+            PublicKey::from_bytes([self.0; 32])
+        }
+
+        fn sign(&self, message: &[u8]) -> Signature {
+            let mut keyed = vec![self.0];
+            keyed.extend_from_slice(message);
+            let hash = compute_hash_bytes(&keyed);
+            let mut bytes = [self.0; 64];
+            bytes[..32].copy_from_slice(&hash);
+            Signature::from_bytes(bytes)
+        }
+    }
+
+    fn accumulator() -> ProofAccumulator<TestSigner> {
+        ProofAccumulator::new(
+            1,
+            TestSigner(7),
+            ProofAccumulatorConfig {
+                window_ticks: 10,
+                retained_windows: 2,
+            },
+        )
+    }
+
+    fn frame(tick: u64, robot_id: u64) -> ValidationFrame {
+        let mut validator = PhysicsValidator::with_default_config();
+        let cmd = MotionCommand::new(robot_id).with_velocity(Velocity::new(1.0, 0.0, 0.0));
+        let report = validator.validate_detailed(&cmd, &[], tick);
+        validator.create_detailed_frame(&cmd, &report.detail, tick, 1)
+    }
+
+    #[test]
+    fn test_window_sealed_on_next_window_frame() {
+        let mut acc = accumulator();
+        for tick in 0..10 {
+            assert!(acc.push(frame(tick, 1), tick).unwrap().is_none());
+            assert!(acc.push(frame(tick, 2), tick).unwrap().is_none());
+        }
+        assert_eq!(acc.pending_count(), 20);
+
+        let digest = acc.push(frame(10, 1), 999).unwrap().unwrap();
+        assert_eq!((digest.from_tick, digest.to_tick), (0, 10));
+        assert_eq!(digest.frame_count, 20);
+        assert_eq!(digest.zone_id, 1);
+        assert_eq!(digest.created_at_ns, 999);
+        assert!(digest.has_merkle_root());
+        assert!(digest.is_signed());
+        assert_eq!(
+            digest.tos_sig,
+            acc.signer.sign(&digest.signing_bytes()).to_vec()
+        );
+        assert_eq!(acc.pending_count(), 1);
+    }
+
+    #[test]
+    fn test_advance_and_flush() {
+        let mut acc = accumulator();
+        acc.push(frame(3, 1), 0).unwrap();
+        assert!(acc.advance(9, 0).is_none());
+        let digest = acc.advance(10, 0).unwrap();
+        assert_eq!(digest.frame_count, 1);
+
+        // 빈 윈도우는 다이제스트 없음
+        assert!(acc.advance(20, 0).is_none());
+        assert!(acc.flush(0).is_none());
+
+        acc.push(frame(25, 1), 0).unwrap();
+        assert_eq!(acc.flush(0).unwrap().from_tick, 20);
+        assert_eq!(acc.digests().count(), 2);
+    }
+
+    #[test]
+    fn test_inclusion_proof() {
+        let mut acc = accumulator();
+        for tick in 0..10 {
+            for robot in 1..=3 {
+                acc.push(frame(tick, robot), 0).unwrap();
+            }
+        }
+        acc.flush(0);
+
+        let proof = acc.inclusion_proof(4, 2).unwrap();
+        assert_eq!(proof.frame.tick, 4);
+        assert_eq!(proof.frame.robot_id, 2);
+        assert!(proof.verify());
+
+        // 변조된 프레임은 검증 실패
+        let mut tampered = proof.clone();
+        tampered.frame.constraints_failed_bitmap ^= 1;
+        assert!(!tampered.verify());
+
+        assert!(acc.inclusion_proof(4, 9).is_none());
+        assert!(acc.inclusion_proof(50, 1).is_none());
+    }
+
+    #[test]
+    fn test_rejects_foreign_and_late_frames() {
+        let mut acc = accumulator();
+        let mut foreign = frame(0, 1);
+        foreign.zone_id = 2;
+        assert!(matches!(
+            acc.push(foreign, 0),
+            Err(AccumulateError::ZoneMismatch {
+                expected: 1,
+                actual: 2
+            })
+        ));
+
+        acc.push(frame(12, 1), 0).unwrap();
+        assert!(matches!(
+            acc.push(frame(5, 1), 0),
+            Err(AccumulateError::LateFrame { tick: 5, .. })
+        ));
+        acc.advance(20, 0);
+        assert!(matches!(
+            acc.push(frame(15, 1), 0),
+            Err(AccumulateError::LateFrame {
+                window_start: 20,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_retention_evicts_oldest_windows() {
+        let mut acc = accumulator();
+        for window in 0..4 {
+            acc.push(frame(window * 10, 1), 0).unwrap();
+        }
+        acc.flush(0);
+
+        let starts: Vec<u64> = acc.digests().map(|d| d.from_tick).collect();
+        assert_eq!(starts, vec![20, 30]);
+        assert!(acc.inclusion_proof(0, 1).is_none());
+        assert!(acc.inclusion_proof(30, 1).unwrap().verify());
+    }
+}
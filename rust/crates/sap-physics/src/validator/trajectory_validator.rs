@@ -0,0 +1,614 @@
+//! TrajectoryValidator - 궤적 전체 검증기
+//!
+//! 시간 매개변수화된 궤적의 모든 구간을 시간순으로 검사하여
+//! 첫 번째 위반 시각과 이유를 반환한다.
+//!
+//! 검사 항목:
+//! - 동역학: 샘플별 속도/가속도, 구간별 저크,
+//!   샘플 위치 변화로 계산한 구간 속도(Δp/Δt)와 인접 구간 간 가속도
+//! - 정적 장애물 이격 거리
+//! - 동적 장애물 이격 거리 (관측 시각 기준 등속 외삽)
+//! - TransitTicket 준수: 각 시각에 해당 복셀의 VTS 보유 여부
+//!
+//! PPR 매핑: AI_process_TrajectoryValidation
+
+use super::PhysicsValidatorConfig;
+use crate::constraint::{SpatialIndex, OUT_OF_GRID_VOXEL};
+use sap_core::{
+    ticket::{TransitTicket, VoxelGrid},
+    types::{DynamicObstacle, Position, Trajectory, TrajectoryPoint, Velocity},
+    validation::ValidationReason,
+};
+
+/// 샘플 위치로 계산한 속도/가속도 허용 여유 (f32 위치 양자화와 이산 미분 오차)
+const IMPLIED_MARGIN: f32 = 1.01;
+
+/// 궤적 검증 설정
+#[derive(Debug, Clone)]
+pub struct TrajectoryValidatorConfig {
+    /// 동역학/충돌 한계
+    pub limits: PhysicsValidatorConfig,
+
+    /// 구간 내 이격 거리 검사 최대 샘플 간격 (나노초)
+    pub max_sample_interval_ns: u64,
+}
+
+impl Default for TrajectoryValidatorConfig {
+    fn default() -> Self {
+        Self {
+            limits: PhysicsValidatorConfig::default(),
+            max_sample_interval_ns: 50_000_000, // 50ms
+        }
+    }
+}
+
+/// 검증 환경 (장애물 + 티켓)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrajectoryContext<'a> {
+    /// 정적 장애물
+    pub static_obstacles: &'a [Position],
+
+    /// 정적 장애물 공간 인덱스 (설정 시 `static_obstacles` 대신 사용)
+    pub static_index: Option<&'a SpatialIndex>,
+
+    /// 동적 장애물
+    pub dynamic_obstacles: &'a [DynamicObstacle],
+
+    /// 동적 장애물 관측 시각 (나노초)
+    pub observed_at_ns: u64,
+
+    /// 로봇의 통행 티켓 (None이면 티켓 검사 생략)
+    pub ticket: Option<&'a TransitTicket>,
+}
+
+impl<'a> TrajectoryContext<'a> {
+    /// 빈 환경 생성
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 정적 장애물 설정
+    pub fn with_static_obstacles(mut self, obstacles: &'a [Position]) -> Self {
+        self.static_obstacles = obstacles;
+        self
+    }
+
+    /// 정적 장애물 공간 인덱스 설정
+    pub fn with_static_index(mut self, index: &'a SpatialIndex) -> Self {
+        self.static_index = Some(index);
+        self
+    }
+
+    /// 동적 장애물 설정
+    pub fn with_dynamic_obstacles(
+        mut self,
+        obstacles: &'a [DynamicObstacle],
+        observed_at_ns: u64,
+    ) -> Self {
+        self.dynamic_obstacles = obstacles;
+        self.observed_at_ns = observed_at_ns;
+        self
+    }
+
+    /// 티켓 설정
+    pub fn with_ticket(mut self, ticket: &'a TransitTicket) -> Self {
+        self.ticket = Some(ticket);
+        self
+    }
+}
+
+/// 궤적 위반 정보
+#[derive(Debug, Clone)]
+pub struct TrajectoryViolation {
+    /// 위반 시각 (나노초)
+    pub timestamp_ns: u64,
+
+    /// 위반이 발생한 구간 인덱스 (구간 i = 샘플 i → i+1, 첫 샘플은 0)
+    pub segment_index: usize,
+
+    /// 위반 위치
+    pub position: Position,
+
+    /// 위반 이유
+    pub reason: ValidationReason,
+}
+
+/// 궤적 검증 결과
+#[derive(Debug, Clone)]
+pub struct TrajectoryCheckResult {
+    /// 첫 번째 위반 (없으면 통과)
+    pub first_violation: Option<TrajectoryViolation>,
+
+    /// 검사한 샘플 수 (보간 샘플 포함)
+    pub samples_checked: usize,
+}
+
+impl TrajectoryCheckResult {
+    /// 통과 여부
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.first_violation.is_none()
+    }
+}
+
+/// 궤적 검증기
+///
+/// PPR: AI_process_TrajectoryValidation(trajectory, context) -> TrajectoryCheckResult
+#[derive(Debug, Clone)]
+pub struct TrajectoryValidator {
+    config: TrajectoryValidatorConfig,
+    voxel_grid: Option<VoxelGrid>,
+}
+
+impl TrajectoryValidator {
+    /// 새 TrajectoryValidator 생성
+    pub fn new(config: TrajectoryValidatorConfig) -> Self {
+        Self {
+            config,
+            voxel_grid: None,
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config() -> Self {
+        Self::new(TrajectoryValidatorConfig::default())
+    }
+
+    /// 복셀 격자 설정 (티켓 복셀 포함 여부 검사에 사용)
+    ///
+    /// 격자가 없으면 티켓 검사는 시간 슬롯 보유 여부만 확인한다.
+    pub fn with_voxel_grid(mut self, grid: VoxelGrid) -> Self {
+        self.voxel_grid = Some(grid);
+        self
+    }
+
+    /// 설정 조회
+    pub fn config(&self) -> &TrajectoryValidatorConfig {
+        &self.config
+    }
+
+    /// 궤적 검증
+    pub fn validate(
+        &self,
+        trajectory: &Trajectory,
+        context: &TrajectoryContext<'_>,
+    ) -> TrajectoryCheckResult {
+        let points = trajectory.points();
+        let start_ns = points.first().map_or(0, |p| p.timestamp_ns);
+        let mut samples_checked = 0;
+
+        if let Some(ticket) = context.ticket {
+            if ticket.robot_id != trajectory.robot_id {
+                if let Some(first) = points.first() {
+                    return TrajectoryCheckResult {
+                        first_violation: Some(TrajectoryViolation {
+                            timestamp_ns: first.timestamp_ns,
+                            segment_index: 0,
+                            position: first.position,
+                            reason: ValidationReason::InvalidTicket {
+                                ticket_id: ticket.ticket_id,
+                            },
+                        }),
+                        samples_checked,
+                    };
+                }
+            }
+        }
+
+        for (index, point) in points.iter().enumerate() {
+            // 직전 샘플과의 구간 내부 (보간 샘플)
+            if index > 0 {
+                let prev = &points[index - 1];
+                let segment = index - 1;
+                let span = point.timestamp_ns - prev.timestamp_ns;
+                let steps = span.div_ceil(self.config.max_sample_interval_ns.max(1));
+                for step in 1..steps {
+                    let t = prev.timestamp_ns + span * step / steps;
+                    let ratio = step as f32 / steps as f32;
+                    let pos = prev.position + (point.position - prev.position).scale(ratio);
+                    samples_checked += 1;
+                    if let Some(reason) = self.check_spatial(start_ns, t, &pos, context) {
+                        return Self::violation(t, segment, pos, reason, samples_checked);
+                    }
+                }
+            }
+
+            samples_checked += 1;
+            let segment = index.saturating_sub(1);
+            if let Some(reason) = self.check_kinematics(&points[..=index]).or_else(|| {
+                self.check_spatial(start_ns, point.timestamp_ns, &point.position, context)
+            }) {
+                return Self::violation(
+                    point.timestamp_ns,
+                    segment,
+                    point.position,
+                    reason,
+                    samples_checked,
+                );
+            }
+        }
+
+        TrajectoryCheckResult {
+            first_violation: None,
+            samples_checked,
+        }
+    }
+
+    fn violation(
+        timestamp_ns: u64,
+        segment_index: usize,
+        position: Position,
+        reason: ValidationReason,
+        samples_checked: usize,
+    ) -> TrajectoryCheckResult {
+        TrajectoryCheckResult {
+            first_violation: Some(TrajectoryViolation {
+                timestamp_ns,
+                segment_index,
+                position,
+                reason,
+            }),
+            samples_checked,
+        }
+    }
+
+    /// `history`의 마지막 샘플과 그 직전 구간들을 검사
+    fn check_kinematics(&self, history: &[TrajectoryPoint]) -> Option<ValidationReason> {
+        let limits = &self.config.limits;
+        let (point, earlier) = history.split_last()?;
+        let prev = earlier.last();
+
+        let speed = point.velocity.magnitude();
+        if speed > limits.max_velocity {
+            return Some(ValidationReason::VelocityExceeded {
+                actual: speed,
+                limit: limits.max_velocity,
+            });
+        }
+
+        let accel = point.acceleration.magnitude();
+        if accel > limits.max_acceleration {
+            return Some(ValidationReason::AccelerationExceeded {
+                actual: accel,
+                limit: limits.max_acceleration,
+            });
+        }
+
+        if let Some(prev) = prev {
+            let dt = (point.timestamp_ns - prev.timestamp_ns) as f32 / 1_000_000_000.0;
+            let jerk = point.acceleration.jerk(&prev.acceleration, dt);
+            if jerk > limits.max_jerk {
+                return Some(ValidationReason::JerkExceeded {
+                    actual: jerk,
+                    limit: limits.max_jerk,
+                });
+            }
+
+            // 선언된 속도와 무관하게 샘플 위치가 요구하는 속도
+            let implied = Self::segment_velocity(prev, point);
+            let implied_speed = implied.magnitude();
+            if implied_speed > limits.max_velocity * IMPLIED_MARGIN {
+                return Some(ValidationReason::VelocityExceeded {
+                    actual: implied_speed,
+                    limit: limits.max_velocity,
+                });
+            }
+
+            // 인접 구간 평균 속도 변화 / 구간 중점 간 시간
+            if let Some(before) = earlier.len().checked_sub(2).map(|i| &earlier[i]) {
+                let previous = Self::segment_velocity(before, prev);
+                let midpoint_dt = (point.timestamp_ns - before.timestamp_ns) as f32 / 2e9;
+                let change = Velocity::new(
+                    implied.vx - previous.vx,
+                    implied.vy - previous.vy,
+                    implied.vz - previous.vz,
+                );
+                let implied_accel = change.magnitude() / midpoint_dt;
+                if implied_accel > limits.max_acceleration * IMPLIED_MARGIN {
+                    return Some(ValidationReason::AccelerationExceeded {
+                        actual: implied_accel,
+                        limit: limits.max_acceleration,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+
+    /// 두 샘플 사이 평균 속도 (Δp/Δt, 타임스탬프는 엄격히 증가)
+    fn segment_velocity(from: &TrajectoryPoint, to: &TrajectoryPoint) -> Velocity {
+        let dt = (to.timestamp_ns - from.timestamp_ns) as f32 / 1_000_000_000.0;
+        let delta = to.position - from.position;
+        Velocity::new(delta.x / dt, delta.y / dt, delta.z / dt)
+    }
+
+    /// 충돌 시 `time_to_collision`은 궤적 시작 시각 기준 (초)
+    fn check_spatial(
+        &self,
+        start_ns: u64,
+        timestamp_ns: u64,
+        position: &Position,
+        context: &TrajectoryContext<'_>,
+    ) -> Option<ValidationReason> {
+        let safety = self.config.limits.collision_safety_distance;
+        let time_to_collision = timestamp_ns.saturating_sub(start_ns) as f32 / 1_000_000_000.0;
+
+        let static_hit = match context.static_index {
+            Some(index) => index.first_within(position, safety),
+            None => context
+                .static_obstacles
+                .iter()
+                .position(|obstacle| position.distance(obstacle) < safety),
+        };
+        if let Some(index) = static_hit {
+            return Some(ValidationReason::CollisionPredicted {
+                time_to_collision,
+                obstacle_id: index as u64,
+            });
+        }
+
+        // 관측 시각 이후 등속 운동 가정 (관측 이전 시각은 관측 위치 사용)
+        let elapsed = timestamp_ns.saturating_sub(context.observed_at_ns) as f32 / 1_000_000_000.0;
+        for obstacle in context.dynamic_obstacles {
+            let predicted = Position::new(
+                obstacle.position.x + obstacle.velocity.vx * elapsed,
+                obstacle.position.y + obstacle.velocity.vy * elapsed,
+                obstacle.position.z + obstacle.velocity.vz * elapsed,
+            );
+            if position.distance(&predicted) < safety + obstacle.radius {
+                return Some(ValidationReason::CollisionPredicted {
+                    time_to_collision,
+                    obstacle_id: obstacle.id,
+                });
+            }
+        }
+
+        context
+            .ticket
+            .and_then(|ticket| self.check_ticket(ticket, timestamp_ns, position))
+    }
+
+    fn check_ticket(
+        &self,
+        ticket: &TransitTicket,
+        timestamp_ns: u64,
+        position: &Position,
+    ) -> Option<ValidationReason> {
+        if !ticket.is_valid(timestamp_ns) {
+            return Some(ValidationReason::InvalidTicket {
+                ticket_id: ticket.ticket_id,
+            });
+        }
+
+        let voxel = match &self.voxel_grid {
+            Some(grid) => match grid.voxel_id(position) {
+                Some(id) => Some(id),
+                None => {
+                    return Some(ValidationReason::VTSViolation {
+                        vts_id: OUT_OF_GRID_VOXEL,
+                    })
+                }
+            },
+            None => None,
+        };
+
+        let covered = ticket.vts_list.iter().any(|vts| {
+            vts.contains_time(timestamp_ns) && voxel.is_none_or(|id| vts.voxel_id == id)
+        });
+
+        if covered {
+            None
+        } else {
+            Some(ValidationReason::VTSViolation {
+                vts_id: voxel.unwrap_or(OUT_OF_GRID_VOXEL),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::{ticket::VoxelTimeSlot, types::Acceleration};
+
+    const MS: u64 = 1_000_000;
+
+    /// x축으로 1 m/s 등속 이동하는 궤적 (0~4초, 1초 간격)
+    fn straight_trajectory() -> Trajectory {
+        let mut trajectory = Trajectory::new(1);
+        for i in 0..=4u64 {
+            trajectory.push(
+                TrajectoryPoint::new(i * 1000 * MS, Position::new(i as f32, 0.5, 0.5))
+                    .with_velocity(Velocity::new(1.0, 0.0, 0.0)),
+            );
+        }
+        trajectory
+    }
+
+    fn grid() -> VoxelGrid {
+        VoxelGrid::new(Position::ORIGIN, 1.0, [10, 1, 1])
+    }
+
+    /// 1초마다 다음 복셀을 예약한 티켓 (0~5초)
+    fn full_ticket() -> TransitTicket {
+        let vts = (0..5u64)
+            .map(|i| VoxelTimeSlot::new(i, i * 1000 * MS, (i + 1) * 1000 * MS))
+            .collect();
+        TransitTicket::new(9, 1, 1).with_vts(vts)
+    }
+
+    #[test]
+    fn test_valid_trajectory() {
+        let validator = TrajectoryValidator::with_default_config().with_voxel_grid(grid());
+        let ticket = full_ticket();
+        let context = TrajectoryContext::new().with_ticket(&ticket);
+
+        let result = validator.validate(&straight_trajectory(), &context);
+        assert!(result.is_valid(), "{:?}", result.first_violation);
+        // 5 샘플 + 구간당 19개 보간 샘플
+        assert_eq!(result.samples_checked, 5 + 4 * 19);
+    }
+
+    #[test]
+    fn test_velocity_violation_timestamp() {
+        let validator = TrajectoryValidator::with_default_config();
+        let mut points = straight_trajectory().points().to_vec();
+        points[3].velocity = Velocity::new(6.0, 0.0, 0.0);
+        let trajectory = Trajectory::from_points(1, points).unwrap();
+
+        let result = validator.validate(&trajectory, &TrajectoryContext::new());
+        let violation = result.first_violation.unwrap();
+        assert_eq!(violation.timestamp_ns, 3000 * MS);
+        assert_eq!(violation.segment_index, 2);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::VelocityExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn test_jerk_violation() {
+        let validator = TrajectoryValidator::with_default_config();
+        let mut trajectory = Trajectory::new(1);
+        trajectory.push(TrajectoryPoint::new(0, Position::ORIGIN));
+        trajectory.push(
+            TrajectoryPoint::new(100 * MS, Position::new(0.01, 0.0, 0.0))
+                .with_acceleration(Acceleration::new(8.0, 0.0, 0.0)),
+        );
+
+        let result = validator.validate(&trajectory, &TrajectoryContext::new());
+        let violation = result.first_violation.unwrap();
+        assert_eq!(violation.timestamp_ns, 100 * MS);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::JerkExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn test_segment_implied_kinematics() {
+        let validator = TrajectoryValidator::with_default_config();
+
+        // 선언 속도는 1 m/s지만 1초에 10m 이동
+        let mut points = straight_trajectory().points().to_vec();
+        for point in &mut points[2..] {
+            point.position.x += 9.0;
+        }
+        let teleport = Trajectory::from_points(1, points).unwrap();
+        let violation = validator
+            .validate(&teleport, &TrajectoryContext::new())
+            .first_violation
+            .unwrap();
+        assert_eq!(violation.timestamp_ns, 2000 * MS);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::VelocityExceeded { actual, .. } if (actual - 10.0).abs() < 1e-3
+        ));
+
+        // 구간 속도 1 → 4 m/s (한계 이내) 이지만 0.2초 만에 변화 - 15 m/s² 요구
+        let mut trajectory = Trajectory::new(1);
+        for (t, x) in [(0, 0.0), (200, 0.2), (400, 1.0)] {
+            trajectory.push(
+                TrajectoryPoint::new(t * MS, Position::new(x, 0.5, 0.5))
+                    .with_velocity(Velocity::new(1.0, 0.0, 0.0)),
+            );
+        }
+        let violation = validator
+            .validate(&trajectory, &TrajectoryContext::new())
+            .first_violation
+            .unwrap();
+        assert_eq!(violation.timestamp_ns, 400 * MS);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::AccelerationExceeded { actual, .. } if (actual - 15.0).abs() < 1e-2
+        ));
+    }
+
+    #[test]
+    fn test_static_obstacle_between_samples() {
+        let validator = TrajectoryValidator::with_default_config();
+        // 샘플(0.0, 1.0) 사이 0.5 지점 옆 장애물
+        let obstacles = [Position::new(0.5, 1.45, 0.5)];
+        let context = TrajectoryContext::new().with_static_obstacles(&obstacles);
+
+        let result = validator.validate(&straight_trajectory(), &context);
+        let violation = result.first_violation.unwrap();
+        assert_eq!(violation.segment_index, 0);
+        assert!(violation.timestamp_ns > 0 && violation.timestamp_ns < 1000 * MS);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::CollisionPredicted { obstacle_id: 0, .. }
+        ));
+
+        // 공간 인덱스 사용 시 동일한 결과
+        let index = SpatialIndex::build(&obstacles, 1.0, 1);
+        let indexed = TrajectoryContext::new().with_static_index(&index);
+        let indexed_result = validator.validate(&straight_trajectory(), &indexed);
+        assert_eq!(
+            indexed_result.first_violation.unwrap().timestamp_ns,
+            violation.timestamp_ns
+        );
+    }
+
+    #[test]
+    fn test_dynamic_obstacle_crossing() {
+        let validator = TrajectoryValidator::with_default_config();
+        // 2초 후 (2.5, 0.5) 지점을 지나는 사람
+        let obstacles = [DynamicObstacle::new(
+            77,
+            Position::new(2.5, 4.5, 0.5),
+            Velocity::new(0.0, -2.0, 0.0),
+            0.3,
+        )];
+        let context = TrajectoryContext::new().with_dynamic_obstacles(&obstacles, 0);
+
+        let result = validator.validate(&straight_trajectory(), &context);
+        let violation = result.first_violation.unwrap();
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::CollisionPredicted {
+                obstacle_id: 77,
+                ..
+            }
+        ));
+        assert!(violation.timestamp_ns > 1000 * MS && violation.timestamp_ns <= 2000 * MS);
+        let ValidationReason::CollisionPredicted {
+            time_to_collision, ..
+        } = violation.reason
+        else {
+            unreachable!()
+        };
+        assert!((time_to_collision - violation.timestamp_ns as f32 / 1e9).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_missing_vts_reports_voxel() {
+        let validator = TrajectoryValidator::with_default_config().with_voxel_grid(grid());
+        let mut ticket = full_ticket();
+        // 2초~3초 구간의 복셀 2 예약을 복셀 7로 교체
+        ticket.vts_list[2] = VoxelTimeSlot::new(7, 2000 * MS, 3000 * MS);
+        let context = TrajectoryContext::new().with_ticket(&ticket);
+
+        let result = validator.validate(&straight_trajectory(), &context);
+        let violation = result.first_violation.unwrap();
+        assert_eq!(violation.timestamp_ns, 2000 * MS);
+        assert!(matches!(
+            violation.reason,
+            ValidationReason::VTSViolation { vts_id: 2 }
+        ));
+    }
+
+    #[test]
+    fn test_ticket_for_other_robot() {
+        let validator = TrajectoryValidator::with_default_config();
+        let ticket = TransitTicket::new(5, 99, 1).with_validity(0, 10_000 * MS);
+        let context = TrajectoryContext::new().with_ticket(&ticket);
+
+        let result = validator.validate(&straight_trajectory(), &context);
+        assert!(matches!(
+            result.first_violation.unwrap().reason,
+            ValidationReason::InvalidTicket { ticket_id: 5 }
+        ));
+    }
+}
@@ -10,6 +10,7 @@
 //! - **상태 관리**: 로봇의 현재 위치, 속도, 티켓 상태 추적
 //! - **명령 생성**: 이동 명령 생성 및 서명
 //! - **티켓 요청**: Edge에 VTS 할당 요청
+//! - **링크 감시**: Edge 연결 상실 시 자율 안전 정지
 //!
 //! ## Quick Start
 //!
@@ -44,6 +45,7 @@
 //! | [`state`] | 로봇 상태 관리 | [`RobotStateManager`] |
 //! | [`command`] | 명령 생성/서명 | [`CommandBuilder`] |
 //! | [`ticket`] | 티켓 요청/검증 | [`TicketRequester`] |
+//! | [`watchdog`] | 링크 감시/자율 정지 | [`LinkWatchdog`] |
 //!
 //! ## 아키텍처
 //!
@@ -68,8 +70,10 @@
 //! ## PPR 매핑
 //!
 //! - `AI_make_RobotState` → [`RobotStateManager`]
+//! - `AI_make_DeltaTick` → [`RobotStateManager::build_delta_tick`]
 //! - `AI_make_MotionCommand` → [`CommandBuilder`]
 //! - `AI_request_TransitTicket` → [`TicketRequester`]
+//! - `AI_process_LinkWatchdog` → [`LinkWatchdog`]
 //!
 //! ## 관련 크레이트
 //!
@@ -79,8 +83,10 @@
 pub mod command;
 pub mod state;
 pub mod ticket;
+pub mod watchdog;
 
 // 주요 타입 re-export
 pub use command::CommandBuilder;
 pub use state::RobotStateManager;
 pub use ticket::TicketRequester;
+pub use watchdog::LinkWatchdog;
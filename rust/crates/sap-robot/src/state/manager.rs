@@ -1,7 +1,12 @@
 //! RobotStateManager - 로봇 상태 관리자
 //!
-//! PPR 매핑: AI_make_RobotState
+//! 예측은 Edge와 공유하는 `MotionModel`을 사용하며,
+//! DeltaTick에는 직전 보고 상태 기준 예측 오차와 모델 해시를 싣는다.
+//!
+//! PPR 매핑: AI_make_RobotState, AI_make_DeltaTick
 
+use sap_core::packet::DeltaTickPacket;
+use sap_core::prediction::{KinematicState, MotionModel};
 use sap_core::types::{Position, RobotState, Velocity};
 
 /// 로봇 상태 관리자
@@ -11,6 +16,9 @@ pub struct RobotStateManager {
     state_history: Vec<StateSnapshot>,
     history_capacity: usize,
     last_update_ns: u64,
+    model: MotionModel,
+    /// 마지막 DeltaTick 보고 상태 (다음 예측 기준)
+    last_reported: Option<(KinematicState, u64)>,
 }
 
 /// 상태 스냅샷
@@ -38,9 +46,17 @@ impl RobotStateManager {
             state_history: Vec::new(),
             history_capacity: 100,
             last_update_ns: 0,
+            model: MotionModel::default(),
+            last_reported: None,
         }
     }
 
+    /// 예측 모델 설정
+    pub fn with_model(mut self, model: MotionModel) -> Self {
+        self.model = model;
+        self
+    }
+
     pub fn with_position(robot_id: u64, position: Position) -> Self {
         let mut manager = Self::new(robot_id);
         manager.current_state.position = position;
@@ -59,6 +75,12 @@ impl RobotStateManager {
         self.last_update_ns = timestamp_ns;
     }
 
+    /// 방향/각속도 갱신 (CTRV 예측용)
+    pub fn update_heading(&mut self, theta: f32, omega: f32) {
+        self.current_state.theta = theta;
+        self.current_state.omega = omega;
+    }
+
     pub fn apply_correction(&mut self, position: Position, velocity: Velocity, timestamp_ns: u64) {
         self.current_state.position = position;
         self.current_state.velocity = velocity;
@@ -67,22 +89,56 @@ impl RobotStateManager {
     }
 
     pub fn predict(&mut self, dt_ns: u64) -> Position {
-        let dt_secs = dt_ns as f32 / 1_000_000_000.0;
-        let current_pos = self.current_state.position;
-        let current_vel = self.current_state.velocity;
-
-        let predicted = Position::new(
-            current_pos.x + current_vel.vx * dt_secs,
-            current_pos.y + current_vel.vy * dt_secs,
-            current_pos.z + current_vel.vz * dt_secs,
-        );
+        let current = KinematicState::from_robot(&self.current_state);
+        let predicted = self.model.predict_ns(&current, dt_ns);
 
-        self.current_state.position = predicted;
+        self.current_state.position = predicted.position;
+        self.current_state.velocity = predicted.velocity;
+        self.current_state.theta = predicted.theta;
         let new_timestamp = self.last_update_ns + dt_ns;
         self.record_snapshot(StateSource::LocalPrediction, new_timestamp);
         self.last_update_ns = new_timestamp;
 
-        predicted
+        predicted.position
+    }
+
+    /// 현재 상태로 DeltaTick 생성
+    ///
+    /// 예측 오차는 직전 보고 상태를 공유 모델로 예측한 값 대비로 계산하므로
+    /// Edge가 같은 모델로 재계산하여 검증할 수 있다. 기준 보고의 타임스탬프를
+    /// 패킷에 실어 보고가 유실돼도 Edge가 기준 불일치를 알 수 있다. 첫 보고는 오차 0.
+    pub fn build_delta_tick(
+        &mut self,
+        zone_id: u32,
+        tick: u64,
+        timestamp_ns: u64,
+    ) -> DeltaTickPacket {
+        let actual = KinematicState::from_robot(&self.current_state);
+        let baseline = self
+            .last_reported
+            .filter(|(_, reported_ns)| timestamp_ns > *reported_ns);
+        let (delta_position, delta_theta) = match &baseline {
+            Some((previous, reported_ns)) => {
+                self.model
+                    .prediction_delta(previous, &actual, timestamp_ns - reported_ns)
+            }
+            None => (Position::ORIGIN, 0.0),
+        };
+        self.last_reported = Some((actual, timestamp_ns));
+
+        let mut packet = DeltaTickPacket::new(zone_id, self.robot_id, tick)
+            .with_motion(
+                actual.position,
+                actual.velocity,
+                actual.acceleration,
+                actual.theta,
+            )
+            .with_angular_rate(actual.omega)
+            .with_model(self.model)
+            .with_delta(delta_position, delta_theta)
+            .with_timestamp(timestamp_ns);
+        packet.baseline_ns = baseline.map(|(_, reported_ns)| reported_ns);
+        packet
     }
 
     fn record_snapshot(&mut self, source: StateSource, timestamp_ns: u64) {
@@ -108,6 +164,9 @@ impl RobotStateManager {
     pub fn robot_id(&self) -> u64 {
         self.robot_id
     }
+    pub fn model(&self) -> MotionModel {
+        self.model
+    }
     pub fn last_update_ns(&self) -> u64 {
         self.last_update_ns
     }
@@ -184,4 +243,41 @@ mod tests {
         }
         assert_eq!(manager.history_len(), 5);
     }
+
+    #[test]
+    fn test_predict_ctrv() {
+        let mut manager =
+            RobotStateManager::new(42).with_model(MotionModel::ConstantTurnRateVelocity);
+        manager.update_from_sensor(Position::ORIGIN, Velocity::new(1.0, 0.0, 0.0), 0);
+        manager.update_heading(0.0, std::f32::consts::FRAC_PI_2);
+        let predicted = manager.predict(1_000_000_000);
+        let radius = 2.0 / std::f32::consts::PI;
+        assert!((predicted.x - radius).abs() < 1e-4);
+        assert!((predicted.y - radius).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_build_delta_tick() {
+        let mut manager = RobotStateManager::new(42).with_model(MotionModel::ConstantAcceleration);
+        manager.update_from_sensor(Position::ORIGIN, Velocity::new(1.0, 0.0, 0.0), 0);
+        let first = manager.build_delta_tick(1, 1, 0);
+        assert_eq!(
+            first.motion_model(),
+            Some(MotionModel::ConstantAcceleration)
+        );
+        assert_eq!(first.delta_magnitude(), 0.0);
+        assert_eq!(first.baseline_ns, None);
+
+        // 등속 예측 (0.05, 0) 대비 실제 (0.06, 0.01)
+        manager.update_from_sensor(
+            Position::new(0.06, 0.01, 0.0),
+            Velocity::new(1.0, 0.0, 0.0),
+            50_000_000,
+        );
+        let second = manager.build_delta_tick(1, 2, 50_000_000);
+        assert!((second.delta_position.x - 0.01).abs() < 1e-5);
+        assert!((second.delta_position.y - 0.01).abs() < 1e-5);
+        assert_eq!(second.timestamp_ns, 50_000_000);
+        assert_eq!(second.baseline_ns, Some(0));
+    }
 }
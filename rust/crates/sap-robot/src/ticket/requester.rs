@@ -2,8 +2,9 @@
 //!
 //! PPR 매핑: AI_request_TransitTicket
 
-use sap_core::ticket::TransitTicket;
+use sap_core::ticket::{TransitTicket, VoxelGrid, VoxelTimeSlot};
 use sap_core::types::Position;
+use sap_physics::kinematics::PlannedMotion;
 use std::collections::HashMap;
 
 /// 티켓 요청자
@@ -28,6 +29,10 @@ pub struct TicketRequest {
     pub priority: u8,
     pub max_price: u64,
     pub created_at_ns: u64,
+    /// 요청 VTS 창 (계획된 궤적 기반, 비어있으면 서버가 결정)
+    pub vts_windows: Vec<VoxelTimeSlot>,
+    /// 예상 도착 시각 (나노초, 0이면 미지정)
+    pub expected_arrival_ns: u64,
 }
 
 impl TicketRequester {
@@ -58,6 +63,8 @@ impl TicketRequester {
             priority,
             max_price,
             created_at_ns: timestamp_ns,
+            vts_windows: Vec::new(),
+            expected_arrival_ns: 0,
         };
 
         self.pending_requests
@@ -65,6 +72,34 @@ impl TicketRequester {
         request
     }
 
+    /// 계획된 이동 기반 티켓 요청 생성
+    ///
+    /// S-커브 궤적의 실제 통과 시각으로 VTS 창을 계산하여 요청에 포함
+    pub fn create_motion_request(
+        &mut self,
+        motion: &PlannedMotion,
+        grid: &VoxelGrid,
+        margin_ns: u64,
+        priority: u8,
+        max_price: u64,
+        timestamp_ns: u64,
+    ) -> TicketRequest {
+        let destination = motion
+            .trajectory
+            .points()
+            .last()
+            .map(|p| p.position)
+            .unwrap_or(Position::ORIGIN);
+
+        let mut request = self.create_request(destination, priority, max_price, timestamp_ns);
+        request.vts_windows = grid.occupancy_windows(&motion.trajectory, margin_ns);
+        request.expected_arrival_ns = motion.arrival_ns;
+
+        self.pending_requests
+            .insert(request.request_id, request.clone());
+        request
+    }
+
     /// 티켓 수신 및 등록
     pub fn receive_ticket(&mut self, request_id: u64, ticket: TransitTicket) -> bool {
         if self.pending_requests.remove(&request_id).is_some() {
@@ -160,6 +195,29 @@ mod tests {
         assert_eq!(requester.pending_request_count(), 1);
     }
 
+    #[test]
+    fn test_create_motion_request() {
+        use sap_physics::kinematics::{KinematicsParams, SCurveGenerator};
+
+        let mut requester = TicketRequester::new(42);
+        let generator = SCurveGenerator::new(KinematicsParams::default());
+        let goal = Position::new(5.5, 0.5, 0.5);
+        let motion = generator
+            .point_to_point(42, Position::new(0.5, 0.5, 0.5), goal, 1_000_000_000)
+            .unwrap();
+        let grid = VoxelGrid::new(Position::ORIGIN, 1.0, [10, 1, 1]);
+
+        let request = requester.create_motion_request(&motion, &grid, 0, 1, 1000, 0);
+
+        assert_eq!(request.destination, goal);
+        assert_eq!(request.expected_arrival_ns, motion.arrival_ns);
+        let voxels: Vec<u64> = request.vts_windows.iter().map(|w| w.voxel_id).collect();
+        assert_eq!(voxels, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(request.vts_windows[0].t_start_ns, 1_000_000_000);
+        assert!(request.vts_windows[5].t_end_ns > motion.arrival_ns);
+        assert_eq!(requester.pending_request_count(), 1);
+    }
+
     #[test]
     fn test_receive_ticket() {
         let mut requester = TicketRequester::new(42);
@@ -0,0 +1,396 @@
+//! LinkWatchdog - 로봇 측 Edge 링크 감시
+//!
+//! Edge의 Failsafe 판단은 링크가 살아 있어야 로봇에 전달된다. Wi-Fi 단절처럼
+//! Edge와 연결이 끊기면 로봇이 스스로 판단해야 하므로, 마지막 유효 메시지 시각과
+//! 티켓 유효성을 감시하여 단계적으로 대응한다.
+//!
+//! ```text
+//! Connected ─(1차 타임아웃)→ Degraded ─(2차 타임아웃)→ SafeStopping → Holding
+//!     ▲            │                                         │           │
+//!     └─(메시지 수신)┘                                         └─(재허가)──┘
+//! ```
+//!
+//! 감시는 첫 틱에서 시작되며, 그때까지 Edge 메시지를 한 번도 받지 못했다면
+//! 첫 틱 시각부터 경과 시간을 잰다 (처음부터 링크가 없는 경우도 정지).
+//!
+//! 안전 정지 이후에는 링크가 복구되어도 자동 재개하지 않으며,
+//! Edge의 명시적 재허가(`regrant`)가 있어야만 Connected로 돌아간다.
+//!
+//! PPR 매핑: AI_process_LinkWatchdog
+
+use sap_core::ticket::TransitTicket;
+use sap_core::types::RobotState;
+use sap_physics::recovery::{
+    ExecutorPhase, RecoveryCommand, RecoveryExecutor, RecoveryExecutorConfig, RecoverySetpoint,
+    RecoveryStep,
+};
+
+/// 링크 감시 설정
+#[derive(Debug, Clone)]
+pub struct LinkWatchdogConfig {
+    /// 감속 운전 전환 타임아웃 (밀리초)
+    pub degrade_timeout_ms: u64,
+
+    /// 자율 안전 정지 타임아웃 (밀리초)
+    pub safe_stop_timeout_ms: u64,
+
+    /// 감속 운전 시 속도 배율 (0.0 ~ 1.0)
+    pub degraded_speed_factor: f32,
+
+    /// 안전 정지 감속도 (m/s²)
+    pub safe_deceleration: f32,
+
+    /// 복구 실행기 설정
+    pub recovery: RecoveryExecutorConfig,
+}
+
+impl Default for LinkWatchdogConfig {
+    fn default() -> Self {
+        Self {
+            degrade_timeout_ms: 200,   // Edge heartbeat 2회 누락
+            safe_stop_timeout_ms: 500, // Edge 복구 보류 시간과 동일
+            degraded_speed_factor: 0.5,
+            safe_deceleration: 2.0,
+            recovery: RecoveryExecutorConfig::default(),
+        }
+    }
+}
+
+/// 링크 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkState {
+    /// 정상 연결
+    Connected,
+    /// 1차 타임아웃 - 감속 운전
+    Degraded,
+    /// 2차 타임아웃 또는 티켓 무효 - 자율 감속 중
+    SafeStopping,
+    /// 정지 완료 - 재허가 대기
+    Holding,
+}
+
+/// 안전 정지 원인
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafeStopCause {
+    /// Edge 링크 상실
+    LinkLost,
+    /// 보유 티켓 만료/무효
+    TicketInvalid,
+}
+
+impl SafeStopCause {
+    /// `RecoveryCommand::reason_code` 값
+    pub const fn reason_code(&self) -> u32 {
+        match self {
+            Self::LinkLost => 0x0100,
+            Self::TicketInvalid => 0x0101,
+        }
+    }
+}
+
+/// 틱별 감시 결과
+#[derive(Debug, Clone)]
+pub enum WatchdogOutput {
+    /// 제한 없음
+    Normal,
+    /// 속도 제한 (명령 속도에 배율 적용)
+    LimitSpeed { speed_factor: f32 },
+    /// 자율 복구 설정값 (명령 속도 대신 따름)
+    Recovery(RecoverySetpoint),
+}
+
+/// 로봇 측 링크 감시기
+///
+/// PPR: AI_process_LinkWatchdog(robot_state, now) -> WatchdogOutput
+#[derive(Debug, Clone)]
+pub struct LinkWatchdog {
+    config: LinkWatchdogConfig,
+    robot_id: u64,
+    state: LinkState,
+    last_message_ns: Option<u64>,
+    armed_ns: Option<u64>,
+    ticket: Option<TransitTicket>,
+    cause: Option<SafeStopCause>,
+    executor: RecoveryExecutor,
+}
+
+impl LinkWatchdog {
+    /// 새 LinkWatchdog 생성
+    pub fn new(robot_id: u64, config: LinkWatchdogConfig) -> Self {
+        let executor = RecoveryExecutor::new(config.recovery.clone());
+        Self {
+            config,
+            robot_id,
+            state: LinkState::Connected,
+            last_message_ns: None,
+            armed_ns: None,
+            ticket: None,
+            cause: None,
+            executor,
+        }
+    }
+
+    /// 기본 설정으로 생성
+    pub fn with_default_config(robot_id: u64) -> Self {
+        Self::new(robot_id, LinkWatchdogConfig::default())
+    }
+
+    /// 유효한 Edge 메시지 수신 기록
+    ///
+    /// 감속 운전은 자동 해제되지만, 안전 정지 이후에는 재허가 전까지 유지된다.
+    pub fn on_edge_message(&mut self, timestamp_ns: u64) {
+        if self.last_message_ns.is_none_or(|last| timestamp_ns > last) {
+            self.last_message_ns = Some(timestamp_ns);
+        }
+        if self.state == LinkState::Degraded {
+            self.state = LinkState::Connected;
+        }
+    }
+
+    /// 주행 티켓 등록 (운행 중 갱신)
+    pub fn set_ticket(&mut self, ticket: TransitTicket) {
+        self.ticket = Some(ticket);
+    }
+
+    /// Edge 재허가 - 안전 정지 해제
+    ///
+    /// 재허가 메시지 자체가 유효 메시지이며, 함께 받은 티켓이 현재 유효해야 한다.
+    pub fn regrant(&mut self, ticket: TransitTicket, timestamp_ns: u64) -> bool {
+        if ticket.robot_id != self.robot_id || !ticket.is_valid(timestamp_ns) {
+            return false;
+        }
+        self.on_edge_message(timestamp_ns);
+        self.ticket = Some(ticket);
+        self.executor.reset();
+        self.cause = None;
+        self.state = LinkState::Connected;
+        true
+    }
+
+    /// 틱 처리
+    pub fn tick(&mut self, robot: &RobotState) -> WatchdogOutput {
+        let now = robot.timestamp_ns;
+        self.armed_ns.get_or_insert(now);
+
+        if matches!(self.state, LinkState::Connected | LinkState::Degraded) {
+            if let Some(cause) = self.check_stop_cause(now) {
+                self.begin_safe_stop(cause, robot);
+            } else if self.elapsed_ms(now) > self.config.degrade_timeout_ms {
+                self.state = LinkState::Degraded;
+            }
+        }
+
+        match self.state {
+            LinkState::Connected => WatchdogOutput::Normal,
+            LinkState::Degraded => WatchdogOutput::LimitSpeed {
+                speed_factor: self.config.degraded_speed_factor,
+            },
+            LinkState::SafeStopping | LinkState::Holding => match self.executor.tick(robot) {
+                RecoveryStep::Setpoint(setpoint) => {
+                    if self.executor.phase() != ExecutorPhase::Running {
+                        self.state = LinkState::Holding;
+                    }
+                    WatchdogOutput::Recovery(setpoint)
+                }
+                // 완료 틱: 다음 틱부터 정지 유지 설정값
+                RecoveryStep::Finished(_) => {
+                    self.state = LinkState::Holding;
+                    match self.executor.tick(robot) {
+                        RecoveryStep::Setpoint(setpoint) => WatchdogOutput::Recovery(setpoint),
+                        _ => unreachable!("executor holds after finishing"),
+                    }
+                }
+                RecoveryStep::Idle => unreachable!("executor started on safe stop"),
+            },
+        }
+    }
+
+    fn check_stop_cause(&self, now_ns: u64) -> Option<SafeStopCause> {
+        if self.elapsed_ms(now_ns) > self.config.safe_stop_timeout_ms {
+            return Some(SafeStopCause::LinkLost);
+        }
+        match &self.ticket {
+            Some(ticket) if !ticket.is_valid(now_ns) => Some(SafeStopCause::TicketInvalid),
+            _ => None,
+        }
+    }
+
+    fn begin_safe_stop(&mut self, cause: SafeStopCause, robot: &RobotState) {
+        let command = RecoveryCommand::safe_deceleration(
+            self.robot_id,
+            self.config.safe_deceleration,
+            robot.timestamp_ns,
+        )
+        .with_reason(cause.reason_code());
+        self.executor.start(command, robot);
+        self.cause = Some(cause);
+        self.state = LinkState::SafeStopping;
+    }
+
+    /// 마지막 유효 메시지 이후 경과 시간 (밀리초, 수신 전에는 감시 시작 이후)
+    fn elapsed_ms(&self, now_ns: u64) -> u64 {
+        self.last_message_ns
+            .or(self.armed_ns)
+            .map_or(0, |since| now_ns.saturating_sub(since) / 1_000_000)
+    }
+
+    /// 현재 링크 상태
+    pub fn state(&self) -> LinkState {
+        self.state
+    }
+
+    /// 안전 정지 원인 (정지 중이 아니면 None)
+    pub fn safe_stop_cause(&self) -> Option<SafeStopCause> {
+        self.cause
+    }
+
+    /// 마지막 유효 메시지 시각 (나노초)
+    pub fn last_message_ns(&self) -> Option<u64> {
+        self.last_message_ns
+    }
+
+    /// 명령 수행 허용 여부
+    pub fn is_motion_allowed(&self) -> bool {
+        matches!(self.state, LinkState::Connected | LinkState::Degraded)
+    }
+
+    /// 로봇 ID 조회
+    pub fn robot_id(&self) -> u64 {
+        self.robot_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap_core::types::Velocity;
+
+    const MS: u64 = 1_000_000;
+
+    fn robot(speed: f32, timestamp_ns: u64) -> RobotState {
+        let mut state = RobotState::new(42);
+        state.velocity = Velocity::new(speed, 0.0, 0.0);
+        state.timestamp_ns = timestamp_ns;
+        state
+    }
+
+    fn ticket(valid_to_ns: u64) -> TransitTicket {
+        TransitTicket::new(1, 42, 1).with_validity(0, valid_to_ns)
+    }
+
+    #[test]
+    fn test_degrade_then_recover_on_message() {
+        let mut watchdog = LinkWatchdog::with_default_config(42);
+        watchdog.on_edge_message(0);
+        assert!(matches!(
+            watchdog.tick(&robot(1.0, 100 * MS)),
+            WatchdogOutput::Normal
+        ));
+
+        let output = watchdog.tick(&robot(1.0, 250 * MS));
+        assert!(
+            matches!(output, WatchdogOutput::LimitSpeed { speed_factor } if speed_factor == 0.5)
+        );
+        assert_eq!(watchdog.state(), LinkState::Degraded);
+
+        watchdog.on_edge_message(260 * MS);
+        assert!(matches!(
+            watchdog.tick(&robot(0.5, 300 * MS)),
+            WatchdogOutput::Normal
+        ));
+    }
+
+    #[test]
+    fn test_no_edge_message_counts_from_first_tick() {
+        let mut watchdog = LinkWatchdog::with_default_config(42);
+        assert!(matches!(
+            watchdog.tick(&robot(1.0, 1_000 * MS)),
+            WatchdogOutput::Normal
+        ));
+        assert_eq!(watchdog.last_message_ns(), None);
+
+        // 메시지를 한 번도 받지 못해도 첫 틱 기준으로 타임아웃
+        watchdog.tick(&robot(1.0, 1_250 * MS));
+        assert_eq!(watchdog.state(), LinkState::Degraded);
+
+        let output = watchdog.tick(&robot(1.0, 1_600 * MS));
+        assert!(matches!(output, WatchdogOutput::Recovery(_)));
+        assert_eq!(watchdog.safe_stop_cause(), Some(SafeStopCause::LinkLost));
+    }
+
+    #[test]
+    fn test_link_loss_safe_stops_and_holds() {
+        let mut watchdog = LinkWatchdog::with_default_config(42);
+        watchdog.on_edge_message(0);
+
+        // 2 m/s에서 링크 상실 → 설정값을 따라 감속
+        let mut state = robot(2.0, 600 * MS);
+        for _ in 0..40 {
+            match watchdog.tick(&state) {
+                WatchdogOutput::Recovery(setpoint) => {
+                    state.velocity = setpoint.target_velocity;
+                    state.timestamp_ns += 100 * MS;
+                }
+                other => panic!("expected recovery, got {other:?}"),
+            }
+            if watchdog.state() == LinkState::Holding {
+                break;
+            }
+        }
+        assert_eq!(watchdog.state(), LinkState::Holding);
+        assert_eq!(watchdog.safe_stop_cause(), Some(SafeStopCause::LinkLost));
+        assert!(!watchdog.is_motion_allowed());
+
+        // 링크가 돌아와도 재허가 전까지 정지 유지
+        watchdog.on_edge_message(state.timestamp_ns);
+        match watchdog.tick(&state) {
+            WatchdogOutput::Recovery(setpoint) => {
+                assert_eq!(setpoint.target_velocity, Velocity::ZERO);
+                assert!(setpoint.hold_position.is_some());
+            }
+            other => panic!("expected hold, got {other:?}"),
+        }
+        assert_eq!(watchdog.state(), LinkState::Holding);
+    }
+
+    #[test]
+    fn test_regrant_required_to_resume() {
+        let mut watchdog = LinkWatchdog::with_default_config(42);
+        watchdog.on_edge_message(0);
+        watchdog.tick(&robot(0.0, 600 * MS));
+        assert_eq!(watchdog.state(), LinkState::SafeStopping);
+
+        // 만료 티켓, 다른 로봇 티켓은 거부
+        assert!(!watchdog.regrant(ticket(500 * MS), 700 * MS));
+        assert!(!watchdog.regrant(
+            TransitTicket::new(2, 7, 1).with_validity(0, 10_000 * MS),
+            700 * MS
+        ));
+        assert!(watchdog.regrant(ticket(10_000 * MS), 700 * MS));
+        assert_eq!(watchdog.state(), LinkState::Connected);
+        assert_eq!(watchdog.safe_stop_cause(), None);
+        assert!(matches!(
+            watchdog.tick(&robot(0.0, 750 * MS)),
+            WatchdogOutput::Normal
+        ));
+    }
+
+    #[test]
+    fn test_ticket_expiry_triggers_safe_stop() {
+        let mut watchdog = LinkWatchdog::with_default_config(42);
+        watchdog.on_edge_message(0);
+        watchdog.set_ticket(ticket(100 * MS));
+        assert!(matches!(
+            watchdog.tick(&robot(1.0, 50 * MS)),
+            WatchdogOutput::Normal
+        ));
+
+        watchdog.on_edge_message(150 * MS);
+        let output = watchdog.tick(&robot(1.0, 150 * MS));
+        assert!(matches!(output, WatchdogOutput::Recovery(_)));
+        assert_eq!(
+            watchdog.safe_stop_cause(),
+            Some(SafeStopCause::TicketInvalid)
+        );
+    }
+}
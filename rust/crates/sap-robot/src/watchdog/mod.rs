@@ -0,0 +1,5 @@
+//! watchdog 모듈 - Edge 링크 감시 및 자율 안전 정지
+
+mod link;
+
+pub use link::{LinkState, LinkWatchdog, LinkWatchdogConfig, SafeStopCause, WatchdogOutput};